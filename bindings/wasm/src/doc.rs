@@ -3,6 +3,7 @@
 
 use identity::core::FromJson;
 use identity::core::SerdeInto;
+use identity::core::Url;
 use identity::did::DIDKey;
 use identity::did::Document;
 use identity::did::DocumentBuilder;
@@ -151,7 +152,7 @@ impl Doc {
     let service: Service = ServiceBuilder::default()
       .id(did.0.into())
       .type_(service_type)
-      .service_endpoint(url.parse().map_err(js_err)?)
+      .service_endpoint(Url::parse(url).map_err(js_err)?)
       .build()
       .map_err(js_err)?;
 
@@ -8,10 +8,11 @@
 
 mod common;
 
-use identity::did::resolution::dereference;
-use identity::did::resolution::resolve;
+use identity::did::peer::PeerDID;
+use identity::did::peer::PeerResolver;
 use identity::did::resolution::Dereference;
 use identity::did::resolution::Resolution;
+use identity::did::resolution::Resolver;
 use identity::iota::Client;
 use identity::iota::IotaDID;
 use identity::iota::IotaDocument;
@@ -25,15 +26,20 @@ async fn main() -> Result<()> {
   let doc: IotaDocument = common::document(&client).await?.0;
   let did: &IotaDID = doc.id();
 
+  // Create a resolver that supports both the IOTA and peer DID methods.
+  let mut resolver: Resolver = Resolver::new();
+  resolver.register(IotaDID::METHOD, client.clone());
+  resolver.register(PeerDID::METHOD, PeerResolver::new());
+
   // Resolve the DID and retrieve the published DID Document from the Tangle.
-  let resolution: Resolution = resolve(did.as_str(), Default::default(), &client).await?;
+  let resolution: Resolution = resolver.resolve(did.as_str(), Default::default()).await?;
 
   println!("DID Document Resolution > {:#?}", resolution);
   println!();
 
   // Dereference the DID and retrieve the authentication method generated above.
   let did: IotaDID = did.join("#key-1")?;
-  let dereference: Dereference = dereference(did.as_str(), Default::default(), &client).await?;
+  let dereference: Dereference = resolver.dereference(did.as_str(), Default::default()).await?;
 
   println!("DID Document Dereference > {:#?}", dereference);
   println!();
@@ -3,6 +3,7 @@
 
 use chrono::DateTime;
 use chrono::SecondsFormat;
+use chrono::TimeZone;
 use chrono::Utc;
 use core::convert::TryFrom;
 use core::fmt::Debug;
@@ -34,6 +35,12 @@ impl Timestamp {
     Self::parse(&Self::to_rfc3339(&Self(Utc::now()))).unwrap()
   }
 
+  /// Creates a new [`Timestamp`] from the given Unix timestamp, or `None` if
+  /// the value is out of range.
+  pub fn from_unix(seconds: i64) -> Option<Self> {
+    Utc.timestamp_opt(seconds, 0).single().map(Self)
+  }
+
   /// Returns the [`Timestamp`] as a Unix timestamp.
   pub fn to_unix(&self) -> i64 {
     self.0.timestamp()
@@ -127,4 +134,12 @@ mod tests {
   fn test_parse_invalid_fmt() {
     Timestamp::parse("2020/01/01 03:30:16").unwrap();
   }
+
+  #[test]
+  fn test_from_unix() {
+    let timestamp = Timestamp::parse("2020-01-01T00:00:00Z").unwrap();
+
+    assert_eq!(Timestamp::from_unix(timestamp.to_unix()), Some(timestamp));
+    assert_eq!(Timestamp::from_unix(i64::MAX), None);
+  }
 }
@@ -48,10 +48,10 @@ impl MerkleKey {
     Self::encode_key::<D, Ed25519>(tree)
   }
 
-  // Encodes a proof in the following form:
-  //
-  //   [ U32(PATH-LEN) [ [ U8(NODE-TAG) | HASH(NODE-PATH) ] ... ] ]
-  pub(crate) fn encode_proof<D>(proof: &Proof<D>) -> Vec<u8>
+  /// Encodes a Merkle tree inclusion [`Proof`] in the following form:
+  ///
+  ///   `[ U32(PATH-LEN) [ [ U8(NODE-TAG) | HASH(NODE-PATH) ] ... ] ]`
+  pub fn encode_proof<D>(proof: &Proof<D>) -> Vec<u8>
   where
     D: Digest,
   {
@@ -66,10 +66,10 @@ impl MerkleKey {
     size.iter().copied().chain(data).collect()
   }
 
-  // Decodes a proof in the following form:
-  //
-  //   [ U32(PATH-LEN) [ [ U8(NODE-TAG) | HASH(NODE-PATH) ] ... ] ]
-  pub(crate) fn decode_proof<D>(data: &[u8]) -> Option<Proof<D>>
+  /// Decodes a Merkle tree inclusion [`Proof`] in the following form:
+  ///
+  ///   `[ U32(PATH-LEN) [ [ U8(NODE-TAG) | HASH(NODE-PATH) ] ... ] ]`
+  pub fn decode_proof<D>(data: &[u8]) -> Option<Proof<D>>
   where
     D: Digest,
   {
@@ -15,6 +15,7 @@ pub use self::key::PublicKey;
 pub use self::key::SecretKey;
 pub(crate) use self::proof::ed25519_sign;
 pub(crate) use self::proof::ed25519_verify;
+pub use self::proof::EddsaJcs2022;
 pub use self::proof::JcsEd25519Signature2020;
 pub use self::signature::SetSignature;
 pub use self::signature::SigName;
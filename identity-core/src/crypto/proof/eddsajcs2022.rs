@@ -0,0 +1,194 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use sha2::digest::Output;
+use sha2::Sha256;
+
+use crate::convert::ToJson;
+use crate::crypto::ed25519_sign;
+use crate::crypto::ed25519_verify;
+use crate::crypto::SigName;
+use crate::crypto::SigSign;
+use crate::crypto::SigVerify;
+use crate::crypto::SignatureData;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::decode_b58;
+use crate::utils::encode_b58;
+use crate::utils::jcs_sha256;
+
+const SIGNATURE_NAME: &str = "DataIntegrityProof";
+const CRYPTOSUITE_NAME: &str = "eddsa-jcs-2022";
+const SIGNATURE_SIZE: usize = 64;
+
+// The multibase prefix of base58-btc encoded data.
+const MULTIBASE_B58: char = 'z';
+
+/// An implementation of the [eddsa-jcs-2022][SPEC1] cryptosuite for
+/// [Data Integrity][SPEC2] proofs.
+///
+/// The signed data is expected to hold the proof in a `proof` property.
+///
+/// [SPEC1]: https://www.w3.org/TR/vc-di-eddsa/#eddsa-jcs-2022
+/// [SPEC2]: https://www.w3.org/TR/vc-data-integrity/
+#[derive(Clone, Copy, Debug)]
+pub struct EddsaJcs2022;
+
+impl EddsaJcs2022 {
+  /// The name of the signature suite.
+  pub const NAME: &'static str = SIGNATURE_NAME;
+
+  /// The name of the cryptosuite.
+  pub const CRYPTOSUITE: &'static str = CRYPTOSUITE_NAME;
+
+  /// Signs the given `data` with `secret` and returns a digital signature.
+  pub fn sign_data<T>(data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    let message: Vec<u8> = hash_data(data)?;
+    let signature: Vec<u8> = ed25519_sign(&message, secret)?;
+
+    Ok(SignatureData::Proof(format!(
+      "{}{}",
+      MULTIBASE_B58,
+      encode_b58(&signature[..SIGNATURE_SIZE])
+    )))
+  }
+
+  /// Verifies the authenticity of `data` using `signature` and `public`.
+  pub fn verify_data<T>(data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    let signature: Vec<u8> = signature
+      .try_proof()
+      .and_then(|proof| proof.strip_prefix(MULTIBASE_B58))
+      .ok_or(Error::InvalidProofFormat)
+      .and_then(|proof| decode_b58(proof))?;
+
+    if signature.len() != SIGNATURE_SIZE {
+      return Err(Error::InvalidProofFormat);
+    }
+
+    let message: Vec<u8> = hash_data(data)?;
+
+    ed25519_verify(&message, &[&signature[..], &message[..]].concat(), public)
+  }
+}
+
+impl SigName for EddsaJcs2022 {
+  fn name(&self) -> String {
+    Self::NAME.to_string()
+  }
+
+  fn cryptosuite(&self) -> Option<String> {
+    Some(Self::CRYPTOSUITE.to_string())
+  }
+}
+
+impl SigSign for EddsaJcs2022 {
+  fn sign<T>(&self, data: &T, secret: &[u8]) -> Result<SignatureData>
+  where
+    T: Serialize,
+  {
+    Self::sign_data(data, secret)
+  }
+}
+
+impl SigVerify for EddsaJcs2022 {
+  fn verify<T>(&self, data: &T, signature: &SignatureData, public: &[u8]) -> Result<()>
+  where
+    T: Serialize,
+  {
+    Self::verify_data(data, signature, public)
+  }
+}
+
+// Splits `data` into the unsecured document and the proof configuration and
+// returns the concatenated SHA-256 hashes of their JCS representations.
+//
+// output = <SHA256(PROOF CONFIG)><SHA256(DOCUMENT)>
+fn hash_data<T>(data: &T) -> Result<Vec<u8>>
+where
+  T: Serialize,
+{
+  let mut document: Value = data.to_json_value()?;
+  let object: &mut Map<String, Value> = document.as_object_mut().ok_or(Error::InvalidProofFormat)?;
+
+  let mut config: Value = object.remove("proof").ok_or(Error::InvalidProofFormat)?;
+
+  if let Some(context) = object.get("@context") {
+    config
+      .as_object_mut()
+      .ok_or(Error::InvalidProofFormat)?
+      .insert("@context".to_string(), context.clone());
+  }
+
+  let config_hash: Output<Sha256> = jcs_sha256(&config)?;
+  let document_hash: Output<Sha256> = jcs_sha256(&document)?;
+
+  Ok([&config_hash[..], &document_hash[..]].concat())
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+  use crate::crypto::KeyPair;
+
+  #[test]
+  fn test_sign_and_verify() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let mut document: Value = json!({
+      "@context": "https://www.w3.org/ns/credentials/v2",
+      "id": "did:example:123",
+      "proof": {
+        "type": EddsaJcs2022::NAME,
+        "cryptosuite": EddsaJcs2022::CRYPTOSUITE,
+        "verificationMethod": "did:example:123#key-1",
+        "proofPurpose": "assertionMethod",
+      },
+    });
+
+    let signature: SignatureData = EddsaJcs2022::sign_data(&document, keypair.secret().as_ref()).unwrap();
+
+    assert!(signature.as_str().starts_with('z'));
+    assert!(EddsaJcs2022::verify_data(&document, &signature, keypair.public().as_ref()).is_ok());
+
+    document["id"] = json!("did:example:456");
+
+    assert!(EddsaJcs2022::verify_data(&document, &signature, keypair.public().as_ref()).is_err());
+  }
+
+  #[test]
+  fn test_proof_config_is_signed() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let mut document: Value = json!({
+      "id": "did:example:123",
+      "proof": {
+        "type": EddsaJcs2022::NAME,
+        "cryptosuite": EddsaJcs2022::CRYPTOSUITE,
+        "verificationMethod": "did:example:123#key-1",
+      },
+    });
+
+    let signature: SignatureData = EddsaJcs2022::sign_data(&document, keypair.secret().as_ref()).unwrap();
+
+    document["proof"]["verificationMethod"] = json!("did:example:123#key-2");
+
+    assert!(EddsaJcs2022::verify_data(&document, &signature, keypair.public().as_ref()).is_err());
+    assert!(EddsaJcs2022::verify_data(
+      &json!({ "id": "did:example:123" }),
+      &signature,
+      keypair.public().as_ref()
+    )
+    .is_err());
+  }
+}
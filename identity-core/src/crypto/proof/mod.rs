@@ -4,8 +4,10 @@
 //! Types and traits for helping ensure the authenticity and integrity of
 //! DID Documents and Verifiable Credentials.
 
+mod eddsajcs2022;
 mod jcsed25519signature2020;
 
+pub use self::eddsajcs2022::EddsaJcs2022;
 pub(crate) use self::jcsed25519signature2020::ed25519_sign;
 pub(crate) use self::jcsed25519signature2020::ed25519_verify;
 pub use self::jcsed25519signature2020::JcsEd25519Signature2020;
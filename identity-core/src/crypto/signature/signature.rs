@@ -19,6 +19,8 @@ use crate::error::Result;
 pub struct Signature {
   #[serde(rename = "type")]
   type_: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  cryptosuite: Option<String>,
   #[serde(flatten, skip_serializing_if = "SignatureValue::is_none")]
   data: SignatureValue,
   #[serde(flatten)]
@@ -30,6 +32,7 @@ impl Signature {
   pub fn new(type_: impl Into<String>, options: SignatureOptions) -> Self {
     Self {
       type_: type_.into(),
+      cryptosuite: None,
       options,
       data: SignatureValue::new(),
     }
@@ -40,6 +43,16 @@ impl Signature {
     &*self.type_
   }
 
+  /// Returns the `cryptosuite` property of the signature, if any.
+  pub fn cryptosuite(&self) -> Option<&str> {
+    self.cryptosuite.as_deref()
+  }
+
+  /// Sets the `cryptosuite` property of the signature.
+  pub fn set_cryptosuite(&mut self, value: Option<String>) {
+    self.cryptosuite = value;
+  }
+
   /// Returns a reference to the signature `data`.
   pub const fn data(&self) -> &SignatureValue {
     &self.data
@@ -98,6 +111,7 @@ impl Debug for Signature {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     f.debug_struct("Signature")
       .field("type_", &self.type_)
+      .field("cryptosuite", &self.cryptosuite)
       .field("data", &self.data)
       .field("verification_method", &self.options.verification_method)
       .field("proof_purpose", &self.options.proof_purpose)
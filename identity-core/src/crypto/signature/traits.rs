@@ -12,6 +12,12 @@ use crate::error::Result;
 pub trait SigName {
   /// Returns the name identifying this signature suite.
   fn name(&self) -> String;
+
+  /// Returns the name of the cryptosuite used by this signature suite, if it
+  /// produces [Data Integrity](https://www.w3.org/TR/vc-data-integrity/) proofs.
+  fn cryptosuite(&self) -> Option<String> {
+    None
+  }
 }
 
 impl<'a, T> SigName for &'a T
@@ -21,6 +27,10 @@ where
   fn name(&self) -> String {
     (**self).name()
   }
+
+  fn cryptosuite(&self) -> Option<String> {
+    (**self).cryptosuite()
+  }
 }
 
 // =============================================================================
@@ -54,3 +54,19 @@ where
 {
   base64::encode_config(data.as_ref(), base64::URL_SAFE)
 }
+
+/// Decodes the given `data` as unpadded base64url.
+pub fn decode_b64_unpadded<T>(data: &T) -> Result<Vec<u8>>
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  base64::decode_config(data.as_ref(), base64::URL_SAFE_NO_PAD).map_err(Error::DecodeBase64)
+}
+
+/// Encodes the given `data` as unpadded base64url.
+pub fn encode_b64_unpadded<T>(data: &T) -> String
+where
+  T: AsRef<[u8]> + ?Sized,
+{
+  base64::encode_config(data.as_ref(), base64::URL_SAFE_NO_PAD)
+}
@@ -6,16 +6,20 @@ use identity_core::common::Object;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::common::Value;
+use serde::Serialize;
 
 use crate::credential::Credential;
+use crate::credential::CredentialVersion;
 use crate::credential::Evidence;
 use crate::credential::Issuer;
 use crate::credential::Policy;
 use crate::credential::Refresh;
+use crate::credential::RelatedResource;
 use crate::credential::Schema;
 use crate::credential::Status;
 use crate::credential::Subject;
 use crate::error::Result;
+use crate::schema::SchemaRegistry;
 
 /// A `CredentialBuilder` is used to create a customized `Credential`.
 #[derive(Clone, Debug)]
@@ -23,15 +27,20 @@ pub struct CredentialBuilder<T = Object> {
   pub(crate) context: Vec<Context>,
   pub(crate) id: Option<Url>,
   pub(crate) types: Vec<String>,
+  pub(crate) name: Option<String>,
+  pub(crate) description: Option<String>,
   pub(crate) subject: Vec<Subject>,
   pub(crate) issuer: Option<Issuer>,
   pub(crate) issuance_date: Option<Timestamp>,
   pub(crate) expiration_date: Option<Timestamp>,
+  pub(crate) valid_from: Option<Timestamp>,
+  pub(crate) valid_until: Option<Timestamp>,
   pub(crate) status: Vec<Status>,
   pub(crate) schema: Vec<Schema>,
   pub(crate) refresh: Vec<Refresh>,
   pub(crate) policy: Vec<Policy>,
   pub(crate) evidence: Vec<Evidence>,
+  pub(crate) related_resource: Vec<RelatedResource>,
   pub(crate) non_transferable: Option<bool>,
   pub(crate) properties: T,
 }
@@ -43,15 +52,20 @@ impl<T> CredentialBuilder<T> {
       context: vec![Credential::<T>::base_context().clone()],
       id: None,
       types: vec![Credential::<T>::base_type().into()],
+      name: None,
+      description: None,
       subject: Vec::new(),
       issuer: None,
       issuance_date: None,
       expiration_date: None,
+      valid_from: None,
+      valid_until: None,
       status: Vec::new(),
       schema: Vec::new(),
       refresh: Vec::new(),
       policy: Vec::new(),
       evidence: Vec::new(),
+      related_resource: Vec::new(),
       non_transferable: None,
       properties,
     }
@@ -64,6 +78,13 @@ impl<T> CredentialBuilder<T> {
     self
   }
 
+  /// Sets the data model version by replacing the base JSON-LD context.
+  #[must_use]
+  pub fn version(mut self, value: CredentialVersion) -> Self {
+    self.context[0] = value.base_context().clone();
+    self
+  }
+
   /// Sets the value of the `Credential` `id`.
   #[must_use]
   pub fn id(mut self, value: Url) -> Self {
@@ -78,6 +99,20 @@ impl<T> CredentialBuilder<T> {
     self
   }
 
+  /// Sets the value of the `Credential` `name`.
+  #[must_use]
+  pub fn name(mut self, value: impl Into<String>) -> Self {
+    self.name = Some(value.into());
+    self
+  }
+
+  /// Sets the value of the `Credential` `description`.
+  #[must_use]
+  pub fn description(mut self, value: impl Into<String>) -> Self {
+    self.description = Some(value.into());
+    self
+  }
+
   /// Adds a value to the `credentialSubject` set.
   #[must_use]
   pub fn subject(mut self, value: Subject) -> Self {
@@ -106,6 +141,20 @@ impl<T> CredentialBuilder<T> {
     self
   }
 
+  /// Sets the value of the `Credential` `validFrom`.
+  #[must_use]
+  pub fn valid_from(mut self, value: Timestamp) -> Self {
+    self.valid_from = Some(value);
+    self
+  }
+
+  /// Sets the value of the `Credential` `validUntil`.
+  #[must_use]
+  pub fn valid_until(mut self, value: Timestamp) -> Self {
+    self.valid_until = Some(value);
+    self
+  }
+
   /// Adds a value to the `credentialStatus` set.
   #[must_use]
   pub fn status(mut self, value: Status) -> Self {
@@ -141,6 +190,13 @@ impl<T> CredentialBuilder<T> {
     self
   }
 
+  /// Adds a value to the `relatedResource` set.
+  #[must_use]
+  pub fn related_resource(mut self, value: RelatedResource) -> Self {
+    self.related_resource.push(value);
+    self
+  }
+
   /// Sets the value of the `Credential` `nonTransferable` property.
   #[must_use]
   pub fn non_transferable(mut self, value: bool) -> Self {
@@ -152,6 +208,22 @@ impl<T> CredentialBuilder<T> {
   pub fn build(self) -> Result<Credential<T>> {
     Credential::from_builder(self)
   }
+
+  /// Returns a new `Credential` based on the `CredentialBuilder` configuration
+  /// after validating it against its JSON Schemas.
+  ///
+  /// See [`Credential::validate_schema`] for more information.
+  pub fn build_validated<R>(self, registry: R) -> Result<Credential<T>>
+  where
+    T: Serialize,
+    R: SchemaRegistry,
+  {
+    let credential: Credential<T> = self.build()?;
+
+    credential.validate_schema(registry)?;
+
+    Ok(credential)
+  }
 }
 
 impl CredentialBuilder {
@@ -201,7 +273,10 @@ mod tests {
 
   use crate::credential::Credential;
   use crate::credential::CredentialBuilder;
+  use crate::credential::Schema;
   use crate::credential::Subject;
+  use crate::error::Error;
+  use crate::schema::MemoryRegistry;
 
   fn subject() -> Subject {
     let json: Value = json!({
@@ -241,7 +316,7 @@ mod tests {
     assert_eq!(credential.types.get(1).unwrap(), "UniversityDegreeCredential");
     assert_eq!(credential.credential_subject.len(), 1);
     assert_eq!(credential.issuer.url(), "did:example:issuer");
-    assert_eq!(credential.issuance_date.to_string(), "2010-01-01T00:00:00Z");
+    assert_eq!(credential.issuance_date.unwrap().to_string(), "2010-01-01T00:00:00Z");
     assert_eq!(credential.credential_subject.get(0).unwrap().id.as_ref().unwrap(), "did:example:ebfeb1f712ebc6f1c276e12ec21");
     assert_eq!(credential.credential_subject.get(0).unwrap().properties["degree"]["type"], "BachelorDegree");
     assert_eq!(credential.credential_subject.get(0).unwrap().properties["degree"]["name"], "Bachelor of Science and Arts");
@@ -258,4 +333,37 @@ mod tests {
   fn test_builder_missing_issuer() {
     let _: Credential = CredentialBuilder::default().subject(subject()).build().unwrap();
   }
+
+  #[test]
+  fn test_builder_validated() {
+    let schema: Url = Url::parse("https://example.org/examples/degree.json").unwrap();
+    let mut registry: MemoryRegistry = MemoryRegistry::new();
+
+    registry.insert(
+      schema.clone(),
+      json!({
+        "type": "object",
+        "required": ["degree"],
+        "properties": {
+          "degree": { "required": ["type", "name", "level"] }
+        }
+      }),
+    );
+
+    let builder: CredentialBuilder = CredentialBuilder::default()
+      .subject(subject())
+      .issuer(issuer())
+      .schema(Schema::new(schema, "JsonSchemaValidator2018".to_string()));
+
+    let error: Error = builder.build_validated(&registry).unwrap_err();
+
+    match &error {
+      Error::SchemaValidation(errors) => {
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].instance_path, "/credentialSubject/degree");
+        assert_eq!(errors[0].keyword_path, "/properties/degree/required");
+      }
+      _ => panic!("unexpected error: {}", error),
+    }
+  }
 }
@@ -11,8 +11,11 @@ use identity_core::common::OneOrMany;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::ToJson;
+use identity_core::crypto::EddsaJcs2022;
 use identity_core::crypto::JcsEd25519Signature2020;
 use identity_core::crypto::SecretKey;
+use identity_core::crypto::SigName;
+use identity_core::crypto::SigSign;
 use identity_core::crypto::SignatureOptions;
 use identity_did::document::Document;
 use identity_did::verifiable::LdSuite;
@@ -22,20 +25,33 @@ use identity_did::verification::MethodWrap;
 use serde::Serialize;
 
 use crate::credential::CredentialBuilder;
+use crate::credential::CredentialVersion;
 use crate::credential::Evidence;
 use crate::credential::Issuer;
 use crate::credential::Policy;
 use crate::credential::Refresh;
+use crate::credential::RelatedResource;
 use crate::credential::Schema;
 use crate::credential::Status;
 use crate::credential::Subject;
 use crate::credential::VerifiableCredential;
 use crate::error::Error;
 use crate::error::Result;
+use crate::integrity::split_hashlink;
+use crate::integrity::verify_hashlink;
+use crate::integrity::ResourceLoader;
+use crate::jwt::jwt_encode;
+use crate::jwt::CredentialClaims;
+use crate::jwt::JwtHeader;
+use crate::schema::SchemaError;
+use crate::schema::SchemaRegistry;
+use crate::schema::SchemaValidator;
 
-lazy_static! {
-  static ref BASE_CONTEXT: Context = Context::Url(Url::parse("https://www.w3.org/2018/credentials/v1").unwrap());
-}
+// The schema type validating each `credentialSubject`.
+const SCHEMA_SUBJECT: &str = "JsonSchemaValidator2018";
+
+// The schema types validating the entire `Credential`.
+const SCHEMA_CREDENTIAL: &[&str] = &["JsonSchema", "JsonSchema2023"];
 
 /// A `Credential` represents a set of claims describing an entity.
 ///
@@ -51,17 +67,31 @@ pub struct Credential<T = Object> {
   /// One or more URIs defining the type of the `Credential`.
   #[serde(rename = "type")]
   pub types: OneOrMany<String>,
+  /// A human-readable name of the `Credential`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// A human-readable description of the `Credential`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
   /// One or more `Object`s representing the `Credential` subject(s).
   #[serde(rename = "credentialSubject")]
   pub credential_subject: OneOrMany<Subject>,
   /// A reference to the issuer of the `Credential`.
   pub issuer: Issuer,
-  /// A timestamp of when the `Credential` becomes valid.
-  #[serde(rename = "issuanceDate")]
-  pub issuance_date: Timestamp,
-  /// A timestamp of when the `Credential` should no longer be considered valid.
-  #[serde(rename = "expirationDate", skip_serializing_if = "Option::is_none")]
+  /// A timestamp of when the `Credential` becomes valid (VCDM 1.1).
+  ///
+  /// Required by VCDM 1.1 and always `None` for VCDM 2.0, see `valid_from`.
+  #[serde(default, rename = "issuanceDate", skip_serializing_if = "Option::is_none")]
+  pub issuance_date: Option<Timestamp>,
+  /// A timestamp of when the `Credential` should no longer be considered valid (VCDM 1.1).
+  #[serde(default, rename = "expirationDate", skip_serializing_if = "Option::is_none")]
   pub expiration_date: Option<Timestamp>,
+  /// A timestamp of when the `Credential` becomes valid (VCDM 2.0).
+  #[serde(default, rename = "validFrom", skip_serializing_if = "Option::is_none")]
+  pub valid_from: Option<Timestamp>,
+  /// A timestamp of when the `Credential` should no longer be considered valid (VCDM 2.0).
+  #[serde(default, rename = "validUntil", skip_serializing_if = "Option::is_none")]
+  pub valid_until: Option<Timestamp>,
   /// Information used to determine the current status of the `Credential`.
   #[serde(default, rename = "credentialStatus", skip_serializing_if = "OneOrMany::is_empty")]
   pub credential_status: OneOrMany<Status>,
@@ -77,6 +107,9 @@ pub struct Credential<T = Object> {
   /// Human-readable evidence used to support the claims within the `Credential`.
   #[serde(default, skip_serializing_if = "OneOrMany::is_empty")]
   pub evidence: OneOrMany<Evidence>,
+  /// Resources linked from the `Credential` and protected by digests.
+  #[serde(default, rename = "relatedResource", skip_serializing_if = "OneOrMany::is_empty")]
+  pub related_resource: OneOrMany<RelatedResource>,
   /// Indicates that the `Credential` must only be contained within a
   /// `Presentation` with a proof issued from the `Credential` subject.
   #[serde(rename = "nonTransferable", skip_serializing_if = "Option::is_none")]
@@ -88,8 +121,11 @@ pub struct Credential<T = Object> {
 
 impl<T> Credential<T> {
   /// Returns the base JSON-LD context for `Credential`s.
+  ///
+  /// This is the base context of VCDM 1.1; see [`CredentialVersion`] for
+  /// other versions.
   pub fn base_context() -> &'static Context {
-    &*BASE_CONTEXT
+    CredentialVersion::V1_1.base_context()
   }
 
   /// Returns the base type for `Credential`s.
@@ -106,19 +142,30 @@ impl<T> Credential<T> {
 
   /// Returns a new `Credential` based on the `CredentialBuilder` configuration.
   pub fn from_builder(builder: CredentialBuilder<T>) -> Result<Self> {
+    // VCDM 1.1 requires an issuance date
+    let issuance_date: Option<Timestamp> = match builder.context.get(0).and_then(CredentialVersion::from_context) {
+      Some(CredentialVersion::V2_0) => builder.issuance_date,
+      Some(CredentialVersion::V1_1) | None => Some(builder.issuance_date.unwrap_or_default()),
+    };
+
     let this: Self = Self {
       context: builder.context.into(),
       id: builder.id,
       types: builder.types.into(),
+      name: builder.name,
+      description: builder.description,
       credential_subject: builder.subject.into(),
       issuer: builder.issuer.ok_or(Error::MissingIssuer)?,
-      issuance_date: builder.issuance_date.unwrap_or_default(),
+      issuance_date,
       expiration_date: builder.expiration_date,
+      valid_from: builder.valid_from,
+      valid_until: builder.valid_until,
       credential_status: builder.status.into(),
       credential_schema: builder.schema.into(),
       refresh_service: builder.refresh.into(),
       terms_of_use: builder.policy.into(),
       evidence: builder.evidence.into(),
+      related_resource: builder.related_resource.into(),
       non_transferable: builder.non_transferable,
       properties: builder.properties,
     };
@@ -128,12 +175,52 @@ impl<T> Credential<T> {
     Ok(this)
   }
 
+  /// Returns the data model version of the `Credential`, if the base context
+  /// is present.
+  pub fn version(&self) -> Option<CredentialVersion> {
+    self.context.get(0).and_then(CredentialVersion::from_context)
+  }
+
+  /// Returns the timestamp of when the `Credential` becomes valid:
+  /// `issuanceDate` (VCDM 1.1) or `validFrom` (VCDM 2.0).
+  pub fn validity_start(&self) -> Option<Timestamp> {
+    match self.version() {
+      Some(CredentialVersion::V2_0) => self.valid_from,
+      Some(CredentialVersion::V1_1) | None => self.issuance_date,
+    }
+  }
+
+  /// Returns the timestamp of when the `Credential` is no longer valid:
+  /// `expirationDate` (VCDM 1.1) or `validUntil` (VCDM 2.0).
+  pub fn validity_end(&self) -> Option<Timestamp> {
+    match self.version() {
+      Some(CredentialVersion::V2_0) => self.valid_until,
+      Some(CredentialVersion::V1_1) | None => self.expiration_date,
+    }
+  }
+
   /// Validates the semantic structure of the `Credential`.
+  ///
+  /// The rules applied depend on the data model version of the `Credential`.
   pub fn check_structure(&self) -> Result<()> {
     // Ensure the base context is present and in the correct location
-    match self.context.get(0) {
-      Some(context) if context == Self::base_context() => {}
-      Some(_) | None => return Err(Error::MissingBaseContext),
+    let version: CredentialVersion = self.version().ok_or(Error::MissingBaseContext)?;
+
+    match version {
+      CredentialVersion::V1_1 => {
+        if self.issuance_date.is_none() {
+          return Err(Error::MissingIssuanceDate);
+        }
+
+        if self.valid_from.is_some() || self.valid_until.is_some() {
+          return Err(Error::IncompatibleVersion);
+        }
+      }
+      CredentialVersion::V2_0 => {
+        if self.issuance_date.is_some() || self.expiration_date.is_some() {
+          return Err(Error::IncompatibleVersion);
+        }
+      }
     }
 
     // The set of types MUST contain the base type
@@ -156,6 +243,145 @@ impl<T> Credential<T> {
     Ok(())
   }
 
+  /// Returns the Urls of all resources linked from the `Credential`: JSON-LD
+  /// contexts, schemas, refresh services, and evidence.
+  pub fn linked_resources(&self) -> Vec<Url> {
+    let contexts = self.context.iter().filter_map(|context| match context {
+      Context::Url(url) => Some(url.clone()),
+      Context::Obj(_) => None,
+    });
+
+    let schemas = self.credential_schema.iter().map(|schema| schema.id.clone());
+    let refresh = self.refresh_service.iter().map(|refresh| refresh.id.clone());
+
+    let evidence = self
+      .evidence
+      .iter()
+      .filter_map(|evidence| evidence.id.as_deref())
+      .filter_map(|id| Url::parse(id).ok());
+
+    contexts.chain(schemas).chain(refresh).chain(evidence).collect()
+  }
+
+  /// Verifies the integrity of all resources linked from the `Credential`
+  /// with the contents fetched from `loader`.
+  ///
+  /// Every `relatedResource` is checked against its digests and every linked
+  /// resource with a hashlink (`hl`) query parameter against the hashlink.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a resource cannot be loaded, does not match its digest, or if a
+  /// `credentialSchema` is not integrity protected.
+  pub fn verify_integrity<L>(&self, loader: L) -> Result<()>
+  where
+    L: ResourceLoader,
+  {
+    for resource in self.related_resource.iter() {
+      resource.verify(&loader.load(&resource.id)?)?;
+    }
+
+    for url in self.linked_resources() {
+      if let (base, Some(hashlink)) = split_hashlink(&url) {
+        verify_hashlink(&hashlink, &loader.load(&base)?)?;
+      }
+    }
+
+    // Schemas MUST NOT change after issuance
+    for schema in self.credential_schema.iter() {
+      if !self.is_integrity_protected(&schema.id) {
+        return Err(Error::MissingResourceDigest);
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Returns `true` if the resource identified by `url` is protected by a
+  /// hashlink or a `relatedResource` digest.
+  pub fn is_integrity_protected(&self, url: &Url) -> bool {
+    split_hashlink(url).1.is_some() || self.related_resource.iter().any(|resource| resource.id == *url)
+  }
+
+  /// Validates the `Credential` against its JSON Schemas, which are resolved
+  /// from `registry`.
+  ///
+  /// `JsonSchemaValidator2018` schemas are applied to every
+  /// `credentialSubject` and `JsonSchema` schemas to the entire `Credential`;
+  /// other schema types are ignored.
+  ///
+  /// # Errors
+  ///
+  /// Fails with [`Error::SchemaValidation`] if the `Credential` does not
+  /// conform to a schema, or if a schema cannot be resolved.
+  pub fn validate_schema<R>(&self, registry: R) -> Result<()>
+  where
+    T: Serialize,
+    R: SchemaRegistry,
+  {
+    let validator: SchemaValidator<'_> = SchemaValidator::new(&registry);
+    let mut errors: Vec<SchemaError> = Vec::new();
+
+    for schema in self.credential_schema.iter() {
+      let (url, _): (Url, Option<String>) = split_hashlink(&schema.id);
+
+      if schema.types.iter().any(|type_| type_ == SCHEMA_SUBJECT) {
+        for (index, subject) in self.credential_subject.iter().enumerate() {
+          let prefix: String = match self.credential_subject {
+            OneOrMany::One(_) => "/credentialSubject".to_string(),
+            OneOrMany::Many(_) => format!("/credentialSubject/{}", index),
+          };
+
+          let report: Vec<SchemaError> = validator.validate(&url, &subject.to_json_value()?)?;
+
+          errors.extend(report.into_iter().map(|error| error.prefixed(&prefix)));
+        }
+      }
+
+      if schema
+        .types
+        .iter()
+        .any(|type_| SCHEMA_CREDENTIAL.contains(&type_.as_str()))
+      {
+        errors.extend(validator.validate(&url, &self.to_json_value()?)?);
+      }
+    }
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(Error::SchemaValidation(errors))
+    }
+  }
+
+  /// Converts the `Credential` to the data model `version`.
+  ///
+  /// The validity period is mapped between `issuanceDate`/`expirationDate`
+  /// and `validFrom`/`validUntil`. Converting to VCDM 1.1 fails without a
+  /// `validFrom` as VCDM 1.1 requires an issuance date.
+  pub fn into_version(mut self, version: CredentialVersion) -> Result<Self> {
+    if self.version().ok_or(Error::MissingBaseContext)? == version {
+      return Ok(self);
+    }
+
+    match version {
+      CredentialVersion::V1_1 => {
+        self.issuance_date = Some(self.valid_from.take().ok_or(Error::MissingIssuanceDate)?);
+        self.expiration_date = self.valid_until.take();
+      }
+      CredentialVersion::V2_0 => {
+        self.valid_from = self.issuance_date.take();
+        self.valid_until = self.expiration_date.take();
+      }
+    }
+
+    *self.context.get_mut(0).expect("infallible") = version.base_context().clone();
+
+    self.check_structure()?;
+
+    Ok(self)
+  }
+
   /// Creates a new [`VerifiableCredential`] by signing `self` with `document`
   /// and `secret`.
   pub fn sign<'a, Q, D1, D2, D3>(
@@ -167,13 +393,44 @@ impl<T> Credential<T> {
   where
     T: Serialize,
     Q: Into<MethodQuery<'a>>,
+  {
+    self.sign_with(JcsEd25519Signature2020, document, query, secret)
+  }
+
+  /// Creates a new [`VerifiableCredential`] by signing `self` with `document`
+  /// and `secret` as a `DataIntegrityProof` using the `eddsa-jcs-2022`
+  /// cryptosuite.
+  pub fn sign_data_integrity<'a, Q, D1, D2, D3>(
+    self,
+    document: &Document<D1, D2, D3>,
+    query: Q,
+    secret: &SecretKey,
+  ) -> Result<VerifiableCredential<T>>
+  where
+    T: Serialize,
+    Q: Into<MethodQuery<'a>>,
+  {
+    self.sign_with(EddsaJcs2022, document, query, secret)
+  }
+
+  fn sign_with<'a, S, Q, D1, D2, D3>(
+    self,
+    suite: S,
+    document: &Document<D1, D2, D3>,
+    query: Q,
+    secret: &SecretKey,
+  ) -> Result<VerifiableCredential<T>>
+  where
+    T: Serialize,
+    S: SigSign + SigName,
+    Q: Into<MethodQuery<'a>>,
   {
     let method: MethodWrap<'_, D2> = document.try_resolve(query)?;
 
     match method.key_type() {
       MethodType::Ed25519VerificationKey2018 => {
         let options: SignatureOptions = method.into();
-        let suite: LdSuite<_> = LdSuite::new(JcsEd25519Signature2020);
+        let suite: LdSuite<S> = LdSuite::new(suite);
 
         let mut verifiable: VerifiableCredential<T> = VerifiableCredential::new(self, Vec::new());
 
@@ -184,6 +441,46 @@ impl<T> Credential<T> {
       _ => Err(Error::DIDError(identity_did::Error::UnknownMethodType)),
     }
   }
+
+  /// Encodes `self` as a VC-JWT signed with `document` and `secret`.
+  ///
+  /// The registered JWT claims are derived from the `Credential` which is
+  /// included as the `vc` claim in its entirety.
+  pub fn sign_jwt<'a, Q, D1, D2, D3>(
+    &self,
+    document: &Document<D1, D2, D3>,
+    query: Q,
+    secret: &SecretKey,
+  ) -> Result<String>
+  where
+    T: Serialize,
+    Q: Into<MethodQuery<'a>>,
+  {
+    CredentialClaims::new(self)?.sign(document, query, secret)
+  }
+
+  /// Encodes `self` as a JWS with the media type `vc+jwt` signed with
+  /// `document` and `secret`.
+  ///
+  /// Unlike a VC-JWT, the claims set is the VCDM 2.0 `Credential` itself.
+  ///
+  /// [More Info](https://www.w3.org/TR/vc-jose-cose/#securing-with-jose)
+  pub fn sign_jose<'a, Q, D1, D2, D3>(
+    &self,
+    document: &Document<D1, D2, D3>,
+    query: Q,
+    secret: &SecretKey,
+  ) -> Result<String>
+  where
+    T: Serialize,
+    Q: Into<MethodQuery<'a>>,
+  {
+    if self.version() != Some(CredentialVersion::V2_0) {
+      return Err(Error::IncompatibleVersion);
+    }
+
+    jwt_encode(self, JwtHeader::TYPE_VC, document, query, secret)
+  }
 }
 
 impl<T> Display for Credential<T>
@@ -201,9 +498,22 @@ where
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_core::common::Value;
   use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use serde_json::json;
 
   use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::CredentialVersion;
+  use crate::credential::RelatedResource;
+  use crate::credential::Schema;
+  use crate::credential::Subject;
+  use crate::error::Error;
+  use crate::integrity::hashlink_url;
+  use crate::integrity::MemoryLoader;
 
   const JSON1: &str = include_str!("../../tests/fixtures/credential-1.json");
   const JSON2: &str = include_str!("../../tests/fixtures/credential-2.json");
@@ -233,4 +543,124 @@ mod tests {
     let _credential: Credential = Credential::from_json(JSON11).unwrap();
     let _credential: Credential = Credential::from_json(JSON12).unwrap();
   }
+
+  #[test]
+  fn test_build_v2() {
+    let credential: Credential = CredentialBuilder::default()
+      .version(CredentialVersion::V2_0)
+      .name("Example Credential")
+      .subject(Subject::from_json_value(json!({ "id": "did:example:123" })).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .valid_from("2010-01-01T00:00:00Z".parse().unwrap())
+      .build()
+      .unwrap();
+
+    assert_eq!(credential.version(), Some(CredentialVersion::V2_0));
+    assert_eq!(credential.validity_start(), credential.valid_from);
+    assert_eq!(credential.issuance_date, None);
+    assert!(credential.to_json_value().unwrap().get("issuanceDate").is_none());
+    assert_eq!(
+      Credential::from_json(&credential.to_json().unwrap()).unwrap(),
+      credential
+    );
+
+    let mut invalid: Credential = credential.clone();
+    invalid.expiration_date = credential.valid_from;
+
+    assert!(matches!(invalid.check_structure(), Err(Error::IncompatibleVersion)));
+
+    let mut json: Value = credential.to_json_value().unwrap();
+    json["issuanceDate"] = json!("2010-01-01T00:00:00Z");
+
+    assert!(matches!(
+      Credential::<Object>::from_json_value(json).unwrap().check_structure(),
+      Err(Error::IncompatibleVersion)
+    ));
+  }
+
+  #[test]
+  fn test_v1_name_description() {
+    let credential: Credential = CredentialBuilder::default()
+      .name("Example Credential")
+      .description("An example credential")
+      .subject(Subject::from_json_value(json!({ "id": "did:example:123" })).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .issuance_date("2010-01-01T00:00:00Z".parse().unwrap())
+      .build()
+      .unwrap();
+
+    assert_eq!(credential.version(), Some(CredentialVersion::V1_1));
+    assert_eq!(credential.name.as_deref(), Some("Example Credential"));
+
+    let mut invalid: Credential = credential.clone();
+    invalid.valid_from = credential.issuance_date;
+
+    assert!(matches!(invalid.check_structure(), Err(Error::IncompatibleVersion)));
+
+    let mut json: Value = credential.to_json_value().unwrap();
+    json.as_object_mut().unwrap().remove("issuanceDate");
+
+    assert!(matches!(
+      Credential::<Object>::from_json_value(json).unwrap().check_structure(),
+      Err(Error::MissingIssuanceDate)
+    ));
+  }
+
+  #[test]
+  fn test_into_version() {
+    let credential: Credential = CredentialBuilder::default()
+      .subject(Subject::from_json_value(json!({ "id": "did:example:123" })).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .issuance_date("2010-01-01T00:00:00Z".parse().unwrap())
+      .expiration_date("2020-01-01T00:00:00Z".parse().unwrap())
+      .build()
+      .unwrap();
+
+    let converted: Credential = credential.clone().into_version(CredentialVersion::V2_0).unwrap();
+
+    assert_eq!(converted.context.get(0), Some(CredentialVersion::V2_0.base_context()));
+    assert_eq!(converted.issuance_date, None);
+    assert_eq!(converted.valid_from, credential.issuance_date);
+    assert_eq!(converted.valid_until, credential.expiration_date);
+    assert_eq!(converted.into_version(CredentialVersion::V1_1).unwrap(), credential);
+  }
+
+  #[test]
+  fn test_verify_integrity() {
+    let schema: Url = Url::parse("https://example.org/examples/degree.json").unwrap();
+    let context: Url = Url::parse("https://www.w3.org/2018/credentials/examples/v1").unwrap();
+    let subject: Subject = Subject::from_json_value(json!({ "id": "did:example:123" })).unwrap();
+
+    let mut loader: MemoryLoader = MemoryLoader::new();
+    loader.insert(schema.clone(), &b"{}"[..]);
+    loader.insert(context.clone(), &b"{\"@context\":{}}"[..]);
+
+    let builder: CredentialBuilder = CredentialBuilder::default()
+      .context(hashlink_url(&context, b"{\"@context\":{}}"))
+      .subject(subject)
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .schema(Schema::new(schema.clone(), "JsonSchemaValidator2018".to_string()));
+
+    let credential: Credential = builder.clone().build().unwrap();
+
+    assert!(matches!(
+      credential.verify_integrity(&loader),
+      Err(Error::MissingResourceDigest)
+    ));
+
+    let credential: Credential = builder
+      .related_resource(RelatedResource::load(schema.clone(), &loader).unwrap())
+      .build()
+      .unwrap();
+
+    assert!(credential.is_integrity_protected(&schema));
+    assert!(credential.verify_integrity(&loader).is_ok());
+
+    loader.insert(schema, &b"{\"type\":\"string\"}"[..]);
+
+    assert!(matches!(
+      credential.verify_integrity(&loader),
+      Err(Error::InvalidResourceDigest)
+    ));
+  }
 }
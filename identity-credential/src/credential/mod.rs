@@ -7,22 +7,28 @@
 
 mod builder;
 mod credential;
+mod enveloped;
 mod evidence;
 mod issuer;
 mod policy;
 mod refresh;
+mod related_resource;
 mod schema;
 mod status;
 mod subject;
 mod verifiable;
+mod version;
 
 pub use self::builder::CredentialBuilder;
 pub use self::credential::Credential;
+pub use self::enveloped::EnvelopedCredential;
 pub use self::evidence::Evidence;
 pub use self::issuer::Issuer;
 pub use self::policy::Policy;
 pub use self::refresh::Refresh;
+pub use self::related_resource::RelatedResource;
 pub use self::schema::Schema;
 pub use self::status::Status;
 pub use self::subject::Subject;
 pub use self::verifiable::VerifiableCredential;
+pub use self::version::CredentialVersion;
@@ -0,0 +1,114 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::integrity::digest_multibase;
+use crate::integrity::digest_sri;
+use crate::integrity::verify_digest_multibase;
+use crate::integrity::verify_digest_sri;
+use crate::integrity::DigestAlgorithm;
+use crate::integrity::ResourceLoader;
+
+/// A resource linked from a `Credential` and protected by one or more
+/// digests of its contents.
+///
+/// [More Info](https://www.w3.org/TR/vc-data-model-2.0/#integrity-of-related-resources)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RelatedResource {
+  /// The Url of the related resource.
+  pub id: Url,
+  /// A Subresource Integrity digest of the resource.
+  #[serde(rename = "digestSRI", skip_serializing_if = "Option::is_none")]
+  pub digest_sri: Option<String>,
+  /// A multibase-encoded multihash digest of the resource.
+  #[serde(rename = "digestMultibase", skip_serializing_if = "Option::is_none")]
+  pub digest_multibase: Option<String>,
+  /// The media type of the resource.
+  #[serde(rename = "mediaType", skip_serializing_if = "Option::is_none")]
+  pub media_type: Option<String>,
+}
+
+impl RelatedResource {
+  /// Creates a new [`RelatedResource`] with digests of the given `data`.
+  pub fn new(id: Url, data: &[u8]) -> Self {
+    Self {
+      id,
+      digest_sri: Some(digest_sri(DigestAlgorithm::Sha384, data)),
+      digest_multibase: Some(digest_multibase(DigestAlgorithm::Sha256, data)),
+      media_type: None,
+    }
+  }
+
+  /// Creates a new [`RelatedResource`] with digests of the resource fetched
+  /// from `loader`.
+  pub fn load<L>(id: Url, loader: L) -> Result<Self>
+  where
+    L: ResourceLoader,
+  {
+    let data: Vec<u8> = loader.load(&id)?;
+
+    Ok(Self::new(id, &data))
+  }
+
+  /// Sets the media type of the resource.
+  #[must_use]
+  pub fn with_media_type(mut self, value: impl Into<String>) -> Self {
+    self.media_type = Some(value.into());
+    self
+  }
+
+  /// Verifies that `data` matches all digests of the resource.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the resource has no digest or any digest does not match.
+  pub fn verify(&self, data: &[u8]) -> Result<()> {
+    if self.digest_sri.is_none() && self.digest_multibase.is_none() {
+      return Err(Error::MissingResourceDigest);
+    }
+
+    if let Some(digest) = self.digest_sri.as_deref() {
+      verify_digest_sri(digest, data)?;
+    }
+
+    if let Some(digest) = self.digest_multibase.as_deref() {
+      verify_digest_multibase(digest, data)?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use crate::credential::RelatedResource;
+  use crate::integrity::MemoryLoader;
+
+  const SCHEMA: &[u8] = br#"{"type":"object"}"#;
+
+  #[test]
+  fn test_verify() {
+    let mut loader: MemoryLoader = MemoryLoader::new();
+    loader.insert("https://example.org/schema.json".parse().unwrap(), SCHEMA);
+
+    let resource: RelatedResource = RelatedResource::load("https://example.org/schema.json".parse().unwrap(), &loader)
+      .unwrap()
+      .with_media_type("application/schema+json");
+
+    assert!(resource.verify(SCHEMA).is_ok());
+    assert!(resource.verify(br#"{"type":"string"}"#).is_err());
+
+    let json: String = resource.to_json().unwrap();
+    let resource: RelatedResource = RelatedResource::from_json(&json).unwrap();
+
+    assert!(json.contains("digestSRI"));
+    assert!(json.contains("digestMultibase"));
+    assert!(resource.verify(SCHEMA).is_ok());
+  }
+}
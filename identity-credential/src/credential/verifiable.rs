@@ -9,14 +9,28 @@ use core::ops::Deref;
 use core::ops::DerefMut;
 use identity_core::common::Object;
 use identity_core::common::OneOrMany;
+use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureOptions;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
+use identity_did::document::Document;
+use identity_did::verification::MethodScope;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::credential::Credential;
+use crate::credential::CredentialVersion;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jwt::jwt_decode;
+use crate::jwt::jwt_decode_unverified;
+use crate::jwt::jwt_proof;
+use crate::jwt::jwt_token;
+use crate::jwt::CredentialClaims;
+use crate::jwt::JwtHeader;
 
 /// A `VerifiableCredential` represents a `Credential` with an associated
 /// digital proof.
@@ -49,6 +63,67 @@ impl<T> VerifiableCredential<T> {
   pub fn proof_mut(&mut self) -> &mut OneOrMany<Signature> {
     &mut self.proof
   }
+
+  /// Verifies and decodes a VC-JWT issued by the DID document `issuer`.
+  ///
+  /// The token is attached to the decoded credential as a `JwtProof2020` proof.
+  pub fn from_jwt<D1, D2, D3>(token: &str, issuer: &Document<D1, D2, D3>) -> Result<Self>
+  where
+    T: DeserializeOwned,
+  {
+    let (options, claims): (SignatureOptions, Vec<u8>) =
+      jwt_decode(token, JwtHeader::TYPE, MethodScope::AssertionMethod, issuer)?;
+    let credential: Credential<T> = CredentialClaims::from_json_slice(&claims)?.into_credential()?;
+
+    if credential.issuer.url().as_str() != issuer.id().as_str() {
+      return Err(Error::IssuerMismatch);
+    }
+
+    Ok(Self::new(credential, jwt_proof(options, token)))
+  }
+
+  /// Verifies and decodes a JWS with the media type `vc+jwt` issued by the
+  /// DID document `issuer`.
+  ///
+  /// The token is attached to the decoded credential as a `JwtProof2020` proof.
+  ///
+  /// [More Info](https://www.w3.org/TR/vc-jose-cose/#securing-with-jose)
+  pub fn from_jose<D1, D2, D3>(token: &str, issuer: &Document<D1, D2, D3>) -> Result<Self>
+  where
+    T: DeserializeOwned,
+  {
+    let (options, claims): (SignatureOptions, Vec<u8>) =
+      jwt_decode(token, JwtHeader::TYPE_VC, MethodScope::AssertionMethod, issuer)?;
+    let credential: Credential<T> = Credential::from_json_slice(&claims)?;
+
+    if credential.version() != Some(CredentialVersion::V2_0) {
+      return Err(Error::IncompatibleVersion);
+    }
+
+    if credential.issuer.url().as_str() != issuer.id().as_str() {
+      return Err(Error::IssuerMismatch);
+    }
+
+    Ok(Self::new(credential, jwt_proof(options, token)))
+  }
+
+  /// Decodes a VC-JWT WITHOUT verifying the signature.
+  pub fn from_jwt_unverified(token: &str) -> Result<Self>
+  where
+    T: DeserializeOwned,
+  {
+    let (header, claims): (JwtHeader, Vec<u8>) = jwt_decode_unverified(token)?;
+    let credential: Credential<T> = CredentialClaims::from_json_slice(&claims)?.into_credential()?;
+    let options: SignatureOptions = SignatureOptions::new(header.kid.unwrap_or_default());
+
+    Ok(Self::new(credential, jwt_proof(options, token)))
+  }
+
+  /// Returns the VC-JWT or `vc+jwt` token the `VerifiableCredential` was
+  /// decoded from, if any.
+  pub fn jwt(&self) -> Option<&str> {
+    self.proof.get(0).and_then(jwt_token)
+  }
 }
 
 impl<T> Deref for VerifiableCredential<T> {
@@ -0,0 +1,65 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Context;
+use identity_core::common::Url;
+
+lazy_static! {
+  static ref CONTEXT_V1: Context = Context::Url(Url::parse("https://www.w3.org/2018/credentials/v1").unwrap());
+  static ref CONTEXT_V2: Context = Context::Url(Url::parse("https://www.w3.org/ns/credentials/v2").unwrap());
+}
+
+/// A version of the Verifiable Credentials Data Model.
+///
+/// The version of a `Credential` or `Presentation` is determined by its
+/// first JSON-LD context.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CredentialVersion {
+  /// [VC Data Model 1.1](https://www.w3.org/TR/vc-data-model/)
+  V1_1,
+  /// [VC Data Model 2.0](https://www.w3.org/TR/vc-data-model-2.0/)
+  V2_0,
+}
+
+impl CredentialVersion {
+  /// Returns the base JSON-LD context of the data model version.
+  pub fn base_context(self) -> &'static Context {
+    match self {
+      Self::V1_1 => &*CONTEXT_V1,
+      Self::V2_0 => &*CONTEXT_V2,
+    }
+  }
+
+  /// Returns the data model version with the given base JSON-LD `context`.
+  pub fn from_context(context: &Context) -> Option<Self> {
+    if context == &*CONTEXT_V1 {
+      Some(Self::V1_1)
+    } else if context == &*CONTEXT_V2 {
+      Some(Self::V2_0)
+    } else {
+      None
+    }
+  }
+}
+
+impl Default for CredentialVersion {
+  fn default() -> Self {
+    Self::V1_1
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_from_context() {
+    for version in [CredentialVersion::V1_1, CredentialVersion::V2_0].iter() {
+      assert_eq!(CredentialVersion::from_context(version.base_context()), Some(*version));
+    }
+
+    let context: Context = Context::Url(Url::parse("https://www.w3.org/2018/credentials/examples/v1").unwrap());
+
+    assert_eq!(CredentialVersion::from_context(&context), None);
+  }
+}
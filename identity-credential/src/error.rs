@@ -3,12 +3,17 @@
 
 //! Errors that may occur when working with Verifiable Credentials.
 
+use crate::schema::SchemaError;
+
 /// Alias for a `Result` with the error type [`Error`].
 pub type Result<T, E = Error> = ::core::result::Result<T, E>;
 
 /// This type represents all possible errors that can occur in the library.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+  /// Caused by errors from the `identity_core` crate.
+  #[error("{0}")]
+  CoreError(#[from] identity_core::Error),
   /// Caused by errors from the `identity_did` crate.
   #[error("{0}")]
   DIDError(#[from] identity_did::Error),
@@ -21,10 +26,94 @@ pub enum Error {
   /// Caused when validating a Credential without an issuer.
   #[error("Missing Credential Issuer")]
   MissingIssuer,
+  /// Caused when validating a VCDM 1.1 Credential without an issuance date.
+  #[error("Missing Issuance Date")]
+  MissingIssuanceDate,
+  /// Caused when a Credential contains properties of another data model version.
+  #[error("Incompatible Data Model Version")]
+  IncompatibleVersion,
+  /// Caused when an enveloped Credential has a malformed or unsupported payload.
+  #[error("Invalid Enveloped Credential")]
+  InvalidEnvelope,
   /// Caused when validating a Credential without a subject.
   #[error("Missing Credential Subject")]
   MissingSubject,
   /// Caused when validating a Credential with a malformed subject.
   #[error("Invalid Credential Subject")]
   InvalidSubject,
+  /// Caused when a linked resource is not integrity protected.
+  #[error("Missing Resource Digest")]
+  MissingResourceDigest,
+  /// Caused when a linked resource does not match its digest.
+  #[error("Invalid Resource Digest")]
+  InvalidResourceDigest,
+  /// Caused when a resource digest uses an unsupported algorithm.
+  #[error("Unsupported Digest Algorithm")]
+  UnsupportedDigestAlgorithm,
+  /// Caused when a linked resource cannot be loaded.
+  #[error("Resource Not Found")]
+  ResourceNotFound,
+  /// Caused when a Credential is not yet valid.
+  #[error("Invalid Issuance Date")]
+  InvalidIssuanceDate,
+  /// Caused when a Credential has expired.
+  #[error("Expired Credential")]
+  ExpiredCredential,
+  /// Caused when a Credential has been revoked.
+  #[error("Revoked Credential")]
+  RevokedCredential,
+  /// Caused when a Credential issuer is not trusted.
+  #[error("Untrusted Credential Issuer")]
+  UntrustedIssuer,
+  /// Caused when a Credential issuer does not match the issuer DID document.
+  #[error("Issuer Document Mismatch")]
+  IssuerMismatch,
+  /// Caused when a proof has an unexpected proof purpose.
+  #[error("Invalid Proof Purpose")]
+  InvalidProofPurpose,
+  /// Caused when a proof uses a signature suite the verifier cannot check.
+  #[error("Unsupported Signature Type: {0}")]
+  UnsupportedSignatureType(String),
+  /// Caused when a JWT is malformed.
+  #[error("Invalid JWT")]
+  InvalidJwt,
+  /// Caused when a JWT signature cannot be verified.
+  #[error("Invalid JWT Signature")]
+  InvalidJwtSignature,
+  /// Caused when the JWT claims do not match the encoded Credential/Presentation.
+  #[error("Invalid JWT Claims")]
+  InvalidJwtClaims,
+  /// Caused when an SD-JWT disclosure is malformed or not referenced by the issuer.
+  #[error("Invalid SD-JWT Disclosure")]
+  InvalidDisclosure,
+  /// Caused when an SD-JWT key binding is missing or invalid.
+  #[error("Invalid SD-JWT Key Binding")]
+  InvalidKeyBinding,
+  /// Caused when a Merkle tree inclusion proof of a claim is invalid.
+  #[error("Invalid Merkle Proof")]
+  InvalidMerkleProof,
+  /// Caused when a JSON Schema is malformed or cannot be resolved.
+  #[error("Invalid JSON Schema")]
+  InvalidSchema,
+  /// Caused when a Credential does not conform to its JSON Schema(s).
+  #[error("Schema Validation Failed: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+  SchemaValidation(Vec<SchemaError>),
+  /// Caused when parsing a malformed or unsupported JSONPath expression.
+  #[error("Invalid JSON Path")]
+  InvalidJsonPath,
+  /// Caused when a presentation submission is missing or malformed.
+  #[error("Invalid Presentation Submission")]
+  InvalidSubmission,
+  /// Caused when no submitted credential satisfies an input descriptor.
+  #[error("Unsatisfied Input Descriptor: {0}")]
+  UnsatisfiedInputDescriptor(String),
+  /// Caused when a credential is not held by a wallet.
+  #[error("Credential Not Found")]
+  CredentialNotFound,
+  /// Caused when a storage key contains unsupported characters.
+  #[error("Invalid Storage Key")]
+  InvalidStorageKey,
+  /// Caused by errors when reading or writing stored credentials.
+  #[error("Storage Error: {0}")]
+  StorageError(#[from] std::io::Error),
 }
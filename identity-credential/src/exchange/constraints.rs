@@ -0,0 +1,34 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Value;
+
+use crate::error::Result;
+use crate::exchange::Field;
+
+/// The constraints a credential must satisfy to match an `InputDescriptor`.
+///
+/// [More Info](https://identity.foundation/presentation-exchange/#input-descriptor-object)
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Constraints {
+  /// The constrained properties of the credential.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub fields: Vec<Field>,
+  /// Whether the holder should limit disclosure to the constrained
+  /// properties (`required` or `preferred`).
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub limit_disclosure: Option<String>,
+}
+
+impl Constraints {
+  /// Returns `true` if `value` satisfies all non-optional fields.
+  pub fn matches(&self, value: &Value) -> Result<bool> {
+    for field in self.fields.iter().filter(|field| !field.optional) {
+      if !field.matches(value)? {
+        return Ok(false);
+      }
+    }
+
+    Ok(true)
+  }
+}
@@ -0,0 +1,37 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Maps an `InputDescriptor` to the submitted credential satisfying it.
+///
+/// [More Info](https://identity.foundation/presentation-exchange/#presentation-submission)
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DescriptorMapping {
+  /// The `id` of the satisfied `InputDescriptor`.
+  pub id: String,
+  /// The claim format of the submitted credential, e.g. `ldp_vc` or `jwt_vc`.
+  pub format: String,
+  /// A JSONPath expression selecting the credential in the presentation.
+  pub path: String,
+  /// A mapping into the value selected by `path`, e.g. a credential within
+  /// an enveloping presentation.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub path_nested: Option<Box<DescriptorMapping>>,
+}
+
+impl DescriptorMapping {
+  /// The claim format of a credential secured with a Linked Data proof.
+  pub const FORMAT_LDP_VC: &'static str = "ldp_vc";
+
+  /// The claim format of a VC-JWT.
+  pub const FORMAT_JWT_VC: &'static str = "jwt_vc";
+
+  /// Creates a new `DescriptorMapping`.
+  pub fn new(id: impl Into<String>, format: impl Into<String>, path: impl Into<String>) -> Self {
+    Self {
+      id: id.into(),
+      format: format.into(),
+      path: path.into(),
+      path_nested: None,
+    }
+  }
+}
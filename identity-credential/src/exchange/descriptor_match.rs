@@ -0,0 +1,38 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+
+use crate::credential::VerifiableCredential;
+use crate::exchange::InputDescriptor;
+
+/// The candidate credentials matching an `InputDescriptor`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescriptorMatch<'a, T = Object> {
+  descriptor: &'a InputDescriptor,
+  credentials: Vec<&'a VerifiableCredential<T>>,
+}
+
+impl<'a, T> DescriptorMatch<'a, T> {
+  pub(crate) fn new(descriptor: &'a InputDescriptor, credentials: Vec<&'a VerifiableCredential<T>>) -> Self {
+    Self {
+      descriptor,
+      credentials,
+    }
+  }
+
+  /// Returns the matched `InputDescriptor`.
+  pub fn descriptor(&self) -> &'a InputDescriptor {
+    self.descriptor
+  }
+
+  /// Returns the candidate credentials, in the order they were given.
+  pub fn credentials(&self) -> &[&'a VerifiableCredential<T>] {
+    &self.credentials
+  }
+
+  /// Returns `true` if at least one candidate credential was found.
+  pub fn is_satisfied(&self) -> bool {
+    !self.credentials.is_empty()
+  }
+}
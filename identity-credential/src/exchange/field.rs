@@ -0,0 +1,79 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Value;
+
+use crate::error::Result;
+use crate::exchange::JsonPath;
+use crate::schema::MemoryRegistry;
+use crate::schema::SchemaValidator;
+
+/// A constraint on a property of a credential, selected by one or more
+/// JSONPath expressions and optionally filtered by a JSON Schema.
+///
+/// [More Info](https://identity.foundation/presentation-exchange/#input-descriptor-object)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Field {
+  /// An optional identifier of the `Field`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  /// JSONPath expressions selecting the constrained property; the first
+  /// expression yielding a matching value is used.
+  pub path: Vec<String>,
+  /// A human-readable name of the `Field`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// The purpose for which the property is requested.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// A JSON Schema the selected value must be valid against.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub filter: Option<Value>,
+  /// Indicates that the `Field` does not need to be satisfied.
+  #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+  pub optional: bool,
+}
+
+impl Field {
+  /// Creates a new `Field` selecting the property at `path`.
+  pub fn new(path: impl Into<String>) -> Self {
+    Self {
+      id: None,
+      path: vec![path.into()],
+      name: None,
+      purpose: None,
+      filter: None,
+      optional: false,
+    }
+  }
+
+  /// Returns the first value in `value` selected by a path of the `Field`
+  /// that passes the filter, if any.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a path or the filter is malformed.
+  pub fn select<'a>(&self, value: &'a Value) -> Result<Option<&'a Value>> {
+    let registry: MemoryRegistry = MemoryRegistry::new();
+    let validator: SchemaValidator<'_> = SchemaValidator::new(&registry);
+
+    for path in self.path.iter() {
+      for candidate in JsonPath::parse(path)?.select(value) {
+        match self.filter.as_ref() {
+          Some(filter) if !validator.validate_schema(filter, candidate)?.is_empty() => {}
+          Some(_) | None => return Ok(Some(candidate)),
+        }
+      }
+    }
+
+    Ok(None)
+  }
+
+  /// Returns `true` if a path of the `Field` selects a value in `value`
+  /// that passes the filter.
+  ///
+  /// Note: This ignores whether the `Field` is `optional`.
+  pub fn matches(&self, value: &Value) -> Result<bool> {
+    self.select(value).map(|value| value.is_some())
+  }
+}
@@ -0,0 +1,51 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Value;
+use identity_core::convert::ToJson;
+use serde::Serialize;
+
+use crate::credential::VerifiableCredential;
+use crate::error::Result;
+use crate::exchange::Constraints;
+
+/// A description of a credential requested by a `PresentationDefinition`.
+///
+/// [More Info](https://identity.foundation/presentation-exchange/#input-descriptor-object)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct InputDescriptor {
+  /// The identifier of the `InputDescriptor`, unique within its definition.
+  pub id: String,
+  /// A human-readable name of the `InputDescriptor`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// The purpose for which the credential is requested.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// The constraints a matching credential must satisfy.
+  #[serde(default)]
+  pub constraints: Constraints,
+}
+
+impl InputDescriptor {
+  /// Creates a new `InputDescriptor` with the given `id` and `constraints`.
+  pub fn new(id: impl Into<String>, constraints: Constraints) -> Self {
+    Self {
+      id: id.into(),
+      name: None,
+      purpose: None,
+      constraints,
+    }
+  }
+
+  /// Returns `true` if `credential` satisfies the constraints of the
+  /// `InputDescriptor`.
+  pub fn matches<T>(&self, credential: &VerifiableCredential<T>) -> Result<bool>
+  where
+    T: Serialize,
+  {
+    let value: Value = credential.to_json_value()?;
+
+    self.constraints.matches(&value)
+  }
+}
@@ -0,0 +1,247 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str::FromStr;
+use identity_core::common::Value;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// A parsed [JSONPath](https://goessner.net/articles/JsonPath/) expression.
+///
+/// Supported syntax: the root (`$`), child members (`.name`, `['name']`),
+/// array indices (`[0]`), wildcards (`.*`, `[*]`), and recursive descent
+/// (`..`). Filter and script expressions are not supported.
+///
+/// Recursive descent may be used at most once per expression, which bounds
+/// the number of selected values by the size of the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonPath {
+  path: String,
+  segments: Vec<Segment>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+  Member(String),
+  Index(usize),
+  Wildcard,
+  Descendants,
+}
+
+impl JsonPath {
+  /// Parses a JSONPath expression.
+  pub fn parse(path: &str) -> Result<Self> {
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut input: &str = path.strip_prefix('$').ok_or(Error::InvalidJsonPath)?;
+
+    while !input.is_empty() {
+      if let Some(rest) = input.strip_prefix("..") {
+        segments.push(Segment::Descendants);
+
+        // A bracket selector may directly follow the recursive descent
+        input = if rest.starts_with('[') {
+          rest
+        } else {
+          parse_member(rest, &mut segments)?
+        };
+      } else if let Some(rest) = input.strip_prefix('.') {
+        input = parse_member(rest, &mut segments)?;
+      } else if let Some(rest) = input.strip_prefix('[') {
+        input = parse_bracket(rest, &mut segments)?;
+      } else {
+        return Err(Error::InvalidJsonPath);
+      }
+    }
+
+    if segments
+      .iter()
+      .filter(|segment| **segment == Segment::Descendants)
+      .count()
+      > 1
+    {
+      return Err(Error::InvalidJsonPath);
+    }
+
+    Ok(Self {
+      path: path.to_string(),
+      segments,
+    })
+  }
+
+  /// Returns the JSONPath expression as a string slice.
+  pub fn as_str(&self) -> &str {
+    &self.path
+  }
+
+  /// Returns `true` if the expression only selects the member `name` of the
+  /// root value or values nested within it.
+  pub fn is_within(&self, name: &str) -> bool {
+    matches!(self.segments.first(), Some(Segment::Member(member)) if member == name)
+  }
+
+  /// Returns all values in `value` selected by the expression, in document
+  /// order.
+  pub fn select<'a>(&self, value: &'a Value) -> Vec<&'a Value> {
+    let mut nodes: Vec<&'a Value> = vec![value];
+
+    for segment in self.segments.iter() {
+      nodes = nodes.into_iter().flat_map(|node| segment.apply(node)).collect();
+    }
+
+    nodes
+  }
+}
+
+impl Segment {
+  fn apply<'a>(&self, node: &'a Value) -> Vec<&'a Value> {
+    match self {
+      Self::Member(name) => node.get(name.as_str()).into_iter().collect(),
+      Self::Index(index) => node.get(*index).into_iter().collect(),
+      Self::Wildcard => children(node),
+      Self::Descendants => {
+        let mut output: Vec<&'a Value> = Vec::new();
+        descendants(node, &mut output);
+        output
+      }
+    }
+  }
+}
+
+impl Display for JsonPath {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(&self.path)
+  }
+}
+
+impl FromStr for JsonPath {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Self::parse(string)
+  }
+}
+
+fn parse_member<'a>(input: &'a str, segments: &mut Vec<Segment>) -> Result<&'a str> {
+  let end: usize = input
+    .find(|char: char| char == '.' || char == '[')
+    .unwrap_or(input.len());
+  let (name, rest): (&str, &str) = input.split_at(end);
+
+  match name {
+    "" => return Err(Error::InvalidJsonPath),
+    "*" => segments.push(Segment::Wildcard),
+    _ => segments.push(Segment::Member(name.to_string())),
+  }
+
+  Ok(rest)
+}
+
+fn parse_bracket<'a>(input: &'a str, segments: &mut Vec<Segment>) -> Result<&'a str> {
+  if let Some(quote) = input.chars().next().filter(|char| *char == '\'' || *char == '"') {
+    let input: &str = &input[1..];
+    let end: usize = input.find(quote).ok_or(Error::InvalidJsonPath)?;
+    let rest: &str = input[end + 1..].strip_prefix(']').ok_or(Error::InvalidJsonPath)?;
+
+    segments.push(Segment::Member(input[..end].to_string()));
+
+    return Ok(rest);
+  }
+
+  let end: usize = input.find(']').ok_or(Error::InvalidJsonPath)?;
+
+  match input[..end].trim() {
+    "*" => segments.push(Segment::Wildcard),
+    index => segments.push(Segment::Index(index.parse().map_err(|_| Error::InvalidJsonPath)?)),
+  }
+
+  Ok(&input[end + 1..])
+}
+
+fn children(node: &Value) -> Vec<&Value> {
+  match node {
+    Value::Array(array) => array.iter().collect(),
+    Value::Object(object) => object.values().collect(),
+    _ => Vec::new(),
+  }
+}
+
+fn descendants<'a>(node: &'a Value, output: &mut Vec<&'a Value>) {
+  output.push(node);
+
+  for child in children(node) {
+    descendants(child, output);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_select() {
+    let value: Value = json!({
+      "type": ["VerifiableCredential", "UniversityDegreeCredential"],
+      "credentialSubject": {
+        "id": "did:example:123",
+        "degree": { "type": "BachelorDegree", "name": "Bachelor of Science" },
+      },
+    });
+
+    let select = |path: &str| -> Vec<Value> {
+      JsonPath::parse(path)
+        .unwrap()
+        .select(&value)
+        .into_iter()
+        .cloned()
+        .collect()
+    };
+
+    assert_eq!(select("$"), vec![value.clone()]);
+    assert_eq!(select("$.credentialSubject.id"), vec![json!("did:example:123")]);
+    assert_eq!(select("$['credentialSubject'][\"id\"]"), vec![json!("did:example:123")]);
+    assert_eq!(select("$.type[1]"), vec![json!("UniversityDegreeCredential")]);
+    assert_eq!(select("$.type[*]").len(), 2);
+    assert_eq!(select("$.credentialSubject.*").len(), 2);
+    assert_eq!(select("$..type"), vec![value["type"].clone(), json!("BachelorDegree")]);
+    assert!(select("$.credentialSubject.missing").is_empty());
+  }
+
+  #[test]
+  fn test_is_within() {
+    assert!(JsonPath::parse("$.verifiableCredential")
+      .unwrap()
+      .is_within("verifiableCredential"));
+    assert!(JsonPath::parse("$['verifiableCredential'][0]")
+      .unwrap()
+      .is_within("verifiableCredential"));
+    assert!(!JsonPath::parse("$").unwrap().is_within("verifiableCredential"));
+    assert!(!JsonPath::parse("$..verifiableCredential")
+      .unwrap()
+      .is_within("verifiableCredential"));
+    assert!(!JsonPath::parse("$.*").unwrap().is_within("verifiableCredential"));
+  }
+
+  #[test]
+  fn test_parse_invalid() {
+    for path in [
+      "",
+      "credentialSubject",
+      "$.",
+      "$[0",
+      "$['id]",
+      "$[x]",
+      "$.a..",
+      "$..*..*",
+    ]
+    .iter()
+    {
+      assert!(matches!(JsonPath::parse(path), Err(Error::InvalidJsonPath)), "{}", path);
+    }
+  }
+}
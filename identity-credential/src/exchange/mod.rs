@@ -0,0 +1,25 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types for requesting and submitting credentials with
+//! [DIF Presentation Exchange](https://identity.foundation/presentation-exchange/).
+
+mod constraints;
+mod descriptor_mapping;
+mod descriptor_match;
+mod field;
+mod input_descriptor;
+mod json_path;
+mod presentation_definition;
+mod presentation_submission;
+mod submission_builder;
+
+pub use self::constraints::Constraints;
+pub use self::descriptor_mapping::DescriptorMapping;
+pub use self::descriptor_match::DescriptorMatch;
+pub use self::field::Field;
+pub use self::input_descriptor::InputDescriptor;
+pub use self::json_path::JsonPath;
+pub use self::presentation_definition::PresentationDefinition;
+pub use self::presentation_submission::PresentationSubmission;
+pub use self::submission_builder::SubmissionBuilder;
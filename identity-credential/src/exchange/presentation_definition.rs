@@ -0,0 +1,380 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::credential::VerifiableCredential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::exchange::DescriptorMapping;
+use crate::exchange::DescriptorMatch;
+use crate::exchange::InputDescriptor;
+use crate::exchange::JsonPath;
+use crate::exchange::PresentationSubmission;
+use crate::presentation::Presentation;
+
+// The presentation property holding the submitted credentials.
+const CREDENTIALS: &str = "verifiableCredential";
+
+/// A request for credentials by a verifier, as defined by
+/// [DIF Presentation Exchange](https://identity.foundation/presentation-exchange/).
+///
+/// Every `InputDescriptor` must be satisfied; `submission_requirements` are
+/// not supported.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PresentationDefinition {
+  /// The identifier of the `PresentationDefinition`.
+  pub id: String,
+  /// A human-readable name of the `PresentationDefinition`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// The purpose for which the credentials are requested.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// Descriptions of the requested credentials.
+  pub input_descriptors: Vec<InputDescriptor>,
+}
+
+impl PresentationDefinition {
+  /// Creates a new `PresentationDefinition` with the given `id` and input
+  /// descriptors.
+  pub fn new(id: impl Into<String>, input_descriptors: Vec<InputDescriptor>) -> Self {
+    Self {
+      id: id.into(),
+      name: None,
+      purpose: None,
+      input_descriptors,
+    }
+  }
+
+  /// Returns the `InputDescriptor` with the given `id`, if any.
+  pub fn input_descriptor(&self, id: &str) -> Option<&InputDescriptor> {
+    self.input_descriptors.iter().find(|descriptor| descriptor.id == id)
+  }
+
+  /// Returns the candidate credentials from `credentials` for each
+  /// `InputDescriptor`, in the order of the input descriptors.
+  pub fn select<'a, T>(&'a self, credentials: &'a [VerifiableCredential<T>]) -> Result<Vec<DescriptorMatch<'a, T>>>
+  where
+    T: Serialize,
+  {
+    let values: Vec<Value> = credentials
+      .iter()
+      .map(|credential| credential.to_json_value().map_err(Into::into))
+      .collect::<Result<_>>()?;
+
+    let mut matches: Vec<DescriptorMatch<'a, T>> = Vec::with_capacity(self.input_descriptors.len());
+
+    for descriptor in self.input_descriptors.iter() {
+      let mut candidates: Vec<&'a VerifiableCredential<T>> = Vec::new();
+
+      for (credential, value) in credentials.iter().zip(values.iter()) {
+        if descriptor.constraints.matches(value)? {
+          candidates.push(credential);
+        }
+      }
+
+      matches.push(DescriptorMatch::new(descriptor, candidates));
+    }
+
+    Ok(matches)
+  }
+
+  /// Evaluates the `presentation_submission` of `presentation` against the
+  /// `PresentationDefinition` and returns the submitted credentials by
+  /// `InputDescriptor` id.
+  ///
+  /// Note: This does not verify the proofs of the presentation or its
+  /// credentials.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the submission is missing, malformed, refers to another
+  /// definition or to values outside of the `verifiableCredential` property,
+  /// or if any `InputDescriptor` is not satisfied.
+  pub fn evaluate<T, U>(&self, presentation: &Presentation<T, U>) -> Result<BTreeMap<String, Vec<VerifiableCredential>>>
+  where
+    T: Serialize,
+    U: Serialize,
+  {
+    let value: Value = presentation.to_json_value()?;
+
+    let submission: PresentationSubmission = value
+      .get(PresentationSubmission::PROPERTY)
+      .cloned()
+      .ok_or(Error::InvalidSubmission)
+      .and_then(|submission| {
+        PresentationSubmission::from_json_value(submission).map_err(|_| Error::InvalidSubmission)
+      })?;
+
+    if submission.definition_id != self.id {
+      return Err(Error::InvalidSubmission);
+    }
+
+    let mut submitted: BTreeMap<String, Vec<VerifiableCredential>> = BTreeMap::new();
+
+    for mapping in submission.descriptor_map.iter() {
+      let descriptor: &InputDescriptor = self.input_descriptor(&mapping.id).ok_or(Error::InvalidSubmission)?;
+
+      // Submitted credentials must be embedded in the presentation
+      if !JsonPath::parse(&mapping.path)?.is_within(CREDENTIALS) {
+        return Err(Error::InvalidSubmission);
+      }
+
+      let credential: VerifiableCredential = resolve(&value, mapping)?;
+
+      if !descriptor.matches(&credential)? {
+        return Err(Error::UnsatisfiedInputDescriptor(descriptor.id.clone()));
+      }
+
+      submitted.entry(descriptor.id.clone()).or_default().push(credential);
+    }
+
+    for descriptor in self.input_descriptors.iter() {
+      if !submitted.contains_key(&descriptor.id) {
+        return Err(Error::UnsatisfiedInputDescriptor(descriptor.id.clone()));
+      }
+    }
+
+    Ok(submitted)
+  }
+}
+
+// Resolves the credential referenced by `mapping`, following nested paths.
+fn resolve(value: &Value, mapping: &DescriptorMapping) -> Result<VerifiableCredential<Object>> {
+  let selected: &Value = JsonPath::parse(&mapping.path)?
+    .select(value)
+    .into_iter()
+    .next()
+    .ok_or(Error::InvalidSubmission)?;
+
+  if let Some(nested) = mapping.path_nested.as_deref() {
+    return resolve(selected, nested);
+  }
+
+  match selected {
+    Value::String(token) if mapping.format == DescriptorMapping::FORMAT_JWT_VC => {
+      VerifiableCredential::from_jwt_unverified(token)
+    }
+    Value::Object(_) => VerifiableCredential::from_json_value(selected.clone()).map_err(Into::into),
+    _ => Err(Error::InvalidSubmission),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::exchange::SubmissionBuilder;
+  use crate::presentation::PresentationBuilder;
+
+  fn definition() -> PresentationDefinition {
+    PresentationDefinition::from_json_value(json!({
+      "id": "32f54163-7166-48f1-93d8-ff217bdb0653",
+      "input_descriptors": [
+        {
+          "id": "degree",
+          "constraints": {
+            "fields": [
+              {
+                "path": ["$.credentialSubject.degree.type", "$.vc.credentialSubject.degree.type"],
+                "filter": { "type": "string", "const": "BachelorDegree" }
+              },
+              { "path": ["$.credentialSubject.gpa"], "optional": true }
+            ]
+          }
+        },
+        {
+          "id": "name",
+          "constraints": { "fields": [{ "path": ["$.credentialSubject.name"] }] }
+        }
+      ]
+    }))
+    .unwrap()
+  }
+
+  fn credential(subject: Value) -> VerifiableCredential {
+    let credential: Credential = CredentialBuilder::default()
+      .subject(Subject::from_json_value(subject).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .build()
+      .unwrap();
+
+    VerifiableCredential::new(credential, Vec::new())
+  }
+
+  fn credentials() -> Vec<VerifiableCredential> {
+    vec![
+      credential(json!({ "id": "did:example:123", "degree": { "type": "BachelorDegree" } })),
+      credential(json!({ "id": "did:example:123", "degree": { "type": "MasterDegree" } })),
+      credential(json!({ "id": "did:example:123", "name": "Alice" })),
+    ]
+  }
+
+  #[test]
+  fn test_select() {
+    let definition: PresentationDefinition = definition();
+    let credentials: Vec<VerifiableCredential> = credentials();
+    let matches: Vec<DescriptorMatch<'_>> = definition.select(&credentials).unwrap();
+
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].descriptor().id, "degree");
+    assert_eq!(matches[0].credentials(), &[&credentials[0]]);
+    assert_eq!(matches[1].descriptor().id, "name");
+    assert_eq!(matches[1].credentials(), &[&credentials[2]]);
+    assert!(matches.iter().all(DescriptorMatch::is_satisfied));
+  }
+
+  #[test]
+  fn test_submit_and_evaluate() {
+    let definition: PresentationDefinition = definition();
+    let credentials: Vec<VerifiableCredential> = credentials();
+
+    let presentation: Presentation = SubmissionBuilder::new(&definition)
+      .id("a30e3b91-fb77-4d22-95fa-871689c322e2")
+      .credential("degree", credentials[0].clone())
+      .credential("name", credentials[2].clone())
+      .build()
+      .unwrap();
+
+    let submission: PresentationSubmission =
+      PresentationSubmission::from_json_value(presentation.properties[PresentationSubmission::PROPERTY].clone())
+        .unwrap();
+
+    assert_eq!(submission.definition_id, definition.id);
+    assert_eq!(submission.descriptor_map[1].path, "$.verifiableCredential[1]");
+    assert_eq!(submission.descriptor_map[1].format, DescriptorMapping::FORMAT_LDP_VC);
+
+    let submitted: BTreeMap<String, Vec<VerifiableCredential>> = definition.evaluate(&presentation).unwrap();
+
+    assert_eq!(submitted["degree"], vec![credentials[0].clone()]);
+    assert_eq!(submitted["name"], vec![credentials[2].clone()]);
+  }
+
+  #[test]
+  fn test_submission_paths() {
+    let definition: PresentationDefinition = definition();
+    let credentials: Vec<VerifiableCredential> = credentials();
+
+    // Credentials of the `PresentationBuilder` precede the submitted ones
+    let presentation: Presentation = SubmissionBuilder::new(&definition)
+      .presentation(PresentationBuilder::new(Object::new()).credential(credentials[1].clone()))
+      .credential("degree", credentials[0].clone())
+      .credential("name", credentials[2].clone())
+      .build()
+      .unwrap();
+
+    let submission: PresentationSubmission =
+      PresentationSubmission::from_json_value(presentation.properties[PresentationSubmission::PROPERTY].clone())
+        .unwrap();
+
+    assert_eq!(submission.descriptor_map[0].path, "$.verifiableCredential[1]");
+    assert_eq!(submission.descriptor_map[1].path, "$.verifiableCredential[2]");
+
+    let submitted: BTreeMap<String, Vec<VerifiableCredential>> = definition.evaluate(&presentation).unwrap();
+
+    assert_eq!(submitted["degree"], vec![credentials[0].clone()]);
+    assert_eq!(submitted["name"], vec![credentials[2].clone()]);
+
+    let definition: PresentationDefinition =
+      PresentationDefinition::new("single", vec![definition.input_descriptors[1].clone()]);
+
+    let presentation: Presentation = SubmissionBuilder::new(&definition)
+      .credential("name", credentials[2].clone())
+      .build()
+      .unwrap();
+
+    let submission: PresentationSubmission =
+      PresentationSubmission::from_json_value(presentation.properties[PresentationSubmission::PROPERTY].clone())
+        .unwrap();
+
+    assert_eq!(submission.descriptor_map[0].path, "$.verifiableCredential");
+    assert_eq!(
+      definition.evaluate(&presentation).unwrap()["name"],
+      vec![credentials[2].clone()]
+    );
+  }
+
+  #[test]
+  fn test_submission_errors() {
+    let definition: PresentationDefinition = definition();
+    let credentials: Vec<VerifiableCredential> = credentials();
+
+    let result: Result<Presentation> = SubmissionBuilder::new(&definition)
+      .credential("degree", credentials[0].clone())
+      .build();
+
+    assert!(matches!(result, Err(Error::UnsatisfiedInputDescriptor(id)) if id == "name"));
+
+    let result: Result<Presentation> = SubmissionBuilder::new(&definition)
+      .credential("degree", credentials[1].clone())
+      .credential("name", credentials[2].clone())
+      .build();
+
+    assert!(matches!(result, Err(Error::UnsatisfiedInputDescriptor(id)) if id == "degree"));
+
+    let mut presentation: Presentation = SubmissionBuilder::new(&definition)
+      .credential("degree", credentials[0].clone())
+      .credential("name", credentials[2].clone())
+      .build()
+      .unwrap();
+
+    // Swap in a credential that does not satisfy the descriptor
+    presentation.verifiable_credential = vec![credentials[1].clone(), credentials[2].clone()].into();
+
+    assert!(matches!(
+      definition.evaluate(&presentation),
+      Err(Error::UnsatisfiedInputDescriptor(id)) if id == "degree"
+    ));
+
+    // Paths must select credentials embedded in the presentation
+    let mut presentation: Presentation = SubmissionBuilder::new(&definition)
+      .credential("degree", credentials[0].clone())
+      .credential("name", credentials[2].clone())
+      .build()
+      .unwrap();
+
+    let mut submission: Value = presentation.properties[PresentationSubmission::PROPERTY].clone();
+    submission["descriptor_map"][1]["path"] = json!("$.name");
+
+    presentation
+      .properties
+      .insert("name".to_string(), credentials[2].to_json_value().unwrap());
+    presentation
+      .properties
+      .insert(PresentationSubmission::PROPERTY.to_string(), submission.clone());
+
+    assert!(matches!(
+      definition.evaluate(&presentation),
+      Err(Error::InvalidSubmission)
+    ));
+
+    submission["descriptor_map"][1]["path"] = json!("$..*..*");
+
+    presentation
+      .properties
+      .insert(PresentationSubmission::PROPERTY.to_string(), submission);
+
+    assert!(matches!(
+      definition.evaluate(&presentation),
+      Err(Error::InvalidJsonPath)
+    ));
+
+    presentation.properties.remove(PresentationSubmission::PROPERTY);
+
+    assert!(matches!(
+      definition.evaluate(&presentation),
+      Err(Error::InvalidSubmission)
+    ));
+  }
+}
@@ -0,0 +1,23 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::exchange::DescriptorMapping;
+
+/// Describes how the credentials of a `Presentation` satisfy the input
+/// descriptors of a `PresentationDefinition`.
+///
+/// [More Info](https://identity.foundation/presentation-exchange/#presentation-submission)
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PresentationSubmission {
+  /// The identifier of the `PresentationSubmission`.
+  pub id: String,
+  /// The `id` of the fulfilled `PresentationDefinition`.
+  pub definition_id: String,
+  /// The mappings of input descriptors to submitted credentials.
+  pub descriptor_map: Vec<DescriptorMapping>,
+}
+
+impl PresentationSubmission {
+  /// The `Presentation` property holding the `PresentationSubmission`.
+  pub const PROPERTY: &'static str = "presentation_submission";
+}
@@ -0,0 +1,137 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::convert::ToJson;
+use identity_core::utils::encode_b58;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::Serialize;
+
+use crate::credential::VerifiableCredential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::exchange::DescriptorMapping;
+use crate::exchange::InputDescriptor;
+use crate::exchange::PresentationDefinition;
+use crate::exchange::PresentationSubmission;
+use crate::presentation::Presentation;
+use crate::presentation::PresentationBuilder;
+
+/// A `SubmissionBuilder` is used to create a `Presentation` with a
+/// `presentation_submission` fulfilling a `PresentationDefinition`.
+#[derive(Clone, Debug)]
+pub struct SubmissionBuilder<'a, U = Object> {
+  definition: &'a PresentationDefinition,
+  presentation: PresentationBuilder<Object, U>,
+  id: Option<String>,
+  credentials: Vec<(String, VerifiableCredential<U>)>,
+}
+
+impl<'a, U> SubmissionBuilder<'a, U> {
+  /// Creates a new `SubmissionBuilder` for the given `definition`.
+  pub fn new(definition: &'a PresentationDefinition) -> Self {
+    Self {
+      definition,
+      presentation: PresentationBuilder::new(Object::new()),
+      id: None,
+      credentials: Vec::new(),
+    }
+  }
+
+  /// Sets the `PresentationBuilder` used to configure the `Presentation`.
+  #[must_use]
+  pub fn presentation(mut self, value: PresentationBuilder<Object, U>) -> Self {
+    self.presentation = value;
+    self
+  }
+
+  /// Sets the `id` of the `PresentationSubmission`.
+  ///
+  /// A random identifier is generated if none is set.
+  #[must_use]
+  pub fn id(mut self, value: impl Into<String>) -> Self {
+    self.id = Some(value.into());
+    self
+  }
+
+  /// Submits `credential` for the `InputDescriptor` identified by
+  /// `descriptor`.
+  #[must_use]
+  pub fn credential(mut self, descriptor: impl Into<String>, credential: VerifiableCredential<U>) -> Self {
+    self.credentials.push((descriptor.into(), credential));
+    self
+  }
+
+  /// Returns a new `Presentation` containing the submitted credentials and
+  /// the corresponding `presentation_submission`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a credential is submitted for an unknown `InputDescriptor`, or
+  /// if any `InputDescriptor` is not satisfied by a submitted credential.
+  pub fn build(self) -> Result<Presentation<Object, U>>
+  where
+    U: Serialize,
+  {
+    for descriptor in self.definition.input_descriptors.iter() {
+      if !self.credentials.iter().any(|(id, _)| *id == descriptor.id) {
+        return Err(Error::UnsatisfiedInputDescriptor(descriptor.id.clone()));
+      }
+    }
+
+    for (id, credential) in self.credentials.iter() {
+      let descriptor: &InputDescriptor = self.definition.input_descriptor(id).ok_or(Error::InvalidSubmission)?;
+
+      if !descriptor.matches(credential)? {
+        return Err(Error::UnsatisfiedInputDescriptor(descriptor.id.clone()));
+      }
+    }
+
+    let (ids, credentials): (Vec<String>, Vec<VerifiableCredential<U>>) = self.credentials.into_iter().unzip();
+
+    let mut presentation: Presentation<Object, U> = credentials
+      .into_iter()
+      .fold(self.presentation, |builder, credential| builder.credential(credential))
+      .build()?;
+
+    // Credentials added to the `PresentationBuilder` precede the submitted
+    // ones, and a single credential is not serialized as an array
+    let total: usize = presentation.verifiable_credential.len();
+    let offset: usize = total - ids.len();
+
+    let descriptor_map: Vec<DescriptorMapping> = ids
+      .into_iter()
+      .enumerate()
+      .map(|(index, id)| {
+        let path: String = if total == 1 {
+          "$.verifiableCredential".to_string()
+        } else {
+          format!("$.verifiableCredential[{}]", offset + index)
+        };
+
+        // Credentials are embedded as objects, even if decoded from a VC-JWT
+        DescriptorMapping::new(id, DescriptorMapping::FORMAT_LDP_VC, path)
+      })
+      .collect();
+
+    let submission: PresentationSubmission = PresentationSubmission {
+      id: self.id.unwrap_or_else(random_id),
+      definition_id: self.definition.id.clone(),
+      descriptor_map,
+    };
+
+    presentation.properties.insert(
+      PresentationSubmission::PROPERTY.to_string(),
+      submission.to_json_value()?,
+    );
+
+    Ok(presentation)
+  }
+}
+
+fn random_id() -> String {
+  let mut bytes: [u8; 16] = [0; 16];
+  OsRng.fill_bytes(&mut bytes);
+  encode_b58(&bytes)
+}
@@ -0,0 +1,131 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::utils::decode_b58;
+use identity_core::utils::encode_b58;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::integrity::DigestAlgorithm;
+
+// The multibase prefix of base58-btc encoded data.
+const MULTIBASE_BASE58BTC: char = 'z';
+
+/// Computes a Subresource Integrity digest (`digestSRI`) of `data`.
+///
+/// [More Info](https://www.w3.org/TR/SRI/#integrity-metadata)
+pub fn digest_sri(algorithm: DigestAlgorithm, data: &[u8]) -> String {
+  format!("{}-{}", algorithm.name(), base64::encode(algorithm.digest(data)))
+}
+
+/// Computes a multibase-encoded multihash digest (`digestMultibase`) of `data`.
+///
+/// [More Info](https://www.w3.org/TR/vc-data-integrity/#resource-integrity)
+pub fn digest_multibase(algorithm: DigestAlgorithm, data: &[u8]) -> String {
+  let digest: Vec<u8> = algorithm.digest(data);
+  let mut multihash: Vec<u8> = Vec::with_capacity(2 + digest.len());
+
+  multihash.push(algorithm.code());
+  multihash.push(digest.len() as u8);
+  multihash.extend_from_slice(&digest);
+
+  format!("{}{}", MULTIBASE_BASE58BTC, encode_b58(&multihash))
+}
+
+/// Verifies that `data` matches the Subresource Integrity metadata `value`.
+///
+/// If `value` contains multiple digests, only the digests of the strongest
+/// supported algorithm are considered and any one of them must match.
+pub fn verify_digest_sri(value: &str, data: &[u8]) -> Result<()> {
+  let digests: Vec<(DigestAlgorithm, &str)> = value
+    .split_whitespace()
+    .filter_map(|token| {
+      // Ignore any trailing options (e.g. `sha384-<digest>?<options>`).
+      let token: &str = token.split('?').next().unwrap_or_default();
+      let index: usize = token.find('-')?;
+
+      DigestAlgorithm::from_name(&token[..index]).map(|algorithm| (algorithm, &token[index + 1..]))
+    })
+    .collect();
+
+  let strongest: DigestAlgorithm = digests
+    .iter()
+    .map(|(algorithm, _)| *algorithm)
+    .max()
+    .ok_or(Error::UnsupportedDigestAlgorithm)?;
+
+  let digest: String = base64::encode(strongest.digest(data));
+
+  if digests
+    .iter()
+    .any(|(algorithm, value)| *algorithm == strongest && *value == digest)
+  {
+    Ok(())
+  } else {
+    Err(Error::InvalidResourceDigest)
+  }
+}
+
+/// Verifies that `data` matches the multibase-encoded multihash `value`.
+pub fn verify_digest_multibase(value: &str, data: &[u8]) -> Result<()> {
+  if !value.starts_with(MULTIBASE_BASE58BTC) {
+    return Err(Error::InvalidResourceDigest);
+  }
+
+  let multihash: Vec<u8> = decode_b58(&value[1..]).map_err(|_| Error::InvalidResourceDigest)?;
+
+  let (algorithm, digest): (DigestAlgorithm, &[u8]) = match multihash.as_slice() {
+    [code, size, digest @ ..] if *size as usize == digest.len() => {
+      let algorithm: DigestAlgorithm = DigestAlgorithm::from_code(*code).ok_or(Error::UnsupportedDigestAlgorithm)?;
+
+      (algorithm, digest)
+    }
+    _ => return Err(Error::InvalidResourceDigest),
+  };
+
+  if algorithm.size() == digest.len() && algorithm.digest(data) == digest {
+    Ok(())
+  } else {
+    Err(Error::InvalidResourceDigest)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const DATA: &[u8] = b"alert('Hello, world.');";
+
+  #[test]
+  fn test_digest_sri() {
+    // https://www.w3.org/TR/SRI/#integrity-metadata
+    let digest: String = digest_sri(DigestAlgorithm::Sha384, DATA);
+
+    assert_eq!(
+      digest,
+      "sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO"
+    );
+
+    assert!(verify_digest_sri(&digest, DATA).is_ok());
+    assert!(verify_digest_sri(&format!("{} sha256-invalid", digest), DATA).is_ok());
+    assert!(verify_digest_sri(&digest, b"alert('Goodbye, world.');").is_err());
+    assert!(verify_digest_sri("md5-invalid", DATA).is_err());
+  }
+
+  #[test]
+  fn test_digest_multibase() {
+    for algorithm in &[
+      DigestAlgorithm::Sha256,
+      DigestAlgorithm::Sha384,
+      DigestAlgorithm::Sha512,
+    ] {
+      let digest: String = digest_multibase(*algorithm, DATA);
+
+      assert!(digest.starts_with('z'));
+      assert!(verify_digest_multibase(&digest, DATA).is_ok());
+      assert!(verify_digest_multibase(&digest, b"").is_err());
+    }
+
+    assert!(verify_digest_multibase("mEiA", DATA).is_err());
+  }
+}
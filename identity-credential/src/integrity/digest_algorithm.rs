@@ -0,0 +1,93 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use sha2::Digest;
+use sha2::Sha256;
+use sha2::Sha384;
+use sha2::Sha512;
+
+/// Supported algorithms for resource digests.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DigestAlgorithm {
+  /// SHA-2 with a 256-bit digest.
+  Sha256,
+  /// SHA-2 with a 384-bit digest.
+  Sha384,
+  /// SHA-2 with a 512-bit digest.
+  Sha512,
+}
+
+impl DigestAlgorithm {
+  /// Returns the algorithm name as used by Subresource Integrity.
+  pub const fn name(self) -> &'static str {
+    match self {
+      Self::Sha256 => "sha256",
+      Self::Sha384 => "sha384",
+      Self::Sha512 => "sha512",
+    }
+  }
+
+  /// Returns the algorithm matching the given Subresource Integrity name.
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "sha256" => Some(Self::Sha256),
+      "sha384" => Some(Self::Sha384),
+      "sha512" => Some(Self::Sha512),
+      _ => None,
+    }
+  }
+
+  /// Returns the multicodec code of the algorithm.
+  ///
+  /// [More Info](https://github.com/multiformats/multicodec/blob/master/table.csv)
+  pub const fn code(self) -> u8 {
+    match self {
+      Self::Sha256 => 0x12,
+      Self::Sha384 => 0x20,
+      Self::Sha512 => 0x13,
+    }
+  }
+
+  /// Returns the algorithm matching the given multicodec code.
+  pub fn from_code(code: u8) -> Option<Self> {
+    match code {
+      0x12 => Some(Self::Sha256),
+      0x20 => Some(Self::Sha384),
+      0x13 => Some(Self::Sha512),
+      _ => None,
+    }
+  }
+
+  /// Returns the size of the digest in bytes.
+  pub const fn size(self) -> usize {
+    match self {
+      Self::Sha256 => 32,
+      Self::Sha384 => 48,
+      Self::Sha512 => 64,
+    }
+  }
+
+  /// Computes the digest of `data`.
+  pub fn digest(self, data: &[u8]) -> Vec<u8> {
+    match self {
+      Self::Sha256 => Sha256::digest(data).to_vec(),
+      Self::Sha384 => Sha384::digest(data).to_vec(),
+      Self::Sha512 => Sha512::digest(data).to_vec(),
+    }
+  }
+}
+
+impl Default for DigestAlgorithm {
+  fn default() -> Self {
+    Self::Sha256
+  }
+}
+
+impl Display for DigestAlgorithm {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(self.name())
+  }
+}
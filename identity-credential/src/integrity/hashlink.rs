@@ -0,0 +1,79 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+
+use crate::error::Result;
+use crate::integrity::digest_multibase;
+use crate::integrity::verify_digest_multibase;
+use crate::integrity::DigestAlgorithm;
+
+// The name of the hashlink query parameter.
+const HASHLINK: &str = "hl";
+
+/// Computes the hashlink (`hl`) value of `data`.
+///
+/// [More Info](https://tools.ietf.org/html/draft-sporny-hashlink-07)
+pub fn hashlink(data: &[u8]) -> String {
+  digest_multibase(DigestAlgorithm::Sha256, data)
+}
+
+/// Returns a copy of `url` with the hashlink of `data` in the `hl` query
+/// parameter, replacing any existing hashlink.
+pub fn hashlink_url(url: &Url, data: &[u8]) -> Url {
+  let (mut output, _): (Url, Option<String>) = split_hashlink(url);
+
+  output.query_pairs_mut().append_pair(HASHLINK, &hashlink(data));
+  output
+}
+
+/// Splits `url` into the url without the `hl` query parameter and the value of
+/// the `hl` query parameter, if any.
+pub fn split_hashlink(url: &Url) -> (Url, Option<String>) {
+  let mut hashlink: Option<String> = None;
+  let mut pairs: Vec<(String, String)> = Vec::new();
+
+  for (key, value) in url.query_pairs() {
+    if key == HASHLINK {
+      hashlink.get_or_insert_with(|| value.into_owned());
+    } else {
+      pairs.push((key.into_owned(), value.into_owned()));
+    }
+  }
+
+  if hashlink.is_none() {
+    return (url.clone(), None);
+  }
+
+  let mut output: Url = url.clone();
+
+  if pairs.is_empty() {
+    output.set_query(None);
+  } else {
+    output.query_pairs_mut().clear().extend_pairs(pairs);
+  }
+
+  (output, hashlink)
+}
+
+/// Verifies that `data` matches the hashlink `value`.
+pub fn verify_hashlink(value: &str, data: &[u8]) -> Result<()> {
+  verify_digest_multibase(value, data)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_hashlink_url() {
+    let url: Url = Url::parse("https://example.org/schema.json?version=1").unwrap();
+    let linked: Url = hashlink_url(&url, b"{}");
+
+    let (base, hashlink): (Url, Option<String>) = split_hashlink(&linked);
+
+    assert_eq!(base, url);
+    assert!(verify_hashlink(&hashlink.unwrap(), b"{}").is_ok());
+    assert_eq!(split_hashlink(&url), (url.clone(), None));
+  }
+}
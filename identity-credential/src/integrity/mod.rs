@@ -0,0 +1,15 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integrity protection of resources linked from Verifiable Credentials.
+
+mod digest;
+mod digest_algorithm;
+mod hashlink;
+mod resource_loader;
+
+pub use self::digest::*;
+pub use self::digest_algorithm::DigestAlgorithm;
+pub use self::hashlink::*;
+pub use self::resource_loader::MemoryLoader;
+pub use self::resource_loader::ResourceLoader;
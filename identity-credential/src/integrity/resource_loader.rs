@@ -0,0 +1,56 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use identity_core::common::Url;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// A source of the resources linked from Verifiable Credentials.
+pub trait ResourceLoader {
+  /// Returns the contents of the resource identified by `url`.
+  fn load(&self, url: &Url) -> Result<Vec<u8>>;
+}
+
+impl<T> ResourceLoader for &'_ T
+where
+  T: ResourceLoader + ?Sized,
+{
+  fn load(&self, url: &Url) -> Result<Vec<u8>> {
+    (**self).load(url)
+  }
+}
+
+/// A [`ResourceLoader`] that serves resources from memory.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryLoader {
+  resources: BTreeMap<Url, Vec<u8>>,
+}
+
+impl MemoryLoader {
+  /// Creates a new, empty `MemoryLoader`.
+  pub fn new() -> Self {
+    Self {
+      resources: BTreeMap::new(),
+    }
+  }
+
+  /// Adds a resource to the loader, replacing any existing resource with the
+  /// same `url`.
+  pub fn insert(&mut self, url: Url, data: impl Into<Vec<u8>>) {
+    self.resources.insert(url, data.into());
+  }
+
+  /// Removes the resource identified by `url` from the loader.
+  pub fn remove(&mut self, url: &Url) -> Option<Vec<u8>> {
+    self.resources.remove(url)
+  }
+}
+
+impl ResourceLoader for MemoryLoader {
+  fn load(&self, url: &Url) -> Result<Vec<u8>> {
+    self.resources.get(url).cloned().ok_or(Error::ResourceNotFound)
+  }
+}
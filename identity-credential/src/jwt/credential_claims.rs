@@ -0,0 +1,312 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Context;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::SerdeInto;
+use identity_core::crypto::SecretKey;
+use identity_did::document::Document;
+use identity_did::verification::MethodQuery;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::credential::Credential;
+use crate::credential::CredentialVersion;
+use crate::credential::Subject;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jwt::jwt_decode_unverified;
+use crate::jwt::jwt_encode;
+use crate::jwt::JwtHeader;
+
+/// The JWT claims set of a JWT-encoded `Credential`.
+///
+/// [More Info](https://www.w3.org/TR/vc-data-model/#jwt-encoding)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CredentialClaims {
+  /// The issuer of the `Credential`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub iss: Option<String>,
+  /// The id of the `Credential` subject.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sub: Option<String>,
+  /// The id of the `Credential`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub jti: Option<String>,
+  /// The issuance date of the `Credential` as a Unix timestamp.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub nbf: Option<i64>,
+  /// The expiration date of the `Credential` as a Unix timestamp.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub exp: Option<i64>,
+  /// The digest algorithm of selectively disclosable claims.
+  #[serde(rename = "_sd_alg", skip_serializing_if = "Option::is_none")]
+  pub sd_alg: Option<String>,
+  /// The key a holder must prove possession of to present the `Credential`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cnf: Option<Object>,
+  /// The JSON representation of the `Credential`.
+  pub vc: Object,
+}
+
+impl CredentialClaims {
+  /// Creates a new [`CredentialClaims`] set from the given `credential`.
+  pub fn new<T>(credential: &Credential<T>) -> Result<Self>
+  where
+    T: Serialize,
+  {
+    let sub: Option<String> = match credential.credential_subject.as_slice() {
+      [subject] => subject.id.as_ref().map(ToString::to_string),
+      _ => None,
+    };
+
+    Ok(Self {
+      iss: Some(credential.issuer.url().to_string()),
+      sub,
+      jti: credential.id.as_ref().map(ToString::to_string),
+      nbf: credential.validity_start().as_ref().map(Timestamp::to_unix),
+      exp: credential.validity_end().as_ref().map(Timestamp::to_unix),
+      sd_alg: None,
+      cnf: None,
+      vc: credential.serde_into()?,
+    })
+  }
+
+  /// Decodes the claims set of `token` WITHOUT verifying the signature.
+  ///
+  /// This can be used to determine the issuer of a token before resolving the
+  /// DID document required for verification.
+  pub fn decode(token: &str) -> Result<Self> {
+    jwt_decode_unverified(token).and_then(|(_, claims)| Self::from_json_slice(&claims).map_err(Into::into))
+  }
+
+  /// Signs the claims set with the verification method of `document` matching
+  /// `query` and returns the encoded JWT.
+  pub fn sign<'a, Q, D1, D2, D3>(&self, document: &Document<D1, D2, D3>, query: Q, secret: &SecretKey) -> Result<String>
+  where
+    Q: Into<MethodQuery<'a>>,
+  {
+    jwt_encode(self, JwtHeader::TYPE, document, query, secret)
+  }
+
+  /// Consumes the claims set and returns the encoded `Credential`.
+  ///
+  /// Registered claims fill in the corresponding `Credential` properties when
+  /// absent and MUST match them otherwise.
+  pub fn into_credential<T>(self) -> Result<Credential<T>>
+  where
+    T: DeserializeOwned,
+  {
+    let mut vc: Object = self.vc;
+
+    insert_missing(&mut vc, "issuer", self.iss.clone().map(Value::String));
+    insert_missing(&mut vc, "id", self.jti.clone().map(Value::String));
+
+    // The names of the validity period properties depend on the version
+    let version: Option<CredentialVersion> = match vc.get("@context") {
+      Some(Value::Array(contexts)) => contexts.get(0).cloned(),
+      context => context.cloned(),
+    }
+    .and_then(|context| Context::from_json_value(context).ok())
+    .and_then(|context| CredentialVersion::from_context(&context));
+
+    let (start, end): (&str, &str) = match version {
+      Some(CredentialVersion::V2_0) => ("validFrom", "validUntil"),
+      Some(CredentialVersion::V1_1) | None => ("issuanceDate", "expirationDate"),
+    };
+
+    insert_missing(&mut vc, start, unix_to_value(self.nbf)?);
+    insert_missing(&mut vc, end, unix_to_value(self.exp)?);
+
+    let mut credential: Credential<T> = Credential::from_json_value(vc.into_iter().collect())?;
+
+    if let Some(sub) = self.sub.as_deref() {
+      let single: bool = credential.credential_subject.len() == 1;
+
+      match credential.credential_subject.get_mut(0) {
+        Some(Subject { id: Some(id), .. }) if id.as_str() == sub => {}
+        Some(subject) if single && subject.id.is_none() => subject.id = Some(sub.parse()?),
+        Some(_) | None => return Err(Error::InvalidJwtClaims),
+      }
+    }
+
+    let consistent: bool = self
+      .iss
+      .as_deref()
+      .map_or(true, |iss| credential.issuer.url().as_str() == iss)
+      && self
+        .jti
+        .as_deref()
+        .map_or(true, |jti| credential.id.as_ref().map(|id| id.as_str()) == Some(jti))
+      && self.nbf.map_or(true, |nbf| {
+        credential.validity_start().map(|date| date.to_unix()) == Some(nbf)
+      })
+      && self.exp.map_or(true, |exp| {
+        credential.validity_end().map(|date| date.to_unix()) == Some(exp)
+      });
+
+    if !consistent {
+      return Err(Error::InvalidJwtClaims);
+    }
+
+    credential.check_structure()?;
+
+    Ok(credential)
+  }
+}
+
+pub(crate) fn insert_missing(object: &mut Object, key: &str, value: Option<Value>) {
+  if let Some(value) = value {
+    object.entry(key.to_string()).or_insert(value);
+  }
+}
+
+pub(crate) fn unix_to_value(value: Option<i64>) -> Result<Option<Value>> {
+  value
+    .map(|value| {
+      Timestamp::from_unix(value)
+        .map(|timestamp| Value::String(timestamp.to_rfc3339()))
+        .ok_or(Error::InvalidJwtClaims)
+    })
+    .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::crypto::KeyPair;
+  use identity_core::utils::encode_b64_unpadded;
+  use identity_did::verification::MethodScope;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::VerifiableCredential;
+  use crate::test_utils::document;
+  use crate::test_utils::issuer;
+
+  fn credential() -> Credential {
+    CredentialBuilder::default()
+      .id(Url::parse("https://example.edu/credentials/3732").unwrap())
+      .subject(Subject::from_json_value(json!({ "id": "did:example:subject", "degree": "BA" })).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .issuance_date(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+      .expiration_date(Timestamp::parse("2030-01-01T00:00:00Z").unwrap())
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_claims() {
+    let claims: CredentialClaims = CredentialClaims::new(&credential()).unwrap();
+
+    assert_eq!(claims.iss.as_deref(), Some("did:example:issuer"));
+    assert_eq!(claims.sub.as_deref(), Some("did:example:subject"));
+    assert_eq!(claims.jti.as_deref(), Some("https://example.edu/credentials/3732"));
+    assert_eq!(claims.nbf, Some(1577836800));
+    assert_eq!(claims.exp, Some(1893456000));
+    assert_eq!(claims.into_credential::<Object>().unwrap(), credential());
+  }
+
+  #[test]
+  fn test_sign_and_verify() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+
+    let token: String = credential()
+      .sign_jwt(&document, ("#key-1", MethodScope::AssertionMethod), keypair.secret())
+      .unwrap();
+
+    let decoded: VerifiableCredential = VerifiableCredential::from_jwt(&token, &document).unwrap();
+
+    assert_eq!(*decoded, credential());
+    assert_eq!(decoded.jwt(), Some(token.as_str()));
+    assert_eq!(decoded.proof().get(0).unwrap().type_(), "JwtProof2020");
+    assert_eq!(
+      decoded.proof().get(0).unwrap().proof_purpose.as_deref(),
+      Some(MethodScope::AssertionMethod.as_str())
+    );
+  }
+
+  #[test]
+  fn test_verify_invalid() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+    let other: Document = issuer(&KeyPair::new_ed25519().unwrap());
+
+    let token: String = credential()
+      .sign_jwt(&document, ("#key-1", MethodScope::AssertionMethod), keypair.secret())
+      .unwrap();
+
+    assert!(matches!(
+      VerifiableCredential::<Object>::from_jwt(&token, &other),
+      Err(Error::InvalidJwtSignature)
+    ));
+
+    let mut segments: Vec<&str> = token.split('.').collect();
+    let claims: String = encode_claims(json!({ "iss": "did:example:issuer", "vc": {} }));
+    segments[1] = &claims;
+
+    assert!(matches!(
+      VerifiableCredential::<Object>::from_jwt(&segments.join("."), &document),
+      Err(Error::InvalidJwtSignature)
+    ));
+
+    assert!(matches!(
+      VerifiableCredential::<Object>::from_jwt("a.b", &document),
+      Err(Error::InvalidJwt)
+    ));
+  }
+
+  #[test]
+  fn test_verify_authentication_key() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = document("did:example:issuer", &keypair, &[MethodScope::Authentication]);
+
+    let token: String = credential()
+      .sign_jwt(&document, ("#key-1", MethodScope::Authentication), keypair.secret())
+      .unwrap();
+
+    assert!(matches!(
+      VerifiableCredential::<Object>::from_jwt(&token, &document),
+      Err(Error::DIDError(identity_did::Error::QueryMethodNotFound))
+    ));
+  }
+
+  #[test]
+  fn test_registered_claims_only() {
+    let claims: CredentialClaims = CredentialClaims::from_json_value(json!({
+      "iss": "did:example:issuer",
+      "sub": "did:example:subject",
+      "jti": "https://example.edu/credentials/3732",
+      "nbf": 1577836800,
+      "exp": 1893456000,
+      "vc": {
+        "@context": "https://www.w3.org/2018/credentials/v1",
+        "type": "VerifiableCredential",
+        "credentialSubject": { "degree": "BA" }
+      }
+    }))
+    .unwrap();
+
+    assert_eq!(claims.into_credential::<Object>().unwrap(), credential());
+  }
+
+  #[test]
+  fn test_conflicting_claims() {
+    let mut claims: CredentialClaims = CredentialClaims::new(&credential()).unwrap();
+    claims.iss = Some("did:example:other".to_string());
+
+    assert!(matches!(
+      claims.into_credential::<Object>(),
+      Err(Error::InvalidJwtClaims)
+    ));
+  }
+
+  fn encode_claims(value: Value) -> String {
+    encode_b64_unpadded(&value.to_string())
+  }
+}
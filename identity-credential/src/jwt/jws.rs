@@ -0,0 +1,158 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_core::crypto::merkle_key::Ed25519;
+use identity_core::crypto::merkle_key::Signature as _;
+use identity_core::crypto::SecretKey;
+use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureData;
+use identity_core::crypto::SignatureOptions;
+use identity_core::utils::decode_b64_unpadded;
+use identity_core::utils::encode_b64_unpadded;
+use identity_did::document::Document;
+use identity_did::verifiable::ResolveMethod;
+use identity_did::verification::MethodQuery;
+use identity_did::verification::MethodScope;
+use identity_did::verification::MethodType;
+use identity_did::verification::MethodWrap;
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jwt::JwtHeader;
+
+/// The JWS algorithm of Ed25519 signatures.
+pub(crate) const JWT_ALGORITHM: &str = "EdDSA";
+
+/// The proof type attached to Credentials/Presentations decoded from a JWT.
+pub(crate) const JWT_PROOF_TYPE: &str = "JwtProof2020";
+
+const SIGNATURE_SIZE: usize = 64;
+
+/// Signs `claims` with the verification method of `document` matching `query`
+/// and returns the compact JWS serialization with the media type `typ`.
+pub(crate) fn jwt_encode<'a, C, Q, D1, D2, D3>(
+  claims: &C,
+  typ: &str,
+  document: &Document<D1, D2, D3>,
+  query: Q,
+  secret: &SecretKey,
+) -> Result<String>
+where
+  C: Serialize,
+  Q: Into<MethodQuery<'a>>,
+{
+  let method: MethodWrap<'_, D2> = document.try_resolve(query)?;
+
+  if method.key_type() != MethodType::Ed25519VerificationKey2018 {
+    return Err(Error::DIDError(identity_did::Error::UnknownMethodType));
+  }
+
+  let mut header: JwtHeader = JwtHeader::new(JWT_ALGORITHM, method.id().as_str());
+  header.typ = Some(typ.to_string());
+
+  let input: String = format!(
+    "{}.{}",
+    encode_b64_unpadded(&header.to_json_vec()?),
+    encode_b64_unpadded(&claims.to_json_vec()?),
+  );
+
+  // The signature is returned as <SIGNATURE><MESSAGE>
+  let signature: Vec<u8> = Ed25519.sign(input.as_bytes(), secret.as_ref())?;
+
+  Ok(format!(
+    "{}.{}",
+    input,
+    encode_b64_unpadded(&signature[..SIGNATURE_SIZE])
+  ))
+}
+
+/// Verifies the signature of `token` with the verification method of
+/// `document` referenced by the `kid` header and returns the signature
+/// options of the method and the decoded claims.
+///
+/// The method must be part of the verification relationship `scope`, e.g.
+/// `AssertionMethod` for credentials or `Authentication` for presentations.
+///
+/// Tokens without a media type are only accepted as plain JWTs.
+pub(crate) fn jwt_decode<R, M>(
+  token: &str,
+  typ: &str,
+  scope: MethodScope,
+  document: &R,
+) -> Result<(SignatureOptions, Vec<u8>)>
+where
+  R: ResolveMethod<M>,
+{
+  let (header, claims, signature): (&str, &str, &str) = split(token)?;
+  let decoded: JwtHeader = JwtHeader::from_json_slice(&decode_b64_unpadded(header)?)?;
+
+  if decoded.alg != JWT_ALGORITHM {
+    return Err(Error::InvalidJwt);
+  }
+
+  match decoded.typ.as_deref() {
+    Some(value) if value == typ => {}
+    None if typ == JwtHeader::TYPE => {}
+    Some(_) | None => return Err(Error::InvalidJwt),
+  }
+
+  let kid: &str = decoded.kid.as_deref().ok_or(Error::InvalidJwt)?;
+  let method: MethodWrap<'_, M> = document
+    .resolve_method((kid, scope).into())
+    .ok_or(Error::DIDError(identity_did::Error::QueryMethodNotFound))?;
+
+  if method.key_type() != MethodType::Ed25519VerificationKey2018 {
+    return Err(Error::DIDError(identity_did::Error::UnknownMethodType));
+  }
+
+  let public: Vec<u8> = method.key_data().try_decode()?;
+  let signature: Vec<u8> = decode_b64_unpadded(signature)?;
+
+  if signature.len() != SIGNATURE_SIZE {
+    return Err(Error::InvalidJwtSignature);
+  }
+
+  let input: &[u8] = token[..header.len() + 1 + claims.len()].as_bytes();
+
+  Ed25519
+    .verify(input, &[signature.as_slice(), input].concat(), &public)
+    .map_err(|_| Error::InvalidJwtSignature)?;
+
+  Ok((method.into(), decode_b64_unpadded(claims)?))
+}
+
+/// Decodes the header and claims of `token` WITHOUT verifying the signature.
+pub(crate) fn jwt_decode_unverified(token: &str) -> Result<(JwtHeader, Vec<u8>)> {
+  let (header, claims, _): (&str, &str, &str) = split(token)?;
+  let header: JwtHeader = JwtHeader::from_json_slice(&decode_b64_unpadded(header)?)?;
+
+  Ok((header, decode_b64_unpadded(claims)?))
+}
+
+/// Creates a proof embedding `token` as the JWS of a `JwtProof2020` signature.
+pub(crate) fn jwt_proof(options: SignatureOptions, token: &str) -> Signature {
+  let mut proof: Signature = Signature::new(JWT_PROOF_TYPE, options);
+  proof.set_data(SignatureData::Jws(token.to_string()));
+  proof
+}
+
+/// Returns the token embedded in a `JwtProof2020` signature, if any.
+pub(crate) fn jwt_token(proof: &Signature) -> Option<&str> {
+  if proof.type_() == JWT_PROOF_TYPE {
+    proof.data().try_jws()
+  } else {
+    None
+  }
+}
+
+fn split(token: &str) -> Result<(&str, &str, &str)> {
+  let mut segments = token.split('.');
+
+  match (segments.next(), segments.next(), segments.next(), segments.next()) {
+    (Some(header), Some(claims), Some(signature), None) => Ok((header, claims, signature)),
+    _ => Err(Error::InvalidJwt),
+  }
+}
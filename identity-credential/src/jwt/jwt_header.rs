@@ -0,0 +1,35 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// The JOSE header of a JWT-encoded Credential or Presentation.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct JwtHeader {
+  /// The algorithm used to sign the token.
+  pub alg: String,
+  /// The media type of the token.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub typ: Option<String>,
+  /// The id of the verification method used to sign the token.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub kid: Option<String>,
+}
+
+impl JwtHeader {
+  /// The media type of JWT-encoded Credentials and Presentations.
+  pub const TYPE: &'static str = "JWT";
+
+  /// The media type of Credentials secured with JOSE, where the claims set
+  /// is the Credential itself.
+  ///
+  /// [More Info](https://www.w3.org/TR/vc-jose-cose/#securing-with-jose)
+  pub const TYPE_VC: &'static str = "vc+jwt";
+
+  /// Creates a new [`JwtHeader`] referencing the verification method `kid`.
+  pub fn new(alg: impl Into<String>, kid: impl Into<String>) -> Self {
+    Self {
+      alg: alg.into(),
+      typ: Some(Self::TYPE.to_string()),
+      kid: Some(kid.into()),
+    }
+  }
+}
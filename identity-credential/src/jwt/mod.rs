@@ -0,0 +1,17 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Encoding and decoding of Verifiable Credentials/Presentations as JWTs.
+//!
+//! [More Info](https://www.w3.org/TR/vc-data-model/#json-web-token)
+
+mod credential_claims;
+mod jws;
+mod jwt_header;
+mod presentation_claims;
+
+pub use self::credential_claims::CredentialClaims;
+pub use self::jwt_header::JwtHeader;
+pub use self::presentation_claims::PresentationClaims;
+
+pub(crate) use self::jws::*;
@@ -0,0 +1,262 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::SerdeInto;
+use identity_core::convert::ToJson;
+use identity_core::crypto::SecretKey;
+use identity_did::document::Document;
+use identity_did::verification::MethodQuery;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::credential::VerifiableCredential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jwt::credential_claims::insert_missing;
+use crate::jwt::credential_claims::unix_to_value;
+use crate::jwt::jwt_decode_unverified;
+use crate::jwt::jwt_encode;
+use crate::jwt::JwtHeader;
+use crate::presentation::Presentation;
+
+const CREDENTIALS: &str = "verifiableCredential";
+
+/// The JWT claims set of a JWT-encoded `Presentation`.
+///
+/// Credentials of the `Presentation` decoded from a JWT are embedded as the
+/// original compact JWS string.
+///
+/// [More Info](https://www.w3.org/TR/vc-data-model/#jwt-encoding)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PresentationClaims {
+  /// The holder of the `Presentation`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub iss: Option<String>,
+  /// The intended audience of the `Presentation`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aud: Option<String>,
+  /// The id of the `Presentation`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub jti: Option<String>,
+  /// The time the `Presentation` becomes valid as a Unix timestamp.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub nbf: Option<i64>,
+  /// The expiration time of the `Presentation` as a Unix timestamp.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub exp: Option<i64>,
+  /// A challenge provided by the verifier to prevent replay attacks.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub nonce: Option<String>,
+  /// The JSON representation of the `Presentation`.
+  pub vp: Object,
+}
+
+impl PresentationClaims {
+  /// Creates a new [`PresentationClaims`] set from the given `presentation`.
+  pub fn new<T, U>(presentation: &Presentation<T, U>) -> Result<Self>
+  where
+    T: Serialize,
+    U: Serialize,
+  {
+    let mut vp: Object = presentation.serde_into()?;
+
+    // Embed JWT-encoded credentials in their original form
+    if presentation
+      .verifiable_credential
+      .iter()
+      .any(|credential| credential.jwt().is_some())
+    {
+      let credentials: Vec<Value> = presentation
+        .verifiable_credential
+        .iter()
+        .map(|credential| match credential.jwt() {
+          Some(token) => Ok(Value::String(token.to_string())),
+          None => credential.to_json_value(),
+        })
+        .collect::<identity_core::Result<_>>()?;
+
+      let credentials: Value = match OneOrMany::from(credentials) {
+        OneOrMany::One(credential) => credential,
+        OneOrMany::Many(credentials) => Value::Array(credentials),
+      };
+
+      vp.insert(CREDENTIALS.to_string(), credentials);
+    }
+
+    Ok(Self {
+      iss: presentation.holder.as_ref().map(ToString::to_string),
+      aud: None,
+      jti: presentation.id.as_ref().map(ToString::to_string),
+      nbf: Some(Timestamp::now().to_unix()),
+      exp: None,
+      nonce: None,
+      vp,
+    })
+  }
+
+  /// Sets the intended audience of the `Presentation`.
+  #[must_use]
+  pub fn audience(mut self, value: impl Into<String>) -> Self {
+    self.aud = Some(value.into());
+    self
+  }
+
+  /// Sets the challenge provided by the verifier.
+  #[must_use]
+  pub fn nonce(mut self, value: impl Into<String>) -> Self {
+    self.nonce = Some(value.into());
+    self
+  }
+
+  /// Sets the expiration time of the `Presentation`.
+  #[must_use]
+  pub fn expiration(mut self, value: Timestamp) -> Self {
+    self.exp = Some(value.to_unix());
+    self
+  }
+
+  /// Decodes the claims set of `token` WITHOUT verifying the signature.
+  pub fn decode(token: &str) -> Result<Self> {
+    jwt_decode_unverified(token).and_then(|(_, claims)| Self::from_json_slice(&claims).map_err(Into::into))
+  }
+
+  /// Signs the claims set with the verification method of `document` matching
+  /// `query` and returns the encoded JWT.
+  pub fn sign<'a, Q, D1, D2, D3>(&self, document: &Document<D1, D2, D3>, query: Q, secret: &SecretKey) -> Result<String>
+  where
+    Q: Into<MethodQuery<'a>>,
+  {
+    jwt_encode(self, JwtHeader::TYPE, document, query, secret)
+  }
+
+  /// Returns `true` if the `Presentation` is expired at the given time.
+  pub fn is_expired(&self, now: Timestamp) -> bool {
+    self.exp.map_or(false, |exp| exp <= now.to_unix())
+  }
+
+  /// Consumes the claims set and returns the encoded `Presentation`.
+  ///
+  /// Embedded JWT-encoded credentials are decoded WITHOUT verification; use
+  /// [`VerifiableCredential::from_jwt`] to verify them with the DID document
+  /// of their issuer.
+  pub fn into_presentation<T, U>(self) -> Result<Presentation<T, U>>
+  where
+    T: DeserializeOwned,
+    U: DeserializeOwned,
+  {
+    let mut vp: Object = self.vp;
+
+    insert_missing(&mut vp, "holder", self.iss.clone().map(Value::String));
+    insert_missing(&mut vp, "id", self.jti.clone().map(Value::String));
+
+    // Validate the time claims even though they have no `Presentation` property
+    unix_to_value(self.nbf)?;
+    unix_to_value(self.exp)?;
+
+    let credentials: Option<Value> = vp.remove(CREDENTIALS);
+    let mut presentation: Presentation<T, U> = Presentation::from_json_value(vp.into_iter().collect())?;
+
+    let credentials: Vec<Value> = match credentials {
+      Some(Value::Array(credentials)) => credentials,
+      Some(credential) => vec![credential],
+      None => Vec::new(),
+    };
+
+    presentation.verifiable_credential = credentials
+      .into_iter()
+      .map(|credential| match credential {
+        Value::String(token) => VerifiableCredential::from_jwt_unverified(&token),
+        credential => VerifiableCredential::from_json_value(credential).map_err(Into::into),
+      })
+      .collect::<Result<Vec<_>>>()?
+      .into();
+
+    let consistent: bool = self.iss.as_deref().map_or(true, |iss| {
+      presentation.holder.as_ref().map(|holder| holder.as_str()) == Some(iss)
+    }) && self
+      .jti
+      .as_deref()
+      .map_or(true, |jti| presentation.id.as_ref().map(|id| id.as_str()) == Some(jti));
+
+    if !consistent {
+      return Err(Error::InvalidJwtClaims);
+    }
+
+    presentation.check_structure()?;
+
+    Ok(presentation)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::crypto::KeyPair;
+  use identity_did::verification::MethodScope;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::presentation::PresentationBuilder;
+  use crate::presentation::VerifiablePresentation;
+  use crate::test_utils::document;
+
+  const QUERY: (&str, MethodScope) = ("#key-1", MethodScope::Authentication);
+
+  #[test]
+  fn test_sign_and_verify() {
+    let issuer_key: KeyPair = KeyPair::new_ed25519().unwrap();
+    let issuer: Document = document("did:example:issuer", &issuer_key, &[MethodScope::AssertionMethod]);
+    let holder_key: KeyPair = KeyPair::new_ed25519().unwrap();
+    let holder: Document = document("did:example:holder", &holder_key, &[MethodScope::Authentication]);
+
+    let credential: Credential = CredentialBuilder::default()
+      .subject(Subject::from_json_value(json!({ "id": "did:example:holder" })).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .build()
+      .unwrap();
+
+    let token: String = credential
+      .sign_jwt(&issuer, ("#key-1", MethodScope::AssertionMethod), issuer_key.secret())
+      .unwrap();
+    let credential: VerifiableCredential = VerifiableCredential::from_jwt(&token, &issuer).unwrap();
+
+    let presentation: Presentation = PresentationBuilder::default()
+      .holder(Url::parse("did:example:holder").unwrap())
+      .credential(credential.clone())
+      .build()
+      .unwrap();
+
+    let claims: PresentationClaims = PresentationClaims::new(&presentation)
+      .unwrap()
+      .audience("did:example:verifier")
+      .nonce("c0ae1c8e");
+
+    assert_eq!(claims.vp[CREDENTIALS], token.as_str());
+
+    let encoded: String = claims.sign(&holder, QUERY, holder_key.secret()).unwrap();
+    let (decoded, claims): (VerifiablePresentation, PresentationClaims) =
+      VerifiablePresentation::from_jwt(&encoded, &holder).unwrap();
+
+    assert_eq!(claims.aud.as_deref(), Some("did:example:verifier"));
+    assert_eq!(claims.nonce.as_deref(), Some("c0ae1c8e"));
+    assert_eq!(decoded.holder, presentation.holder);
+    assert_eq!(
+      decoded.verifiable_credential.get(0).unwrap().jwt(),
+      Some(token.as_str())
+    );
+    assert_eq!(*decoded.verifiable_credential.get(0).unwrap(), credential);
+
+    assert!(matches!(
+      VerifiablePresentation::<Object, Object>::from_jwt(&encoded, &issuer),
+      Err(_)
+    ));
+  }
+}
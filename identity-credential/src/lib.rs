@@ -23,7 +23,18 @@ extern crate serde;
 
 pub mod credential;
 pub mod error;
+pub mod exchange;
+pub mod integrity;
+pub mod jwt;
+pub mod merkle;
 pub mod presentation;
+pub mod schema;
+pub mod sd_jwt;
+pub mod verifier;
+pub mod wallet;
+
+#[cfg(test)]
+mod test_utils;
 
 pub use self::error::Error;
 pub use self::error::Result;
@@ -0,0 +1,78 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Value;
+use identity_core::convert::ToJson;
+use identity_core::crypto::merkle_key::MerkleKey;
+use identity_core::crypto::merkle_tree::Digest;
+use identity_core::crypto::merkle_tree::DigestExt;
+use identity_core::crypto::merkle_tree::Hash;
+use identity_core::crypto::merkle_tree::Proof;
+use identity_core::utils::decode_b58;
+use identity_core::utils::encode_b58;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::Sha256;
+
+use crate::error::Error;
+use crate::error::Result;
+
+const SALT_SIZE: usize = 16;
+
+/// A salted `credentialSubject` property with a proof of inclusion in the
+/// Merkle tree of the subject.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MerkleClaim {
+  /// The index of the `credentialSubject` the claim belongs to.
+  pub subject: usize,
+  /// The name of the claim.
+  pub name: String,
+  /// The value of the claim.
+  pub value: Value,
+  /// The base58-encoded salt of the claim.
+  pub salt: String,
+  /// The base58-encoded inclusion proof of the claim.
+  pub proof: String,
+}
+
+impl MerkleClaim {
+  /// Returns the Merkle tree leaf hash of the claim.
+  ///
+  /// The leaf is computed from the JCS serialization of `[salt, name, value]`.
+  pub fn hash(&self) -> Result<Hash<Sha256>> {
+    leaf(&self.salt, &self.name, &self.value)
+  }
+
+  /// Verifies the inclusion of the claim in the Merkle tree with the given
+  /// `root` hash.
+  pub fn verify(&self, root: &Hash<Sha256>) -> Result<()> {
+    let proof: Proof<Sha256> = decode_b58(&self.proof)
+      .ok()
+      .and_then(|proof| MerkleKey::decode_proof(&proof))
+      .ok_or(Error::InvalidMerkleProof)?;
+
+    if proof.verify(root, self.hash()?) {
+      Ok(())
+    } else {
+      Err(Error::InvalidMerkleProof)
+    }
+  }
+}
+
+pub(crate) fn leaf(salt: &str, name: &str, value: &Value) -> Result<Hash<Sha256>> {
+  let data: Vec<u8> = (salt, name, value).to_jcs()?;
+
+  Ok(Sha256::new().hash_leaf(&data))
+}
+
+pub(crate) fn salt() -> String {
+  let mut salt: [u8; SALT_SIZE] = [0; SALT_SIZE];
+
+  OsRng.fill_bytes(&mut salt);
+
+  encode_b58(&salt)
+}
+
+pub(crate) fn encode_proof(proof: &Proof<Sha256>) -> String {
+  encode_b58(&MerkleKey::encode_proof(proof))
+}
@@ -0,0 +1,272 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::mem;
+use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_core::crypto::merkle_tree::Digest;
+use identity_core::crypto::merkle_tree::DigestExt;
+use identity_core::crypto::merkle_tree::Hash;
+use identity_core::crypto::merkle_tree::MTree;
+use identity_core::crypto::JcsEd25519Signature2020;
+use identity_core::crypto::SecretKey;
+use identity_core::utils::decode_b58;
+use identity_core::utils::encode_b58;
+use identity_did::document::Document;
+use identity_did::verifiable::LdSuite;
+use identity_did::verification::MethodQuery;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::Serialize;
+use sha2::Sha256;
+
+use crate::credential::Credential;
+use crate::credential::Subject;
+use crate::credential::VerifiableCredential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::merkle::merkle_claim::encode_proof;
+use crate::merkle::merkle_claim::leaf;
+use crate::merkle::merkle_claim::salt;
+use crate::merkle::MerkleClaim;
+
+/// The `credentialSubject` property holding the Merkle root of the subject.
+const ROOT: &str = "merkleRoot";
+
+/// A `VerifiableCredential` whose subject claims are committed to by the
+/// signed Merkle root of each `credentialSubject`.
+///
+/// The `id` of each subject remains visible.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MerkleCredential<T = Object> {
+  #[serde(flatten)]
+  credential: VerifiableCredential<T>,
+  #[serde(default, rename = "merkleClaims", skip_serializing_if = "Vec::is_empty")]
+  claims: Vec<MerkleClaim>,
+}
+
+impl<T> MerkleCredential<T> {
+  /// Replaces the subject claims of `credential` with salted Merkle roots and
+  /// signs the result with `document` and `secret`.
+  ///
+  /// The returned credential includes every claim with its inclusion proof.
+  pub fn issue<'a, Q, D1, D2, D3>(
+    credential: Credential<T>,
+    document: &Document<D1, D2, D3>,
+    query: Q,
+    secret: &SecretKey,
+  ) -> Result<Self>
+  where
+    T: Serialize,
+    Q: Into<MethodQuery<'a>>,
+  {
+    let mut credential: Credential<T> = credential;
+    let mut claims: Vec<MerkleClaim> = Vec::new();
+
+    for index in 0..credential.credential_subject.len() {
+      let subject: &mut Subject = credential.credential_subject.get_mut(index).expect("infallible");
+      let properties: Object = mem::take(&mut subject.properties);
+
+      let salted: Vec<(String, Value, String)> = properties
+        .into_iter()
+        .map(|(name, value)| (name, value, salt()))
+        .collect();
+
+      let mut leaves: Vec<Hash<Sha256>> = salted
+        .iter()
+        .map(|(name, value, salt)| leaf(salt, name, value))
+        .collect::<Result<_>>()?;
+
+      // The tree requires a power-of-two number of leaves; random padding
+      // also hides the exact number of claims.
+      let size: usize = leaves.len().max(2).next_power_of_two();
+
+      while leaves.len() < size {
+        leaves.push(padding());
+      }
+
+      let tree: MTree<Sha256> = MTree::from_leaves(&leaves).ok_or(Error::InvalidMerkleProof)?;
+
+      for (local, (name, value, salt)) in salted.into_iter().enumerate() {
+        claims.push(MerkleClaim {
+          subject: index,
+          name,
+          value,
+          salt,
+          proof: encode_proof(&tree.proof(local).ok_or(Error::InvalidMerkleProof)?),
+        });
+      }
+
+      subject
+        .properties
+        .insert(ROOT.to_string(), Value::String(encode_b58(tree.root())));
+    }
+
+    Ok(Self {
+      credential: credential.sign(document, query, secret)?,
+      claims,
+    })
+  }
+
+  /// Returns the signed `VerifiableCredential` holding the Merkle roots.
+  pub fn credential(&self) -> &VerifiableCredential<T> {
+    &self.credential
+  }
+
+  /// Returns the revealed claims.
+  pub fn claims(&self) -> &[MerkleClaim] {
+    &self.claims
+  }
+
+  /// Returns a copy revealing only the claims matching the given `predicate`.
+  pub fn select<F>(&self, predicate: F) -> Self
+  where
+    T: Clone,
+    F: FnMut(&&MerkleClaim) -> bool,
+  {
+    Self {
+      credential: self.credential.clone(),
+      claims: self.claims.iter().filter(predicate).cloned().collect(),
+    }
+  }
+
+  /// Returns a copy revealing only the claims with the given `names`.
+  pub fn disclose(&self, names: &[&str]) -> Self
+  where
+    T: Clone,
+  {
+    self.select(|claim| names.contains(&claim.name.as_str()))
+  }
+
+  /// Verifies the issuer signature and the inclusion proof of every revealed
+  /// claim and returns the `Credential` with the revealed claims.
+  pub fn verify<D1, D2, D3>(&self, issuer: &Document<D1, D2, D3>) -> Result<Credential<T>>
+  where
+    T: Clone + Serialize,
+    D2: Serialize,
+  {
+    if self.credential.issuer.url().as_str() != issuer.id().as_str() {
+      return Err(Error::IssuerMismatch);
+    }
+
+    LdSuite::new(JcsEd25519Signature2020).verify_data(&self.credential, issuer)?;
+
+    let mut credential: Credential<T> = (*self.credential).clone();
+    let mut roots: Vec<Hash<Sha256>> = Vec::with_capacity(credential.credential_subject.len());
+
+    for index in 0..credential.credential_subject.len() {
+      let subject: &mut Subject = credential.credential_subject.get_mut(index).expect("infallible");
+
+      let root: Option<Hash<Sha256>> = match subject.properties.remove(ROOT) {
+        Some(Value::String(root)) => decode_b58(&root).ok().and_then(|root| Hash::from_slice(&root)),
+        Some(_) | None => None,
+      };
+
+      // Claims are only committed to by the signed root
+      if !subject.properties.is_empty() {
+        return Err(Error::InvalidMerkleProof);
+      }
+
+      roots.push(root.ok_or(Error::InvalidMerkleProof)?);
+    }
+
+    for claim in self.claims.iter() {
+      let root: &Hash<Sha256> = roots.get(claim.subject).ok_or(Error::InvalidMerkleProof)?;
+
+      claim.verify(root)?;
+
+      let subject: &mut Subject = credential
+        .credential_subject
+        .get_mut(claim.subject)
+        .ok_or(Error::InvalidMerkleProof)?;
+
+      if subject
+        .properties
+        .insert(claim.name.clone(), claim.value.clone())
+        .is_some()
+      {
+        return Err(Error::InvalidMerkleProof);
+      }
+    }
+
+    Ok(credential)
+  }
+}
+
+fn padding() -> Hash<Sha256> {
+  let mut data: [u8; 32] = [0; 32];
+
+  OsRng.fill_bytes(&mut data);
+
+  Sha256::new().hash_leaf(&data)
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use identity_core::crypto::KeyPair;
+  use identity_did::verification::MethodScope;
+
+  use super::*;
+  use crate::test_utils::credential;
+  use crate::test_utils::issuer;
+
+  fn issue(document: &Document, keypair: &KeyPair) -> MerkleCredential {
+    MerkleCredential::issue(
+      credential(),
+      document,
+      ("#key-1", MethodScope::AssertionMethod),
+      keypair.secret(),
+    )
+    .unwrap()
+  }
+
+  #[test]
+  fn test_issue_and_verify() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+    let issued: MerkleCredential = issue(&document, &keypair);
+    let subject: &Subject = issued.credential().credential_subject.get(0).unwrap();
+
+    assert_eq!(issued.claims().len(), 3);
+    assert_eq!(subject.properties.len(), 1);
+    assert!(subject.properties.contains_key(ROOT));
+
+    let decoded: MerkleCredential = MerkleCredential::from_json(&issued.to_json().unwrap()).unwrap();
+
+    assert_eq!(decoded, issued);
+    assert_eq!(decoded.verify(&document).unwrap(), credential());
+  }
+
+  #[test]
+  fn test_disclose() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+    let disclosed: MerkleCredential = issue(&document, &keypair).disclose(&["degree"]);
+    let credential: Credential = disclosed.verify(&document).unwrap();
+    let subject: &Subject = credential.credential_subject.get(0).unwrap();
+
+    assert_eq!(disclosed.claims().len(), 1);
+    assert_eq!(subject.id.as_ref().unwrap(), "did:example:holder");
+    assert_eq!(subject.properties.len(), 1);
+    assert_eq!(subject.properties["degree"]["type"], "BachelorDegree");
+  }
+
+  #[test]
+  fn test_tampered_claim() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+    let mut disclosed: MerkleCredential = issue(&document, &keypair).disclose(&["birthdate"]);
+
+    disclosed.claims[0].value = "2000-01-01".into();
+
+    assert!(matches!(disclosed.verify(&document), Err(Error::InvalidMerkleProof)));
+
+    let mut disclosed: MerkleCredential = issue(&document, &keypair).disclose(&["birthdate"]);
+
+    disclosed.claims[0].subject = 1;
+
+    assert!(matches!(disclosed.verify(&document), Err(Error::InvalidMerkleProof)));
+  }
+}
@@ -0,0 +1,14 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Selective disclosure of Verifiable Credentials with salted Merkle trees.
+//!
+//! Every `credentialSubject` property becomes a salted leaf of a Merkle tree
+//! and the issuer signs only the root hash of each subject. A holder reveals
+//! a subset of the claims alongside their inclusion proofs.
+
+mod merkle_claim;
+mod merkle_credential;
+
+pub use self::merkle_claim::MerkleClaim;
+pub use self::merkle_credential::MerkleCredential;
@@ -6,6 +6,7 @@ use identity_core::common::Object;
 use identity_core::common::Url;
 use identity_core::common::Value;
 
+use crate::credential::CredentialVersion;
 use crate::credential::Policy;
 use crate::credential::Refresh;
 use crate::credential::VerifiableCredential;
@@ -47,6 +48,13 @@ impl<T, U> PresentationBuilder<T, U> {
     self
   }
 
+  /// Sets the data model version by replacing the base JSON-LD context.
+  #[must_use]
+  pub fn version(mut self, value: CredentialVersion) -> Self {
+    self.context[0] = value.base_context().clone();
+    self
+  }
+
   /// Sets the value of the `Presentation` `id`.
   #[must_use]
   pub fn id(mut self, value: Url) -> Self {
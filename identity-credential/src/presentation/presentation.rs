@@ -10,15 +10,26 @@ use identity_core::common::Object;
 use identity_core::common::OneOrMany;
 use identity_core::common::Url;
 use identity_core::convert::ToJson;
+use identity_core::crypto::JcsEd25519Signature2020;
+use identity_core::crypto::SecretKey;
+use identity_core::crypto::SignatureOptions;
+use identity_did::document::Document;
+use identity_did::verifiable::LdSuite;
+use identity_did::verification::MethodQuery;
+use identity_did::verification::MethodType;
+use identity_did::verification::MethodWrap;
 use serde::Serialize;
 
 use crate::credential::Credential;
+use crate::credential::CredentialVersion;
 use crate::credential::Policy;
 use crate::credential::Refresh;
 use crate::credential::VerifiableCredential;
 use crate::error::Error;
 use crate::error::Result;
+use crate::jwt::PresentationClaims;
 use crate::presentation::PresentationBuilder;
+use crate::presentation::VerifiablePresentation;
 
 /// A `Presentation` represents a bundle of one or more `VerifiableCredential`s.
 ///
@@ -87,12 +98,18 @@ impl<T, U> Presentation<T, U> {
     Ok(this)
   }
 
+  /// Returns the data model version of the `Presentation`, if the base
+  /// context is present.
+  pub fn version(&self) -> Option<CredentialVersion> {
+    self.context.get(0).and_then(CredentialVersion::from_context)
+  }
+
   /// Validates the semantic structure of the `Presentation`.
   pub fn check_structure(&self) -> Result<()> {
-    // Ensure the base context is present and in the correct location
-    match self.context.get(0) {
-      Some(context) if context == Self::base_context() => {}
-      Some(_) | None => return Err(Error::MissingBaseContext),
+    // Ensure the base context of a supported version is present and in the
+    // correct location
+    if self.version().is_none() {
+      return Err(Error::MissingBaseContext);
     }
 
     // The set of types MUST contain the base type
@@ -107,6 +124,53 @@ impl<T, U> Presentation<T, U> {
 
     Ok(())
   }
+
+  /// Creates a new [`VerifiablePresentation`] by signing `self` with
+  /// `document` and `secret`.
+  pub fn sign<'a, Q, D1, D2, D3>(
+    self,
+    document: &Document<D1, D2, D3>,
+    query: Q,
+    secret: &SecretKey,
+  ) -> Result<VerifiablePresentation<T, U>>
+  where
+    T: Serialize,
+    U: Serialize,
+    Q: Into<MethodQuery<'a>>,
+  {
+    let method: MethodWrap<'_, D2> = document.try_resolve(query)?;
+
+    match method.key_type() {
+      MethodType::Ed25519VerificationKey2018 => {
+        let options: SignatureOptions = method.into();
+        let suite: LdSuite<_> = LdSuite::new(JcsEd25519Signature2020);
+
+        let mut verifiable: VerifiablePresentation<T, U> = VerifiablePresentation::new(self, Vec::new());
+
+        suite.sign(&mut verifiable, options, secret)?;
+
+        Ok(verifiable)
+      }
+      _ => Err(Error::DIDError(identity_did::Error::UnknownMethodType)),
+    }
+  }
+
+  /// Encodes `self` as a VP-JWT signed with `document` and `secret`.
+  ///
+  /// Use [`PresentationClaims`] to include an audience, nonce, or expiration.
+  pub fn sign_jwt<'a, Q, D1, D2, D3>(
+    &self,
+    document: &Document<D1, D2, D3>,
+    query: Q,
+    secret: &SecretKey,
+  ) -> Result<String>
+  where
+    T: Serialize,
+    U: Serialize,
+    Q: Into<MethodQuery<'a>>,
+  {
+    PresentationClaims::new(self)?.sign(document, query, secret)
+  }
 }
 
 impl<T, U> Display for Presentation<T, U>
@@ -149,7 +213,7 @@ mod tests {
     assert_eq!(credential.id.as_ref().unwrap(), "http://example.edu/credentials/3732");
     assert_eq!(credential.types.as_slice(), ["VerifiableCredential", "UniversityDegreeCredential"]);
     assert_eq!(credential.issuer.url(), "https://example.edu/issuers/14");
-    assert_eq!(credential.issuance_date, "2010-01-01T19:23:24Z".parse().unwrap());
+    assert_eq!(credential.issuance_date, Some("2010-01-01T19:23:24Z".parse().unwrap()));
     assert_eq!(credential.proof().get(0).unwrap().type_(), "RsaSignature2018");
 
     assert_eq!(subject.id.as_ref().unwrap(), "did:example:ebfeb1f712ebc6f1c276e12ec21");
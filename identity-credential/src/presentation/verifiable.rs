@@ -9,13 +9,24 @@ use core::ops::Deref;
 use core::ops::DerefMut;
 use identity_core::common::Object;
 use identity_core::common::OneOrMany;
+use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
 use identity_core::crypto::SetSignature;
 use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureOptions;
 use identity_core::crypto::TrySignature;
 use identity_core::crypto::TrySignatureMut;
+use identity_did::document::Document;
+use identity_did::verification::MethodScope;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+use crate::error::Error;
+use crate::error::Result;
+use crate::jwt::jwt_decode;
+use crate::jwt::jwt_proof;
+use crate::jwt::JwtHeader;
+use crate::jwt::PresentationClaims;
 use crate::presentation::Presentation;
 
 /// A `VerifiablePresentation` represents a `Presentation` with an associated
@@ -49,6 +60,28 @@ impl<T, U> VerifiablePresentation<T, U> {
   pub fn proof_mut(&mut self) -> &mut OneOrMany<Signature> {
     &mut self.proof
   }
+
+  /// Verifies and decodes a VP-JWT signed by the DID document `holder`.
+  ///
+  /// The claims set is returned alongside the presentation so the audience,
+  /// nonce, and expiration can be checked by the caller.
+  pub fn from_jwt<D1, D2, D3>(token: &str, holder: &Document<D1, D2, D3>) -> Result<(Self, PresentationClaims)>
+  where
+    T: DeserializeOwned,
+    U: DeserializeOwned,
+  {
+    let (options, claims): (SignatureOptions, Vec<u8>) =
+      jwt_decode(token, JwtHeader::TYPE, MethodScope::Authentication, holder)?;
+    let claims: PresentationClaims = PresentationClaims::from_json_slice(&claims)?;
+    let presentation: Presentation<T, U> = claims.clone().into_presentation()?;
+
+    match presentation.holder.as_ref() {
+      Some(url) if url.as_str() == holder.id().as_str() => {}
+      Some(_) | None => return Err(Error::IssuerMismatch),
+    }
+
+    Ok((Self::new(presentation, jwt_proof(options, token)), claims))
+  }
 }
 
 impl<T, U> Deref for VerifiablePresentation<T, U> {
@@ -0,0 +1,43 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Value;
+
+/// Supported JSON Schema specification versions.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Draft {
+  /// JSON Schema draft 7.
+  ///
+  /// [More Info](https://json-schema.org/specification-links.html#draft-7)
+  Draft7,
+  /// JSON Schema draft 2020-12.
+  ///
+  /// [More Info](https://json-schema.org/specification-links.html#2020-12)
+  Draft202012,
+}
+
+impl Draft {
+  /// Detects the specification version from the `$schema` keyword of
+  /// `schema`, falling back to `default`.
+  pub fn detect(schema: &Value, default: Self) -> Self {
+    match schema.get("$schema").and_then(Value::as_str) {
+      Some(uri) if uri.contains("draft-07") => Self::Draft7,
+      Some(uri) if uri.contains("2020-12") => Self::Draft202012,
+      Some(_) | None => default,
+    }
+  }
+
+  /// Returns the name of the keyword containing reusable schema definitions.
+  pub const fn definitions(self) -> &'static str {
+    match self {
+      Self::Draft7 => "definitions",
+      Self::Draft202012 => "$defs",
+    }
+  }
+}
+
+impl Default for Draft {
+  fn default() -> Self {
+    Self::Draft202012
+  }
+}
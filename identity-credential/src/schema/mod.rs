@@ -0,0 +1,16 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! JSON Schema validation of Verifiable Credentials.
+
+mod draft;
+mod schema_error;
+mod schema_registry;
+mod schema_validator;
+
+pub use self::draft::Draft;
+pub use self::schema_error::SchemaError;
+pub use self::schema_registry::LoaderRegistry;
+pub use self::schema_registry::MemoryRegistry;
+pub use self::schema_registry::SchemaRegistry;
+pub use self::schema_validator::SchemaValidator;
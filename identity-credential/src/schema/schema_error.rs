@@ -0,0 +1,38 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+/// A JSON Schema validation error.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SchemaError {
+  /// A JSON Pointer to the invalid value of the validated instance.
+  pub instance_path: String,
+  /// A JSON Pointer to the failed keyword of the schema.
+  pub keyword_path: String,
+  /// A description of the failure.
+  pub message: String,
+}
+
+impl SchemaError {
+  pub(crate) fn new(instance_path: &str, keyword_path: &str, message: impl Into<String>) -> Self {
+    Self {
+      instance_path: instance_path.to_string(),
+      keyword_path: keyword_path.to_string(),
+      message: message.into(),
+    }
+  }
+
+  pub(crate) fn prefixed(mut self, prefix: &str) -> Self {
+    self.instance_path = format!("{}{}", prefix, self.instance_path);
+    self
+  }
+}
+
+impl Display for SchemaError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}: {} ({})", self.instance_path, self.message, self.keyword_path)
+  }
+}
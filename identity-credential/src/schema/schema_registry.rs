@@ -0,0 +1,75 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use std::collections::BTreeMap;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::integrity::ResourceLoader;
+
+/// A source of JSON Schema documents.
+pub trait SchemaRegistry {
+  /// Returns the JSON Schema identified by `url`.
+  ///
+  /// Note: The `url` never contains a fragment.
+  fn schema(&self, url: &Url) -> Result<Value>;
+}
+
+impl<T> SchemaRegistry for &'_ T
+where
+  T: SchemaRegistry + ?Sized,
+{
+  fn schema(&self, url: &Url) -> Result<Value> {
+    (**self).schema(url)
+  }
+}
+
+/// A [`SchemaRegistry`] that serves schemas from memory, e.g. for offline
+/// validation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryRegistry {
+  schemas: BTreeMap<Url, Value>,
+}
+
+impl MemoryRegistry {
+  /// Creates a new, empty `MemoryRegistry`.
+  pub fn new() -> Self {
+    Self {
+      schemas: BTreeMap::new(),
+    }
+  }
+
+  /// Adds a schema to the registry, replacing any existing schema with the
+  /// same `url`.
+  pub fn insert(&mut self, url: Url, schema: Value) {
+    self.schemas.insert(url, schema);
+  }
+
+  /// Removes the schema identified by `url` from the registry.
+  pub fn remove(&mut self, url: &Url) -> Option<Value> {
+    self.schemas.remove(url)
+  }
+}
+
+impl SchemaRegistry for MemoryRegistry {
+  fn schema(&self, url: &Url) -> Result<Value> {
+    self.schemas.get(url).cloned().ok_or(Error::ResourceNotFound)
+  }
+}
+
+/// A [`SchemaRegistry`] that fetches JSON-encoded schemas from a
+/// [`ResourceLoader`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LoaderRegistry<L>(pub L);
+
+impl<L> SchemaRegistry for LoaderRegistry<L>
+where
+  L: ResourceLoader,
+{
+  fn schema(&self, url: &Url) -> Result<Value> {
+    Value::from_json_slice(&self.0.load(url)?).map_err(Into::into)
+  }
+}
@@ -0,0 +1,851 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::cmp::Ordering;
+use core::fmt::Display;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use regex::Regex;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::schema::Draft;
+use crate::schema::SchemaError;
+use crate::schema::SchemaRegistry;
+
+// The maximum number of nested `$ref` resolutions.
+const MAX_DEPTH: usize = 64;
+
+/// A JSON Schema (draft 7 and 2020-12) validator.
+///
+/// Note: The `format`, `$anchor`, and `unevaluated*` keywords are not
+/// supported; `format` is treated as an annotation.
+#[derive(Clone, Copy)]
+pub struct SchemaValidator<'a> {
+  registry: &'a dyn SchemaRegistry,
+  draft: Draft,
+}
+
+#[derive(Clone)]
+struct Scope<'s> {
+  root: &'s Value,
+  base: Option<Url>,
+  draft: Draft,
+  depth: usize,
+}
+
+impl<'a> SchemaValidator<'a> {
+  /// Creates a new `SchemaValidator` resolving schemas from `registry`.
+  pub fn new(registry: &'a dyn SchemaRegistry) -> Self {
+    Self {
+      registry,
+      draft: Draft::default(),
+    }
+  }
+
+  /// Sets the specification version used for schemas without `$schema`.
+  #[must_use]
+  pub fn draft(mut self, value: Draft) -> Self {
+    self.draft = value;
+    self
+  }
+
+  /// Validates `instance` against the schema identified by `url` and returns
+  /// all validation errors.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a schema cannot be resolved or is malformed.
+  pub fn validate(&self, url: &Url, instance: &Value) -> Result<Vec<SchemaError>> {
+    let fragment: String = url.fragment().unwrap_or_default().to_string();
+    let mut base: Url = url.clone();
+
+    base.set_fragment(None);
+
+    let document: Value = self.registry.schema(&base)?;
+
+    let scope: Scope<'_> = Scope {
+      root: &document,
+      base: Some(base),
+      draft: Draft::detect(&document, self.draft),
+      depth: 0,
+    };
+
+    self.validate_scope(&scope, pointer(&document, &fragment)?, instance)
+  }
+
+  /// Validates `instance` against the given `schema` and returns all
+  /// validation errors.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a referenced schema cannot be resolved or is malformed.
+  pub fn validate_schema(&self, schema: &Value, instance: &Value) -> Result<Vec<SchemaError>> {
+    let base: Option<Url> = schema
+      .get("$id")
+      .and_then(Value::as_str)
+      .and_then(|id| Url::parse(id).ok());
+
+    let scope: Scope<'_> = Scope {
+      root: schema,
+      base,
+      draft: Draft::detect(schema, self.draft),
+      depth: 0,
+    };
+
+    self.validate_scope(&scope, schema, instance)
+  }
+
+  fn validate_scope(&self, scope: &Scope<'_>, schema: &Value, instance: &Value) -> Result<Vec<SchemaError>> {
+    let mut errors: Vec<SchemaError> = Vec::new();
+
+    self.node(scope, schema, instance, "", "", &mut errors)?;
+
+    Ok(errors)
+  }
+
+  fn is_valid(&self, scope: &Scope<'_>, schema: &Value, instance: &Value) -> Result<bool> {
+    self
+      .validate_scope(scope, schema, instance)
+      .map(|errors| errors.is_empty())
+  }
+
+  fn node(
+    &self,
+    scope: &Scope<'_>,
+    schema: &Value,
+    instance: &Value,
+    ipath: &str,
+    kpath: &str,
+    errors: &mut Vec<SchemaError>,
+  ) -> Result<()> {
+    match schema {
+      Value::Bool(true) => return Ok(()),
+      Value::Bool(false) => {
+        errors.push(SchemaError::new(ipath, kpath, "no value is allowed"));
+        return Ok(());
+      }
+      Value::Object(_) => {}
+      _ => return Err(Error::InvalidSchema),
+    }
+
+    if let Some(reference) = schema.get("$ref") {
+      let reference: &str = reference.as_str().ok_or(Error::InvalidSchema)?;
+
+      self.reference(scope, reference, instance, ipath, &child(kpath, "$ref"), errors)?;
+
+      // In draft 7, all other keywords are ignored when `$ref` is present.
+      if scope.draft == Draft::Draft7 {
+        return Ok(());
+      }
+    }
+
+    generic(schema, instance, ipath, kpath, errors)?;
+    numeric(schema, instance, ipath, kpath, errors)?;
+    string(schema, instance, ipath, kpath, errors)?;
+
+    self.array(scope, schema, instance, ipath, kpath, errors)?;
+    self.object(scope, schema, instance, ipath, kpath, errors)?;
+    self.combinators(scope, schema, instance, ipath, kpath, errors)?;
+
+    Ok(())
+  }
+
+  fn reference(
+    &self,
+    scope: &Scope<'_>,
+    reference: &str,
+    instance: &Value,
+    ipath: &str,
+    kpath: &str,
+    errors: &mut Vec<SchemaError>,
+  ) -> Result<()> {
+    if scope.depth >= MAX_DEPTH {
+      return Err(Error::InvalidSchema);
+    }
+
+    let (location, fragment): (&str, &str) = match reference.find('#') {
+      Some(index) => (&reference[..index], &reference[index + 1..]),
+      None => (reference, ""),
+    };
+
+    if location.is_empty() {
+      let next: Scope<'_> = Scope {
+        depth: scope.depth + 1,
+        ..scope.clone()
+      };
+
+      return self.node(&next, pointer(scope.root, fragment)?, instance, ipath, kpath, errors);
+    }
+
+    let url: Url = match scope.base.as_ref() {
+      Some(base) => base.join(location)?,
+      None => Url::parse(location)?,
+    };
+
+    let document: Value = self.registry.schema(&url)?;
+
+    let next: Scope<'_> = Scope {
+      root: &document,
+      base: Some(url),
+      draft: Draft::detect(&document, scope.draft),
+      depth: scope.depth + 1,
+    };
+
+    self.node(&next, pointer(&document, fragment)?, instance, ipath, kpath, errors)
+  }
+
+  fn array(
+    &self,
+    scope: &Scope<'_>,
+    schema: &Value,
+    instance: &Value,
+    ipath: &str,
+    kpath: &str,
+    errors: &mut Vec<SchemaError>,
+  ) -> Result<()> {
+    let items: &[Value] = match instance {
+      Value::Array(items) => items,
+      _ => return Ok(()),
+    };
+
+    let mut evaluated: usize = 0;
+
+    if let Some(prefix) = schema.get("prefixItems") {
+      let prefix: &[Value] = prefix.as_array().ok_or(Error::InvalidSchema)?;
+      let path: String = child(kpath, "prefixItems");
+
+      for (index, (item, subschema)) in items.iter().zip(prefix).enumerate() {
+        self.node(
+          scope,
+          subschema,
+          item,
+          &child(ipath, index),
+          &child(&path, index),
+          errors,
+        )?;
+      }
+
+      evaluated = prefix.len();
+    }
+
+    match schema.get("items") {
+      // Draft 7 tuple validation
+      Some(Value::Array(tuple)) => {
+        let path: String = child(kpath, "items");
+
+        for (index, (item, subschema)) in items.iter().zip(tuple).enumerate() {
+          self.node(
+            scope,
+            subschema,
+            item,
+            &child(ipath, index),
+            &child(&path, index),
+            errors,
+          )?;
+        }
+
+        if let Some(additional) = schema.get("additionalItems") {
+          let path: String = child(kpath, "additionalItems");
+
+          for (index, item) in items.iter().enumerate().skip(tuple.len()) {
+            self.node(scope, additional, item, &child(ipath, index), &path, errors)?;
+          }
+        }
+      }
+      Some(subschema) => {
+        let path: String = child(kpath, "items");
+
+        for (index, item) in items.iter().enumerate().skip(evaluated) {
+          self.node(scope, subschema, item, &child(ipath, index), &path, errors)?;
+        }
+      }
+      None => {}
+    }
+
+    if let Some(max) = count(schema, "maxItems")? {
+      if items.len() > max {
+        let message: String = format!("expected at most {} items", max);
+        errors.push(SchemaError::new(ipath, &child(kpath, "maxItems"), message));
+      }
+    }
+
+    if let Some(min) = count(schema, "minItems")? {
+      if items.len() < min {
+        let message: String = format!("expected at least {} items", min);
+        errors.push(SchemaError::new(ipath, &child(kpath, "minItems"), message));
+      }
+    }
+
+    if schema.get("uniqueItems").and_then(Value::as_bool).unwrap_or(false) {
+      let duplicate: bool = items
+        .iter()
+        .enumerate()
+        .any(|(index, item)| items[index + 1..].iter().any(|other| json_eq(item, other)));
+
+      if duplicate {
+        let path: String = child(kpath, "uniqueItems");
+        errors.push(SchemaError::new(ipath, &path, "array items are not unique"));
+      }
+    }
+
+    if let Some(subschema) = schema.get("contains") {
+      let mut matches: usize = 0;
+
+      for item in items {
+        if self.is_valid(scope, subschema, item)? {
+          matches += 1;
+        }
+      }
+
+      let min: usize = count(schema, "minContains")?.unwrap_or(1);
+      let max: Option<usize> = count(schema, "maxContains")?;
+
+      if matches < min {
+        let message: String = format!("expected at least {} matching items", min);
+        errors.push(SchemaError::new(ipath, &child(kpath, "contains"), message));
+      }
+
+      if let Some(max) = max.filter(|max| matches > *max) {
+        let message: String = format!("expected at most {} matching items", max);
+        errors.push(SchemaError::new(ipath, &child(kpath, "maxContains"), message));
+      }
+    }
+
+    Ok(())
+  }
+
+  fn object(
+    &self,
+    scope: &Scope<'_>,
+    schema: &Value,
+    instance: &Value,
+    ipath: &str,
+    kpath: &str,
+    errors: &mut Vec<SchemaError>,
+  ) -> Result<()> {
+    let properties = match instance {
+      Value::Object(properties) => properties,
+      _ => return Ok(()),
+    };
+
+    if let Some(max) = count(schema, "maxProperties")? {
+      if properties.len() > max {
+        let message: String = format!("expected at most {} properties", max);
+        errors.push(SchemaError::new(ipath, &child(kpath, "maxProperties"), message));
+      }
+    }
+
+    if let Some(min) = count(schema, "minProperties")? {
+      if properties.len() < min {
+        let message: String = format!("expected at least {} properties", min);
+        errors.push(SchemaError::new(ipath, &child(kpath, "minProperties"), message));
+      }
+    }
+
+    if let Some(required) = schema.get("required") {
+      required_properties(required, instance, ipath, &child(kpath, "required"), errors)?;
+    }
+
+    let declared = match schema.get("properties") {
+      Some(declared) => Some(declared.as_object().ok_or(Error::InvalidSchema)?),
+      None => None,
+    };
+
+    if let Some(declared) = declared {
+      let path: String = child(kpath, "properties");
+
+      for (name, subschema) in declared.iter() {
+        if let Some(value) = properties.get(name) {
+          self.node(
+            scope,
+            subschema,
+            value,
+            &child(ipath, name),
+            &child(&path, name),
+            errors,
+          )?;
+        }
+      }
+    }
+
+    let mut patterns: Vec<(Regex, &str, &Value)> = Vec::new();
+
+    if let Some(pattern_properties) = schema.get("patternProperties") {
+      for (pattern, subschema) in pattern_properties.as_object().ok_or(Error::InvalidSchema)?.iter() {
+        patterns.push((regex(pattern)?, pattern.as_str(), subschema));
+      }
+
+      let path: String = child(kpath, "patternProperties");
+
+      for (name, value) in properties.iter() {
+        for (regex, pattern, subschema) in patterns.iter() {
+          if regex.is_match(name) {
+            self.node(
+              scope,
+              subschema,
+              value,
+              &child(ipath, name),
+              &child(&path, pattern),
+              errors,
+            )?;
+          }
+        }
+      }
+    }
+
+    if let Some(additional) = schema.get("additionalProperties") {
+      let path: String = child(kpath, "additionalProperties");
+
+      for (name, value) in properties.iter() {
+        if declared.map_or(false, |declared| declared.contains_key(name))
+          || patterns.iter().any(|(regex, _, _)| regex.is_match(name))
+        {
+          continue;
+        }
+
+        if let Value::Bool(false) = additional {
+          let message: String = format!("additional property `{}` is not allowed", name);
+          errors.push(SchemaError::new(ipath, &path, message));
+        } else {
+          self.node(scope, additional, value, &child(ipath, name), &path, errors)?;
+        }
+      }
+    }
+
+    if let Some(subschema) = schema.get("propertyNames") {
+      let path: String = child(kpath, "propertyNames");
+
+      for name in properties.keys() {
+        self.node(scope, subschema, &Value::String(name.clone()), ipath, &path, errors)?;
+      }
+    }
+
+    let dependencies = ["dependentRequired", "dependentSchemas", "dependencies"]
+      .iter()
+      .filter_map(|keyword| schema.get(*keyword).map(|value| (*keyword, value)));
+
+    for (keyword, dependencies) in dependencies {
+      let path: String = child(kpath, keyword);
+
+      for (name, dependency) in dependencies.as_object().ok_or(Error::InvalidSchema)?.iter() {
+        if !properties.contains_key(name) {
+          continue;
+        }
+
+        match dependency {
+          Value::Array(_) => required_properties(dependency, instance, ipath, &child(&path, name), errors)?,
+          _ => self.node(scope, dependency, instance, ipath, &child(&path, name), errors)?,
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  fn combinators(
+    &self,
+    scope: &Scope<'_>,
+    schema: &Value,
+    instance: &Value,
+    ipath: &str,
+    kpath: &str,
+    errors: &mut Vec<SchemaError>,
+  ) -> Result<()> {
+    if let Some(subschemas) = schema.get("allOf") {
+      let path: String = child(kpath, "allOf");
+
+      for (index, subschema) in subschemas.as_array().ok_or(Error::InvalidSchema)?.iter().enumerate() {
+        self.node(scope, subschema, instance, ipath, &child(&path, index), errors)?;
+      }
+    }
+
+    if let Some(subschemas) = schema.get("anyOf") {
+      let mut matched: bool = false;
+
+      for subschema in subschemas.as_array().ok_or(Error::InvalidSchema)? {
+        if self.is_valid(scope, subschema, instance)? {
+          matched = true;
+          break;
+        }
+      }
+
+      if !matched {
+        let path: String = child(kpath, "anyOf");
+        errors.push(SchemaError::new(ipath, &path, "value does not match any schema"));
+      }
+    }
+
+    if let Some(subschemas) = schema.get("oneOf") {
+      let mut matches: usize = 0;
+
+      for subschema in subschemas.as_array().ok_or(Error::InvalidSchema)? {
+        if self.is_valid(scope, subschema, instance)? {
+          matches += 1;
+        }
+      }
+
+      if matches != 1 {
+        let message: String = format!("value matches {} schemas, expected exactly one", matches);
+        errors.push(SchemaError::new(ipath, &child(kpath, "oneOf"), message));
+      }
+    }
+
+    if let Some(subschema) = schema.get("not") {
+      if self.is_valid(scope, subschema, instance)? {
+        let path: String = child(kpath, "not");
+        errors.push(SchemaError::new(ipath, &path, "value must not match the schema"));
+      }
+    }
+
+    if let Some(condition) = schema.get("if") {
+      let branch: &str = if self.is_valid(scope, condition, instance)? {
+        "then"
+      } else {
+        "else"
+      };
+
+      if let Some(subschema) = schema.get(branch) {
+        self.node(scope, subschema, instance, ipath, &child(kpath, branch), errors)?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+fn generic(schema: &Value, instance: &Value, ipath: &str, kpath: &str, errors: &mut Vec<SchemaError>) -> Result<()> {
+  if let Some(types) = schema.get("type") {
+    let matched: bool = match types {
+      Value::String(name) => is_type(instance, name),
+      Value::Array(names) => names
+        .iter()
+        .filter_map(Value::as_str)
+        .any(|name| is_type(instance, name)),
+      _ => return Err(Error::InvalidSchema),
+    };
+
+    if !matched {
+      let message: String = format!("expected type {}", types);
+      errors.push(SchemaError::new(ipath, &child(kpath, "type"), message));
+    }
+  }
+
+  if let Some(values) = schema.get("enum") {
+    let values: &[Value] = values.as_array().ok_or(Error::InvalidSchema)?;
+
+    if !values.iter().any(|value| json_eq(value, instance)) {
+      let path: String = child(kpath, "enum");
+      errors.push(SchemaError::new(ipath, &path, "value is not one of the allowed values"));
+    }
+  }
+
+  if let Some(value) = schema.get("const") {
+    if !json_eq(value, instance) {
+      let path: String = child(kpath, "const");
+      errors.push(SchemaError::new(ipath, &path, "value does not match the constant"));
+    }
+  }
+
+  Ok(())
+}
+
+fn numeric(schema: &Value, instance: &Value, ipath: &str, kpath: &str, errors: &mut Vec<SchemaError>) -> Result<()> {
+  let value: f64 = match instance.as_f64() {
+    Some(value) => value,
+    None => return Ok(()),
+  };
+
+  if let Some(multiple) = number(schema, "multipleOf")? {
+    if multiple <= 0.0 {
+      return Err(Error::InvalidSchema);
+    }
+
+    let quotient: f64 = value / multiple;
+
+    if (quotient - quotient.round()).abs() > 1e-9 {
+      let message: String = format!("expected a multiple of {}", multiple);
+      errors.push(SchemaError::new(ipath, &child(kpath, "multipleOf"), message));
+    }
+  }
+
+  let limits: [(&str, fn(f64, f64) -> bool, &str); 4] = [
+    ("maximum", |value, limit| value > limit, "expected at most"),
+    ("exclusiveMaximum", |value, limit| value >= limit, "expected less than"),
+    ("minimum", |value, limit| value < limit, "expected at least"),
+    ("exclusiveMinimum", |value, limit| value <= limit, "expected more than"),
+  ];
+
+  for (keyword, violates, expected) in limits.iter() {
+    if let Some(limit) = number(schema, keyword)? {
+      if violates(value, limit) {
+        let message: String = format!("{} {}", expected, limit);
+        errors.push(SchemaError::new(ipath, &child(kpath, keyword), message));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+fn string(schema: &Value, instance: &Value, ipath: &str, kpath: &str, errors: &mut Vec<SchemaError>) -> Result<()> {
+  let value: &str = match instance.as_str() {
+    Some(value) => value,
+    None => return Ok(()),
+  };
+
+  let length: usize = value.chars().count();
+
+  if let Some(max) = count(schema, "maxLength")? {
+    if length > max {
+      let message: String = format!("expected at most {} characters", max);
+      errors.push(SchemaError::new(ipath, &child(kpath, "maxLength"), message));
+    }
+  }
+
+  if let Some(min) = count(schema, "minLength")? {
+    if length < min {
+      let message: String = format!("expected at least {} characters", min);
+      errors.push(SchemaError::new(ipath, &child(kpath, "minLength"), message));
+    }
+  }
+
+  if let Some(pattern) = schema.get("pattern") {
+    let pattern: &str = pattern.as_str().ok_or(Error::InvalidSchema)?;
+
+    if !regex(pattern)?.is_match(value) {
+      let message: String = format!("value does not match the pattern `{}`", pattern);
+      errors.push(SchemaError::new(ipath, &child(kpath, "pattern"), message));
+    }
+  }
+
+  Ok(())
+}
+
+fn required_properties(
+  required: &Value,
+  instance: &Value,
+  ipath: &str,
+  kpath: &str,
+  errors: &mut Vec<SchemaError>,
+) -> Result<()> {
+  for name in required.as_array().ok_or(Error::InvalidSchema)? {
+    let name: &str = name.as_str().ok_or(Error::InvalidSchema)?;
+
+    if instance.get(name).is_none() {
+      let message: String = format!("missing required property `{}`", name);
+      errors.push(SchemaError::new(ipath, kpath, message));
+    }
+  }
+
+  Ok(())
+}
+
+fn is_type(instance: &Value, name: &str) -> bool {
+  match name {
+    "null" => instance.is_null(),
+    "boolean" => instance.is_boolean(),
+    "object" => instance.is_object(),
+    "array" => instance.is_array(),
+    "number" => instance.is_number(),
+    "string" => instance.is_string(),
+    "integer" => {
+      instance.is_i64()
+        || instance.is_u64()
+        || instance
+          .as_f64()
+          .map_or(false, |value| value.fract().abs() < f64::EPSILON)
+    }
+    _ => false,
+  }
+}
+
+// Compares JSON values for equality, treating numbers by their mathematical
+// value (i.e. `1` and `1.0` are equal).
+fn json_eq(lhs: &Value, rhs: &Value) -> bool {
+  match (lhs, rhs) {
+    (Value::Number(lhs), Value::Number(rhs)) => lhs.as_f64().partial_cmp(&rhs.as_f64()) == Some(Ordering::Equal),
+    (Value::Array(lhs), Value::Array(rhs)) => {
+      lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(lhs, rhs)| json_eq(lhs, rhs))
+    }
+    (Value::Object(lhs), Value::Object(rhs)) => {
+      lhs.len() == rhs.len()
+        && lhs
+          .iter()
+          .all(|(key, lhs)| rhs.get(key).map_or(false, |rhs| json_eq(lhs, rhs)))
+    }
+    _ => lhs == rhs,
+  }
+}
+
+fn number(schema: &Value, keyword: &str) -> Result<Option<f64>> {
+  schema
+    .get(keyword)
+    .map(|value| value.as_f64().ok_or(Error::InvalidSchema))
+    .transpose()
+}
+
+fn count(schema: &Value, keyword: &str) -> Result<Option<usize>> {
+  schema
+    .get(keyword)
+    .map(|value| value.as_u64().map(|value| value as usize).ok_or(Error::InvalidSchema))
+    .transpose()
+}
+
+fn regex(pattern: &str) -> Result<Regex> {
+  Regex::new(pattern).map_err(|_| Error::InvalidSchema)
+}
+
+fn pointer<'v>(document: &'v Value, fragment: &str) -> Result<&'v Value> {
+  if fragment.is_empty() {
+    Ok(document)
+  } else if fragment.starts_with('/') {
+    document.pointer(fragment).ok_or(Error::InvalidSchema)
+  } else {
+    // Named anchors are not supported
+    Err(Error::InvalidSchema)
+  }
+}
+
+// Appends an escaped reference token to a JSON Pointer.
+fn child(path: &str, token: impl Display) -> String {
+  format!("{}/{}", path, token.to_string().replace('~', "~0").replace('/', "~1"))
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::common::Value;
+  use serde_json::json;
+
+  use crate::schema::MemoryRegistry;
+  use crate::schema::SchemaError;
+  use crate::schema::SchemaValidator;
+
+  fn registry() -> MemoryRegistry {
+    let mut registry: MemoryRegistry = MemoryRegistry::new();
+
+    registry.insert(
+      Url::parse("https://example.org/degree.json").unwrap(),
+      json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "required": ["id", "degree"],
+        "properties": {
+          "id": { "type": "string", "pattern": "^did:" },
+          "degree": { "$ref": "common.json#/$defs/degree" }
+        }
+      }),
+    );
+
+    registry.insert(
+      Url::parse("https://example.org/common.json").unwrap(),
+      json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "$defs": {
+          "degree": {
+            "type": "object",
+            "properties": {
+              "type": { "enum": ["BachelorDegree", "MasterDegree"] },
+              "name": { "type": "string", "minLength": 1 }
+            },
+            "additionalProperties": false
+          }
+        }
+      }),
+    );
+
+    registry
+  }
+
+  fn paths(errors: &[SchemaError]) -> Vec<(&str, &str)> {
+    errors
+      .iter()
+      .map(|error| (error.instance_path.as_str(), error.keyword_path.as_str()))
+      .collect()
+  }
+
+  #[test]
+  fn test_validate_registry() {
+    let registry: MemoryRegistry = registry();
+    let validator: SchemaValidator<'_> = SchemaValidator::new(&registry);
+    let url: Url = Url::parse("https://example.org/degree.json").unwrap();
+
+    let valid: Value = json!({
+      "id": "did:example:123",
+      "degree": { "type": "BachelorDegree", "name": "Bachelor of Science and Arts" }
+    });
+
+    assert!(validator.validate(&url, &valid).unwrap().is_empty());
+
+    let invalid: Value = json!({
+      "id": "urn:uuid:123",
+      "degree": { "type": "Diploma", "name": "", "grade": "A" }
+    });
+
+    assert_eq!(
+      paths(&validator.validate(&url, &invalid).unwrap()),
+      [
+        ("/id", "/properties/id/pattern"),
+        ("/degree/type", "/properties/degree/$ref/properties/type/enum"),
+        ("/degree/name", "/properties/degree/$ref/properties/name/minLength"),
+        ("/degree", "/properties/degree/$ref/additionalProperties"),
+      ]
+    );
+
+    let missing: Value = json!({ "id": "did:example:123" });
+    let errors: Vec<SchemaError> = validator.validate(&url, &missing).unwrap();
+
+    assert_eq!(paths(&errors), [("", "/required")]);
+    assert_eq!(errors[0].message, "missing required property `degree`");
+
+    let unknown: Url = Url::parse("https://example.org/unknown.json").unwrap();
+    assert!(validator.validate(&unknown, &valid).is_err());
+  }
+
+  #[test]
+  fn test_validate_keywords() {
+    let registry: MemoryRegistry = MemoryRegistry::new();
+    let validator: SchemaValidator<'_> = SchemaValidator::new(&registry);
+
+    let schema: Value = json!({
+      "type": "object",
+      "properties": {
+        "age": { "type": "integer", "minimum": 18, "multipleOf": 1 },
+        "tags": { "type": "array", "prefixItems": [{ "const": "a" }], "items": { "type": "string" }, "uniqueItems": true },
+        "kind": { "oneOf": [{ "const": "x" }, { "type": "string", "maxLength": 1 }] },
+        "level": { "if": { "minimum": 10 }, "then": { "multipleOf": 10 }, "else": { "not": { "const": 5 } } }
+      },
+      "dependentRequired": { "age": ["tags"] }
+    });
+
+    let valid: Value = json!({ "age": 21.0, "tags": ["a", "b"], "kind": "y", "level": 20 });
+    assert!(validator.validate_schema(&schema, &valid).unwrap().is_empty());
+
+    let invalid: Value = json!({ "age": 16, "kind": "x", "level": 5 });
+
+    assert_eq!(
+      paths(&validator.validate_schema(&schema, &invalid).unwrap()),
+      [
+        ("/age", "/properties/age/minimum"),
+        ("/kind", "/properties/kind/oneOf"),
+        ("/level", "/properties/level/else/not"),
+        ("", "/dependentRequired/age"),
+      ]
+    );
+
+    let invalid: Value = json!({ "tags": ["b", 1, 1] });
+
+    assert_eq!(
+      paths(&validator.validate_schema(&schema, &invalid).unwrap()),
+      [
+        ("/tags/0", "/properties/tags/prefixItems/0/const"),
+        ("/tags/1", "/properties/tags/items/type"),
+        ("/tags/2", "/properties/tags/items/type"),
+        ("/tags", "/properties/tags/uniqueItems"),
+      ]
+    );
+
+    assert!(validator.validate_schema(&json!({ "type": 1 }), &valid).is_err());
+  }
+}
@@ -0,0 +1,156 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str::FromStr;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::utils::decode_b64_unpadded;
+use identity_core::utils::encode_b64_unpadded;
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::integrity::DigestAlgorithm;
+
+const SALT_SIZE: usize = 16;
+
+/// A selectively disclosable claim of an SD-JWT.
+///
+/// A disclosure is the base64url-encoded JSON array `[salt, name, value]`
+/// referenced from the issuer-signed JWT by its digest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Disclosure {
+  salt: String,
+  name: String,
+  value: Value,
+  encoded: String,
+}
+
+impl Disclosure {
+  /// Creates a new [`Disclosure`] of the claim `name` with a random salt.
+  pub fn new(name: impl Into<String>, value: Value) -> Self {
+    let mut salt: [u8; SALT_SIZE] = [0; SALT_SIZE];
+
+    OsRng.fill_bytes(&mut salt);
+
+    Self::with_salt(encode_b64_unpadded(&salt), name, value)
+  }
+
+  /// Creates a new [`Disclosure`] of the claim `name` with the given `salt`.
+  pub fn with_salt(salt: impl Into<String>, name: impl Into<String>, value: Value) -> Self {
+    let salt: String = salt.into();
+    let name: String = name.into();
+
+    let array: Value = Value::Array(vec![
+      Value::String(salt.clone()),
+      Value::String(name.clone()),
+      value.clone(),
+    ]);
+
+    Self {
+      salt,
+      name,
+      value,
+      encoded: encode_b64_unpadded(&array.to_string()),
+    }
+  }
+
+  /// Parses a [`Disclosure`] from its base64url encoding.
+  pub fn parse(encoded: &str) -> Result<Self> {
+    let array: Value = Value::from_json_slice(&decode_b64_unpadded(encoded)?)?;
+
+    match array {
+      Value::Array(mut values) if values.len() == 3 => {
+        let value: Value = values.pop().expect("infallible");
+
+        match (values.pop(), values.pop()) {
+          (Some(Value::String(name)), Some(Value::String(salt))) => Ok(Self {
+            salt,
+            name,
+            value,
+            encoded: encoded.to_string(),
+          }),
+          _ => Err(Error::InvalidDisclosure),
+        }
+      }
+      _ => Err(Error::InvalidDisclosure),
+    }
+  }
+
+  /// Returns the salt of the disclosure.
+  pub fn salt(&self) -> &str {
+    &self.salt
+  }
+
+  /// Returns the name of the disclosed claim.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Returns the value of the disclosed claim.
+  pub fn value(&self) -> &Value {
+    &self.value
+  }
+
+  /// Returns the base64url encoding of the disclosure.
+  pub fn as_str(&self) -> &str {
+    &self.encoded
+  }
+
+  /// Returns the base64url-encoded SHA-256 digest of the disclosure.
+  pub fn digest(&self) -> String {
+    encode_b64_unpadded(&DigestAlgorithm::Sha256.digest(self.encoded.as_bytes()))
+  }
+}
+
+impl Display for Disclosure {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(&self.encoded)
+  }
+}
+
+impl FromStr for Disclosure {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Self::parse(string)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_roundtrip() {
+    let disclosure: Disclosure = Disclosure::with_salt("_26bc4LT-ac6q2KI6cBW5es", "family_name", "Möbius".into());
+    let parsed: Disclosure = disclosure.as_str().parse().unwrap();
+
+    assert_eq!(parsed, disclosure);
+    assert_eq!(parsed.salt(), "_26bc4LT-ac6q2KI6cBW5es");
+    assert_eq!(parsed.name(), "family_name");
+    assert_eq!(parsed.value(), "Möbius");
+    assert_eq!(parsed.digest(), disclosure.digest());
+  }
+
+  #[test]
+  fn test_random_salt() {
+    let a: Disclosure = Disclosure::new("name", Value::Bool(true));
+    let b: Disclosure = Disclosure::new("name", Value::Bool(true));
+
+    assert_ne!(a.salt(), b.salt());
+    assert_ne!(a.digest(), b.digest());
+  }
+
+  #[test]
+  fn test_parse_invalid() {
+    let encoded: String = encode_b64_unpadded(r#"["salt", "name"]"#);
+
+    assert!(matches!(Disclosure::parse(&encoded), Err(Error::InvalidDisclosure)));
+    assert!(Disclosure::parse("!").is_err());
+  }
+}
@@ -0,0 +1,18 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// The JWT claims set of an SD-JWT key binding JWT.
+///
+/// The key binding JWT proves possession of the holder key and binds the
+/// presented disclosures to a specific verifier and transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct KeyBindingClaims {
+  /// The time the key binding JWT was issued as a Unix timestamp.
+  pub iat: i64,
+  /// The intended audience of the presentation.
+  pub aud: String,
+  /// A challenge provided by the verifier to prevent replay attacks.
+  pub nonce: String,
+  /// The base64url-encoded digest of the presented SD-JWT.
+  pub sd_hash: String,
+}
@@ -0,0 +1,28 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Selective disclosure of Verifiable Credentials encoded as SD-JWTs.
+//!
+//! [More Info](https://datatracker.ietf.org/doc/draft-ietf-oauth-selective-disclosure-jwt/)
+
+mod disclosure;
+mod key_binding_claims;
+mod sd_jwt;
+mod sd_jwt_encoder;
+
+pub use self::disclosure::Disclosure;
+pub use self::key_binding_claims::KeyBindingClaims;
+pub use self::sd_jwt::SdJwt;
+pub use self::sd_jwt_encoder::SdJwtEncoder;
+
+/// The digest algorithm of disclosures.
+pub(crate) const SD_ALGORITHM: &str = "sha-256";
+
+/// The media type of the issuer-signed JWT.
+pub(crate) const SD_JWT_TYPE: &str = "vc+sd-jwt";
+
+/// The media type of the key binding JWT.
+pub(crate) const KB_JWT_TYPE: &str = "kb+jwt";
+
+/// The claim holding the digests of selectively disclosable claims.
+pub(crate) const SD_CLAIM: &str = "_sd";
@@ -0,0 +1,409 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str::FromStr;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::crypto::SecretKey;
+use identity_core::crypto::SignatureOptions;
+use identity_core::utils::encode_b64_unpadded;
+use identity_did::document::Document;
+use identity_did::verification::MethodQuery;
+use identity_did::verification::MethodScope;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::integrity::DigestAlgorithm;
+use crate::jwt::jwt_decode;
+use crate::jwt::jwt_decode_unverified;
+use crate::jwt::jwt_encode;
+use crate::jwt::CredentialClaims;
+use crate::jwt::JwtHeader;
+use crate::sd_jwt::Disclosure;
+use crate::sd_jwt::KeyBindingClaims;
+use crate::sd_jwt::KB_JWT_TYPE;
+use crate::sd_jwt::SD_ALGORITHM;
+use crate::sd_jwt::SD_CLAIM;
+use crate::sd_jwt::SD_JWT_TYPE;
+
+const SEPARATOR: char = '~';
+
+/// A `Credential` encoded as an SD-JWT.
+///
+/// The serialization is the issuer-signed JWT followed by the presented
+/// disclosures and an optional key binding JWT, separated by `~`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SdJwt {
+  jwt: String,
+  disclosures: Vec<Disclosure>,
+  key_binding: Option<String>,
+}
+
+impl SdJwt {
+  /// Creates a new [`SdJwt`] from the issuer-signed `jwt` and `disclosures`.
+  pub fn new(jwt: String, disclosures: Vec<Disclosure>) -> Self {
+    Self {
+      jwt,
+      disclosures,
+      key_binding: None,
+    }
+  }
+
+  /// Parses an [`SdJwt`] from its `~`-separated serialization.
+  pub fn parse(input: &str) -> Result<Self> {
+    let mut segments: Vec<&str> = input.split(SEPARATOR).collect();
+
+    if segments.len() < 2 {
+      return Err(Error::InvalidJwt);
+    }
+
+    let key_binding: Option<String> = match segments.pop() {
+      Some("") | None => None,
+      Some(token) => Some(token.to_string()),
+    };
+
+    let jwt: String = segments.remove(0).to_string();
+
+    let disclosures: Vec<Disclosure> = segments.into_iter().map(Disclosure::parse).collect::<Result<_>>()?;
+
+    Ok(Self {
+      jwt,
+      disclosures,
+      key_binding,
+    })
+  }
+
+  /// Returns the issuer-signed JWT.
+  pub fn jwt(&self) -> &str {
+    &self.jwt
+  }
+
+  /// Returns the presented disclosures.
+  pub fn disclosures(&self) -> &[Disclosure] {
+    &self.disclosures
+  }
+
+  /// Returns the key binding JWT, if any.
+  pub fn key_binding(&self) -> Option<&str> {
+    self.key_binding.as_deref()
+  }
+
+  /// Returns a copy of the SD-JWT presenting only the disclosures matching
+  /// the given `predicate`.
+  ///
+  /// The key binding JWT is removed as it no longer matches the disclosures.
+  pub fn select<F>(&self, mut predicate: F) -> Self
+  where
+    F: FnMut(&Disclosure) -> bool,
+  {
+    Self::new(
+      self.jwt.clone(),
+      self
+        .disclosures
+        .iter()
+        .filter(|disclosure| predicate(disclosure))
+        .cloned()
+        .collect(),
+    )
+  }
+
+  /// Returns a copy of the SD-JWT presenting only the disclosures of the
+  /// claims with the given `names`.
+  ///
+  /// Disclosures of nested claims require the disclosure of their parent.
+  pub fn disclose(&self, names: &[&str]) -> Self {
+    self.select(|disclosure| names.contains(&disclosure.name()))
+  }
+
+  /// Adds a key binding JWT signed by the holder with the verification
+  /// method of `document` matching `query`.
+  pub fn bind<'a, Q, D1, D2, D3>(
+    mut self,
+    document: &Document<D1, D2, D3>,
+    query: Q,
+    secret: &SecretKey,
+    audience: impl Into<String>,
+    nonce: impl Into<String>,
+  ) -> Result<Self>
+  where
+    Q: Into<MethodQuery<'a>>,
+  {
+    self.key_binding = None;
+
+    let claims: KeyBindingClaims = KeyBindingClaims {
+      iat: Timestamp::now().to_unix(),
+      aud: audience.into(),
+      nonce: nonce.into(),
+      sd_hash: self.sd_hash(),
+    };
+
+    self.key_binding = Some(jwt_encode(&claims, KB_JWT_TYPE, document, query, secret)?);
+
+    Ok(self)
+  }
+
+  /// Verifies the issuer signature and returns the `Credential` with all
+  /// presented disclosures revealed.
+  ///
+  /// Claims without a presented disclosure are omitted.
+  pub fn verify<T, D1, D2, D3>(&self, issuer: &Document<D1, D2, D3>) -> Result<Credential<T>>
+  where
+    T: DeserializeOwned,
+  {
+    let (_, claims): (SignatureOptions, Vec<u8>) =
+      jwt_decode(&self.jwt, SD_JWT_TYPE, MethodScope::AssertionMethod, issuer)?;
+    let mut claims: CredentialClaims = CredentialClaims::from_json_slice(&claims)?;
+
+    match claims.sd_alg.take().as_deref() {
+      Some(SD_ALGORITHM) | None => {}
+      Some(_) => return Err(Error::UnsupportedDigestAlgorithm),
+    }
+
+    let mut digests: BTreeMap<String, &Disclosure> = BTreeMap::new();
+
+    for disclosure in self.disclosures.iter() {
+      if digests.insert(disclosure.digest(), disclosure).is_some() {
+        return Err(Error::InvalidDisclosure);
+      }
+    }
+
+    let mut vc: Value = claims.vc.into_iter().collect();
+
+    reveal(&mut vc, &mut digests)?;
+
+    // Every presented disclosure must be referenced by the issuer
+    if !digests.is_empty() {
+      return Err(Error::InvalidDisclosure);
+    }
+
+    claims.vc = Object::from_json_value(vc)?;
+    claims.cnf = None;
+
+    let credential: Credential<T> = claims.into_credential()?;
+
+    if credential.issuer.url().as_str() != issuer.id().as_str() {
+      return Err(Error::IssuerMismatch);
+    }
+
+    Ok(credential)
+  }
+
+  /// Verifies the key binding JWT with the DID document of the `holder`.
+  ///
+  /// The issuer signature is NOT verified; use [`SdJwt::verify`] as well.
+  pub fn verify_key_binding<D1, D2, D3>(
+    &self,
+    holder: &Document<D1, D2, D3>,
+    audience: &str,
+    nonce: &str,
+  ) -> Result<()> {
+    let token: &str = self.key_binding.as_deref().ok_or(Error::InvalidKeyBinding)?;
+    let (options, claims): (SignatureOptions, Vec<u8>) =
+      jwt_decode(token, KB_JWT_TYPE, MethodScope::Authentication, holder)?;
+    let claims: KeyBindingClaims = KeyBindingClaims::from_json_slice(&claims)?;
+
+    if claims.aud != audience || claims.nonce != nonce || claims.sd_hash != self.sd_hash() {
+      return Err(Error::InvalidKeyBinding);
+    }
+
+    // The holder key must match the key requested by the issuer
+    let (_, issuer): (JwtHeader, Vec<u8>) = jwt_decode_unverified(&self.jwt)?;
+    let issuer: CredentialClaims = CredentialClaims::from_json_slice(&issuer)?;
+
+    match issuer.cnf.as_ref().and_then(|cnf| cnf.get("kid")) {
+      Some(Value::String(kid)) if *kid == options.verification_method => Ok(()),
+      Some(_) => Err(Error::InvalidKeyBinding),
+      None => Ok(()),
+    }
+  }
+
+  /// Returns the base64url-encoded digest of the SD-JWT without the key
+  /// binding JWT.
+  fn sd_hash(&self) -> String {
+    let presented: String = Self::new(self.jwt.clone(), self.disclosures.clone()).to_string();
+
+    encode_b64_unpadded(&DigestAlgorithm::Sha256.digest(presented.as_bytes()))
+  }
+}
+
+impl Display for SdJwt {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(&self.jwt)?;
+
+    for disclosure in self.disclosures.iter() {
+      write!(f, "{}{}", SEPARATOR, disclosure)?;
+    }
+
+    write!(f, "{}", SEPARATOR)?;
+
+    if let Some(key_binding) = self.key_binding.as_deref() {
+      f.write_str(key_binding)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl FromStr for SdJwt {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Self::parse(string)
+  }
+}
+
+fn reveal(value: &mut Value, digests: &mut BTreeMap<String, &Disclosure>) -> Result<()> {
+  match value {
+    Value::Object(object) => {
+      match object.remove(SD_CLAIM) {
+        Some(Value::Array(concealed)) => {
+          for digest in concealed {
+            let digest: &str = digest.as_str().ok_or(Error::InvalidDisclosure)?;
+
+            if let Some(disclosure) = digests.remove(digest) {
+              if object.contains_key(disclosure.name()) {
+                return Err(Error::InvalidDisclosure);
+              }
+
+              object.insert(disclosure.name().to_string(), disclosure.value().clone());
+            }
+          }
+        }
+        Some(_) => return Err(Error::InvalidDisclosure),
+        None => {}
+      }
+
+      for inner in object.values_mut() {
+        reveal(inner, digests)?;
+      }
+    }
+    Value::Array(values) => {
+      for inner in values.iter_mut() {
+        reveal(inner, digests)?;
+      }
+    }
+    _ => {}
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::crypto::KeyPair;
+  use identity_did::verification::MethodScope;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::Subject;
+  use crate::sd_jwt::SdJwtEncoder;
+  use crate::test_utils::credential;
+  use crate::test_utils::document;
+
+  const SCOPES: &[MethodScope] = &[MethodScope::AssertionMethod, MethodScope::Authentication];
+
+  fn encode(issuer: &Document, keypair: &KeyPair) -> SdJwt {
+    SdJwtEncoder::new(&credential())
+      .conceal("/birthdate")
+      .unwrap()
+      .conceal("/degree")
+      .unwrap()
+      .conceal("/degree/name")
+      .unwrap()
+      .decoys(2)
+      .holder_binding("did:example:holder#key-1")
+      .sign(issuer, ("#key-1", MethodScope::AssertionMethod), keypair.secret())
+      .unwrap()
+  }
+
+  #[test]
+  fn test_disclose_all() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let issuer: Document = document("did:example:issuer", &keypair, SCOPES);
+    let sd_jwt: SdJwt = encode(&issuer, &keypair);
+
+    assert_eq!(sd_jwt.disclosures().len(), 3);
+
+    let parsed: SdJwt = sd_jwt.to_string().parse().unwrap();
+    let decoded: Credential = parsed.verify(&issuer).unwrap();
+
+    assert_eq!(parsed, sd_jwt);
+    assert_eq!(decoded, credential());
+  }
+
+  #[test]
+  fn test_disclose_selected() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let issuer: Document = document("did:example:issuer", &keypair, SCOPES);
+    let sd_jwt: SdJwt = encode(&issuer, &keypair).disclose(&["degree"]);
+    let decoded: Credential = sd_jwt.verify(&issuer).unwrap();
+    let subject: &Subject = decoded.credential_subject.get(0).unwrap();
+
+    assert_eq!(subject.properties["name"], "Alice");
+    assert_eq!(subject.properties["degree"], json!({ "type": "BachelorDegree" }));
+    assert!(subject.properties.get("birthdate").is_none());
+    assert!(subject.properties.get(SD_CLAIM).is_none());
+  }
+
+  #[test]
+  fn test_unreferenced_disclosure() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let issuer: Document = document("did:example:issuer", &keypair, SCOPES);
+    let sd_jwt: SdJwt = encode(&issuer, &keypair);
+
+    let mut disclosures: Vec<Disclosure> = sd_jwt.disclosures().to_vec();
+    disclosures.push(Disclosure::new("birthdate", "2000-01-01".into()));
+
+    let forged: SdJwt = SdJwt::new(sd_jwt.jwt().to_string(), disclosures);
+
+    assert!(matches!(
+      forged.verify::<Object, _, _, _>(&issuer),
+      Err(Error::InvalidDisclosure)
+    ));
+  }
+
+  #[test]
+  fn test_key_binding() {
+    let issuer_key: KeyPair = KeyPair::new_ed25519().unwrap();
+    let issuer: Document = document("did:example:issuer", &issuer_key, SCOPES);
+    let holder_key: KeyPair = KeyPair::new_ed25519().unwrap();
+    let holder: Document = document("did:example:holder", &holder_key, SCOPES);
+
+    let presented: SdJwt = encode(&issuer, &issuer_key)
+      .disclose(&["birthdate"])
+      .bind(
+        &holder,
+        ("#key-1", MethodScope::Authentication),
+        holder_key.secret(),
+        "did:example:verifier",
+        "n-0S6_WzA2Mj",
+      )
+      .unwrap();
+
+    let parsed: SdJwt = presented.to_string().parse().unwrap();
+
+    assert!(parsed.key_binding().is_some());
+    assert!(parsed
+      .verify_key_binding(&holder, "did:example:verifier", "n-0S6_WzA2Mj")
+      .is_ok());
+    assert!(parsed
+      .verify_key_binding(&holder, "did:example:verifier", "other")
+      .is_err());
+    assert!(parsed
+      .disclose(&[])
+      .verify_key_binding(&holder, "did:example:verifier", "n-0S6_WzA2Mj")
+      .is_err());
+    assert!(parsed
+      .verify_key_binding(&issuer, "did:example:verifier", "n-0S6_WzA2Mj")
+      .is_err());
+    assert!(parsed.verify::<Object, _, _, _>(&issuer).is_ok());
+  }
+}
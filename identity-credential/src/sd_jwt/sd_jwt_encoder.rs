@@ -0,0 +1,185 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_core::crypto::SecretKey;
+use identity_core::utils::encode_b64_unpadded;
+use identity_did::document::Document;
+use identity_did::verification::MethodQuery;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::Serialize;
+
+use crate::credential::Credential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::integrity::DigestAlgorithm;
+use crate::jwt::jwt_encode;
+use crate::jwt::CredentialClaims;
+use crate::sd_jwt::Disclosure;
+use crate::sd_jwt::SdJwt;
+use crate::sd_jwt::SD_ALGORITHM;
+use crate::sd_jwt::SD_CLAIM;
+use crate::sd_jwt::SD_JWT_TYPE;
+
+/// Encodes a `Credential` as an SD-JWT with selectively disclosable
+/// `credentialSubject` properties.
+#[derive(Debug)]
+pub struct SdJwtEncoder<'a, T = Object> {
+  credential: &'a Credential<T>,
+  paths: Vec<Vec<String>>,
+  decoys: usize,
+  holder: Option<String>,
+}
+
+impl<'a, T> SdJwtEncoder<'a, T> {
+  /// Creates a new [`SdJwtEncoder`] for the given `credential`.
+  pub fn new(credential: &'a Credential<T>) -> Self {
+    Self {
+      credential,
+      paths: Vec::new(),
+      decoys: 0,
+      holder: None,
+    }
+  }
+
+  /// Marks the `credentialSubject` property at the JSON pointer `path` as
+  /// selectively disclosable, e.g. `/degree/name`.
+  ///
+  /// Nested properties may be concealed together with their parent.
+  pub fn conceal(mut self, path: &str) -> Result<Self> {
+    let segments: Vec<String> = match path.strip_prefix('/') {
+      Some(path) => path
+        .split('/')
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect(),
+      None => return Err(Error::InvalidDisclosure),
+    };
+
+    if segments
+      .iter()
+      .any(|segment| segment.is_empty() || segment == SD_CLAIM || segment == "...")
+    {
+      return Err(Error::InvalidDisclosure);
+    }
+
+    self.paths.push(segments);
+
+    Ok(self)
+  }
+
+  /// Adds `count` decoy digests to every `credentialSubject` to hide the
+  /// number of selectively disclosable properties.
+  #[must_use]
+  pub fn decoys(mut self, count: usize) -> Self {
+    self.decoys = count;
+    self
+  }
+
+  /// Requires the holder to prove possession of the verification method
+  /// `kid` when presenting the SD-JWT.
+  #[must_use]
+  pub fn holder_binding(mut self, kid: impl Into<String>) -> Self {
+    self.holder = Some(kid.into());
+    self
+  }
+
+  /// Signs the SD-JWT with the verification method of `document` matching
+  /// `query` and returns it with all disclosures.
+  pub fn sign<'b, Q, D1, D2, D3>(&self, document: &Document<D1, D2, D3>, query: Q, secret: &SecretKey) -> Result<SdJwt>
+  where
+    T: Serialize,
+    Q: Into<MethodQuery<'b>>,
+  {
+    let mut claims: CredentialClaims = CredentialClaims::new(self.credential)?;
+    let mut disclosures: Vec<Disclosure> = Vec::new();
+
+    // Conceal nested properties before their parents
+    let mut paths: Vec<&Vec<String>> = self.paths.iter().collect();
+    paths.sort_by(|a, b| b.len().cmp(&a.len()));
+
+    let subjects: &mut Value = claims.vc.get_mut("credentialSubject").ok_or(Error::MissingSubject)?;
+
+    for path in paths {
+      let mut concealed: bool = false;
+
+      for_each_subject(subjects, |subject| {
+        concealed |= conceal(subject, path, &mut disclosures);
+      });
+
+      if !concealed {
+        return Err(Error::InvalidDisclosure);
+      }
+
+      // The subject id must not leak through the registered `sub` claim
+      if path.len() == 1 && path[0] == "id" {
+        claims.sub = None;
+      }
+    }
+
+    for _ in 0..self.decoys {
+      for_each_subject(subjects, |subject| insert_digest(subject, decoy()));
+    }
+
+    claims.sd_alg = Some(SD_ALGORITHM.to_string());
+
+    claims.cnf = self.holder.as_ref().map(|kid| {
+      let mut cnf: Object = Object::new();
+      cnf.insert("kid".to_string(), Value::String(kid.clone()));
+      cnf
+    });
+
+    let jwt: String = jwt_encode(&claims, SD_JWT_TYPE, document, query, secret)?;
+
+    Ok(SdJwt::new(jwt, disclosures))
+  }
+}
+
+fn for_each_subject(subjects: &mut Value, mut f: impl FnMut(&mut Value)) {
+  match subjects {
+    Value::Array(subjects) => subjects.iter_mut().for_each(f),
+    subject => f(subject),
+  }
+}
+
+fn conceal(value: &mut Value, path: &[String], disclosures: &mut Vec<Disclosure>) -> bool {
+  match path {
+    [] => false,
+    [name] => match value.as_object_mut().and_then(|object| object.remove(name)) {
+      Some(claim) => {
+        let disclosure: Disclosure = Disclosure::new(name.as_str(), claim);
+
+        insert_digest(value, disclosure.digest());
+        disclosures.push(disclosure);
+
+        true
+      }
+      None => false,
+    },
+    [name, path @ ..] => match value.get_mut(name) {
+      Some(inner) => conceal(inner, path, disclosures),
+      None => false,
+    },
+  }
+}
+
+fn insert_digest(value: &mut Value, digest: String) {
+  let digests: Option<&mut Value> = value
+    .as_object_mut()
+    .map(|object| object.entry(SD_CLAIM).or_insert_with(|| Value::Array(Vec::new())));
+
+  if let Some(Value::Array(digests)) = digests {
+    // Sorting hides the original order of the concealed properties
+    digests.push(Value::String(digest));
+    digests.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+  }
+}
+
+fn decoy() -> String {
+  let mut data: [u8; 32] = [0; 32];
+
+  OsRng.fill_bytes(&mut data);
+
+  encode_b64_unpadded(&DigestAlgorithm::Sha256.digest(&data))
+}
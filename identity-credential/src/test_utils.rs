@@ -0,0 +1,75 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::crypto::KeyPair;
+use identity_did::did::DID;
+use identity_did::document::Document;
+use identity_did::document::DocumentBuilder;
+use identity_did::verification::Method;
+use identity_did::verification::MethodBuilder;
+use identity_did::verification::MethodData;
+use identity_did::verification::MethodScope;
+use identity_did::verification::MethodType;
+use serde_json::json;
+
+use crate::credential::Credential;
+use crate::credential::CredentialBuilder;
+use crate::credential::Subject;
+
+/// Creates a `Document` for `did` with an Ed25519 method `#key-1` of
+/// `keypair` in each of the given `scopes`.
+pub(crate) fn document(did: &str, keypair: &KeyPair, scopes: &[MethodScope]) -> Document {
+  let did: DID = did.parse().unwrap();
+
+  let method: Method = MethodBuilder::default()
+    .id(did.join("#key-1").unwrap())
+    .controller(did.clone())
+    .key_type(MethodType::Ed25519VerificationKey2018)
+    .key_data(MethodData::new_b58(keypair.public()))
+    .build()
+    .unwrap();
+
+  let mut builder: DocumentBuilder = DocumentBuilder::new(Object::new()).id(did);
+
+  for scope in scopes {
+    builder = match scope {
+      MethodScope::VerificationMethod => builder.verification_method(method.clone()),
+      MethodScope::Authentication => builder.authentication(method.clone()),
+      MethodScope::AssertionMethod => builder.assertion_method(method.clone()),
+      MethodScope::KeyAgreement => builder.key_agreement(method.clone()),
+      MethodScope::CapabilityDelegation => builder.capability_delegation(method.clone()),
+      MethodScope::CapabilityInvocation => builder.capability_invocation(method.clone()),
+    };
+  }
+
+  builder.build().unwrap()
+}
+
+/// Creates the `Document` of `did:example:issuer` with an assertion method
+/// `#key-1` of `keypair`.
+pub(crate) fn issuer(keypair: &KeyPair) -> Document {
+  document("did:example:issuer", keypair, &[MethodScope::AssertionMethod])
+}
+
+/// Creates a `Credential` issued by `did:example:issuer` with a subject of
+/// several claims.
+pub(crate) fn credential() -> Credential {
+  let subject: Subject = Subject::from_json_value(json!({
+    "id": "did:example:holder",
+    "name": "Alice",
+    "birthdate": "1990-01-01",
+    "degree": { "type": "BachelorDegree", "name": "Bachelor of Science" }
+  }))
+  .unwrap();
+
+  CredentialBuilder::default()
+    .subject(subject)
+    .issuer(Url::parse("did:example:issuer").unwrap())
+    .issuance_date(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+    .build()
+    .unwrap()
+}
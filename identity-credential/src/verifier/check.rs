@@ -0,0 +1,62 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+
+/// An individual check performed when verifying a `Credential`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Check {
+  /// The semantic structure of the `Credential` is valid.
+  Structure,
+  /// The `proof` was created by a verification method of the issuer.
+  Signature,
+  /// The `proofPurpose` matches the expected verification relationship.
+  ProofPurpose,
+  /// The `issuanceDate` is not in the future.
+  IssuanceDate,
+  /// The `expirationDate` has not passed.
+  ExpirationDate,
+  /// No `credentialStatus` reports the `Credential` as revoked.
+  Status,
+  /// Every `credentialSchema` is integrity protected and the `Credential`
+  /// conforms to it.
+  Schema,
+  /// The `issuer` is a member of the trusted set.
+  TrustedIssuer,
+}
+
+impl Check {
+  /// All checks in the order they are performed.
+  pub const ALL: &'static [Self] = &[
+    Self::Structure,
+    Self::Signature,
+    Self::ProofPurpose,
+    Self::IssuanceDate,
+    Self::ExpirationDate,
+    Self::Status,
+    Self::Schema,
+    Self::TrustedIssuer,
+  ];
+
+  /// Returns the name of the check.
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Structure => "Structure",
+      Self::Signature => "Signature",
+      Self::ProofPurpose => "ProofPurpose",
+      Self::IssuanceDate => "IssuanceDate",
+      Self::ExpirationDate => "ExpirationDate",
+      Self::Status => "Status",
+      Self::Schema => "Schema",
+      Self::TrustedIssuer => "TrustedIssuer",
+    }
+  }
+}
+
+impl Display for Check {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
@@ -0,0 +1,40 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+
+/// A source of the current time used when checking `Credential` validity
+/// periods.
+pub trait Clock {
+  /// Returns the current time.
+  fn now(&self) -> Timestamp;
+}
+
+impl<T> Clock for &'_ T
+where
+  T: Clock + ?Sized,
+{
+  fn now(&self) -> Timestamp {
+    (**self).now()
+  }
+}
+
+/// A [`Clock`] that returns the current system time.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+  fn now(&self) -> Timestamp {
+    Timestamp::now()
+  }
+}
+
+/// A [`Clock`] that always returns the same time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixedClock(pub Timestamp);
+
+impl Clock for FixedClock {
+  fn now(&self) -> Timestamp {
+    self.0
+  }
+}
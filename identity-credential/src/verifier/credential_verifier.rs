@@ -0,0 +1,541 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryInto;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::convert::SerdeInto;
+use identity_core::crypto::EddsaJcs2022;
+use identity_core::crypto::JcsEd25519Signature2020;
+use identity_core::crypto::Signature;
+use identity_core::crypto::SignatureOptions;
+use identity_core::crypto::TrySignature;
+use identity_did::did::DID;
+use identity_did::verifiable::LdSuite;
+use identity_did::verifiable::ResolveMethod;
+use identity_did::verification::MethodScope;
+use identity_did::verification::MethodWrap;
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+use crate::credential::Credential;
+use crate::credential::VerifiableCredential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::integrity::ResourceLoader;
+use crate::jwt::jwt_decode;
+use crate::jwt::jwt_decode_unverified;
+use crate::jwt::CredentialClaims;
+use crate::jwt::JwtHeader;
+use crate::schema::SchemaRegistry;
+use crate::verifier::Check;
+use crate::verifier::Clock;
+use crate::verifier::Outcome;
+use crate::verifier::StatusResolver;
+use crate::verifier::SystemClock;
+use crate::verifier::VerificationReport;
+
+/// A configurable verifier of `VerifiableCredential`s.
+///
+/// All checks are enabled by default; checks that depend on external
+/// information (`Status`, `Schema`, and `TrustedIssuer`) are skipped unless
+/// the verifier is configured with a `StatusResolver`, a `ResourceLoader`
+/// and/or `SchemaRegistry`, or trusted issuers.
+#[derive(Clone)]
+pub struct CredentialVerifier<'a, C = SystemClock> {
+  clock: C,
+  disabled: BTreeSet<Check>,
+  trusted: BTreeSet<Url>,
+  proof_purpose: MethodScope,
+  historical: bool,
+  status: Option<&'a dyn StatusResolver>,
+  loader: Option<&'a dyn ResourceLoader>,
+  registry: Option<&'a dyn SchemaRegistry>,
+}
+
+impl<'a> CredentialVerifier<'a> {
+  /// Creates a new `CredentialVerifier` using the system clock.
+  pub fn new() -> Self {
+    Self::with_clock(SystemClock)
+  }
+}
+
+impl<'a, C> CredentialVerifier<'a, C>
+where
+  C: Clock,
+{
+  /// Creates a new `CredentialVerifier` using the given `clock`.
+  pub fn with_clock(clock: C) -> Self {
+    Self {
+      clock,
+      disabled: BTreeSet::new(),
+      trusted: BTreeSet::new(),
+      proof_purpose: MethodScope::AssertionMethod,
+      historical: false,
+      status: None,
+      loader: None,
+      registry: None,
+    }
+  }
+
+  /// Enables or disables the given `check`.
+  #[must_use]
+  pub fn check(mut self, check: Check, enabled: bool) -> Self {
+    if enabled {
+      self.disabled.remove(&check);
+    } else {
+      self.disabled.insert(check);
+    }
+    self
+  }
+
+  /// Adds an issuer to the trusted set.
+  #[must_use]
+  pub fn trusted_issuer(mut self, value: Url) -> Self {
+    self.trusted.insert(value);
+    self
+  }
+
+  /// Sets the verification relationship expected as `proofPurpose`.
+  ///
+  /// Note: Defaults to `MethodScope::AssertionMethod`.
+  #[must_use]
+  pub fn proof_purpose(mut self, value: MethodScope) -> Self {
+    self.proof_purpose = value;
+    self
+  }
+
+  /// Accepts proofs created by deactivated DIDs, i.e. when checking a
+  /// credential that was issued before deactivation.
+  #[must_use]
+  pub fn historical(mut self, value: bool) -> Self {
+    self.historical = value;
+    self
+  }
+
+  /// Sets the resolver used to check the `credentialStatus`.
+  #[must_use]
+  pub fn status_resolver(mut self, value: &'a dyn StatusResolver) -> Self {
+    self.status = Some(value);
+    self
+  }
+
+  /// Sets the loader used to fetch the `credentialSchema` resources.
+  #[must_use]
+  pub fn resource_loader(mut self, value: &'a dyn ResourceLoader) -> Self {
+    self.loader = Some(value);
+    self
+  }
+
+  /// Sets the registry used to validate the `Credential` against its JSON
+  /// Schemas.
+  #[must_use]
+  pub fn schema_registry(mut self, value: &'a dyn SchemaRegistry) -> Self {
+    self.registry = Some(value);
+    self
+  }
+
+  /// Verifies `credential` with the resolved DID document of the `issuer`
+  /// and returns the outcome of every check.
+  ///
+  /// Note: Proofs are only rejected for deactivated DIDs if `issuer` reports
+  /// deactivation, e.g. a `MetaDocument` or an `IotaDocument`.
+  pub fn verify<T, R, M>(&self, credential: &VerifiableCredential<T>, issuer: &R) -> VerificationReport
+  where
+    T: Serialize,
+    R: ResolveMethod<M>,
+    M: Serialize,
+  {
+    let now: Timestamp = self.clock.now();
+    let mut report: VerificationReport = VerificationReport::new();
+
+    for check in Check::ALL {
+      let outcome: Outcome = if self.disabled.contains(check) {
+        Outcome::Skipped
+      } else {
+        self.perform(*check, credential, issuer, now)
+      };
+
+      report.push(*check, outcome);
+    }
+
+    report
+  }
+
+  fn perform<T, R, M>(&self, check: Check, credential: &VerifiableCredential<T>, issuer: &R, now: Timestamp) -> Outcome
+  where
+    T: Serialize,
+    R: ResolveMethod<M>,
+    M: Serialize,
+  {
+    match check {
+      Check::Structure => credential.check_structure().into(),
+      Check::Signature => self.check_signature(credential, issuer).into(),
+      Check::ProofPurpose => self.check_proof_purpose(credential).into(),
+      Check::IssuanceDate => match credential.validity_start() {
+        Some(start) if start > now => Outcome::Failed(Error::InvalidIssuanceDate.to_string()),
+        Some(_) => Outcome::Passed,
+        None => Outcome::Skipped,
+      },
+      Check::ExpirationDate => match credential.validity_end() {
+        Some(expiration) if expiration <= now => Outcome::Failed(Error::ExpiredCredential.to_string()),
+        Some(_) => Outcome::Passed,
+        None => Outcome::Skipped,
+      },
+      Check::Status => match self.status {
+        Some(resolver) if !credential.credential_status.is_empty() => check_status(credential, resolver).into(),
+        Some(_) | None => Outcome::Skipped,
+      },
+      Check::Schema if credential.credential_schema.is_empty() => Outcome::Skipped,
+      Check::Schema if self.loader.is_none() && self.registry.is_none() => Outcome::Skipped,
+      Check::Schema => self.check_schema(credential).into(),
+      Check::TrustedIssuer if self.trusted.is_empty() => Outcome::Skipped,
+      Check::TrustedIssuer if self.trusted.contains(credential.issuer.url()) => Outcome::Passed,
+      Check::TrustedIssuer => Outcome::Failed(Error::UntrustedIssuer.to_string()),
+    }
+  }
+
+  fn check_signature<T, R, M>(&self, credential: &VerifiableCredential<T>, issuer: &R) -> Result<()>
+  where
+    T: Serialize,
+    R: ResolveMethod<M>,
+    M: Serialize,
+  {
+    // Credentials decoded from a VC-JWT or `vc+jwt` token are verified
+    // against the original token
+    if let Some(token) = credential.jwt() {
+      if !self.historical && issuer.is_deactivated() {
+        return Err(Error::DIDError(identity_did::Error::DeactivatedDocument));
+      }
+
+      let (header, _): (JwtHeader, Vec<u8>) = jwt_decode_unverified(token)?;
+
+      let (options, decoded): (SignatureOptions, Credential<Object>) = match header.typ.as_deref() {
+        Some(JwtHeader::TYPE_VC) => {
+          let (options, claims): (SignatureOptions, Vec<u8>) =
+            jwt_decode(token, JwtHeader::TYPE_VC, MethodScope::AssertionMethod, issuer)?;
+
+          (options, Credential::from_json_slice(&claims)?)
+        }
+        _ => {
+          let (options, claims): (SignatureOptions, Vec<u8>) =
+            jwt_decode(token, JwtHeader::TYPE, MethodScope::AssertionMethod, issuer)?;
+
+          (options, CredentialClaims::from_json_slice(&claims)?.into_credential()?)
+        }
+      };
+      let expected: Credential<Object> = (**credential).serde_into()?;
+
+      if decoded != expected {
+        return Err(Error::InvalidJwtClaims);
+      }
+
+      return check_issuer(credential, &options.verification_method);
+    }
+
+    let signature: &Signature = credential.try_signature()?;
+
+    // Data Integrity proofs identify the suite by their cryptosuite. Merkle
+    // Key Collection signatures need the public key of the signer and are not
+    // supported.
+    match (signature.type_(), signature.cryptosuite()) {
+      (JcsEd25519Signature2020::NAME, None) => LdSuite::new(JcsEd25519Signature2020)
+        .historical(self.historical)
+        .verify_data(credential, issuer)?,
+      (EddsaJcs2022::NAME, Some(EddsaJcs2022::CRYPTOSUITE)) => LdSuite::new(EddsaJcs2022)
+        .historical(self.historical)
+        .verify_data(credential, issuer)?,
+      (type_, None) => return Err(Error::UnsupportedSignatureType(type_.to_string())),
+      (type_, Some(cryptosuite)) => {
+        return Err(Error::UnsupportedSignatureType(format!("{} ({})", type_, cryptosuite)));
+      }
+    }
+
+    let method: MethodWrap<'_, M> = issuer.try_resolve_method(signature.try_into()?)?;
+
+    check_issuer(credential, method.id().as_str())
+  }
+
+  fn check_schema<T>(&self, credential: &VerifiableCredential<T>) -> Result<()>
+  where
+    T: Serialize,
+  {
+    if let Some(loader) = self.loader {
+      credential.verify_integrity(loader)?;
+    }
+
+    if let Some(registry) = self.registry {
+      credential.validate_schema(registry)?;
+    }
+
+    Ok(())
+  }
+
+  fn check_proof_purpose<T>(&self, credential: &VerifiableCredential<T>) -> Result<()> {
+    let signature: &Signature = credential.proof().get(0).ok_or(Error::InvalidProofPurpose)?;
+
+    match signature.proof_purpose.as_deref().map(str::parse::<MethodScope>) {
+      Some(Ok(scope)) if scope == self.proof_purpose => Ok(()),
+      Some(_) | None => Err(Error::InvalidProofPurpose),
+    }
+  }
+}
+
+impl Default for CredentialVerifier<'_> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// Ensures the verification method `method` of the proof belongs to the
+// credential issuer.
+fn check_issuer<T>(credential: &Credential<T>, method: &str) -> Result<()> {
+  let issuer: DID = credential
+    .issuer
+    .url()
+    .as_str()
+    .parse()
+    .map_err(|_| Error::IssuerMismatch)?;
+  let method: DID = method.parse().map_err(|_| Error::IssuerMismatch)?;
+
+  if issuer.method() != method.method() || issuer.method_id() != method.method_id() {
+    return Err(Error::IssuerMismatch);
+  }
+
+  Ok(())
+}
+
+fn check_status<T>(credential: &VerifiableCredential<T>, resolver: &dyn StatusResolver) -> Result<()> {
+  for status in credential.credential_status.iter() {
+    if resolver.is_revoked(status)? {
+      return Err(Error::RevokedCredential);
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::crypto::merkle_key::MerkleKey;
+  use identity_core::crypto::KeyPair;
+  use identity_did::document::Document;
+  use identity_did::resolution::DocumentMetadata;
+  use identity_did::resolution::MetaDocument;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::CredentialVersion;
+  use crate::credential::Status;
+  use crate::credential::Subject;
+  use crate::test_utils::issuer;
+  use crate::verifier::FixedClock;
+
+  struct Revoked;
+
+  impl StatusResolver for Revoked {
+    fn is_revoked(&self, _: &Status) -> Result<bool> {
+      Ok(true)
+    }
+  }
+
+  fn credential(document: &Document, keypair: &KeyPair) -> VerifiableCredential {
+    let credential: Credential = CredentialBuilder::default()
+      .subject(Subject::from_json_value(json!({ "id": "did:example:subject" })).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .issuance_date(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+      .expiration_date(Timestamp::parse("2021-01-01T00:00:00Z").unwrap())
+      .status(Status::new(
+        Url::parse("https://example.edu/status/24").unwrap(),
+        "CredentialStatusList2017".to_string(),
+      ))
+      .build()
+      .unwrap();
+
+    credential
+      .sign(document, ("#key-1", MethodScope::AssertionMethod), keypair.secret())
+      .unwrap()
+  }
+
+  fn clock(value: &str) -> FixedClock {
+    FixedClock(Timestamp::parse(value).unwrap())
+  }
+
+  #[test]
+  fn test_verify_valid() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+    let credential: VerifiableCredential = credential(&document, &keypair);
+
+    let report: VerificationReport = CredentialVerifier::with_clock(clock("2020-06-01T00:00:00Z"))
+      .trusted_issuer(Url::parse("did:example:issuer").unwrap())
+      .verify(&credential, &document);
+
+    assert!(report.is_verified());
+    assert_eq!(report.outcome(Check::Signature), Some(&Outcome::Passed));
+    assert_eq!(report.outcome(Check::ProofPurpose), Some(&Outcome::Passed));
+    assert_eq!(report.outcome(Check::TrustedIssuer), Some(&Outcome::Passed));
+    assert_eq!(report.outcome(Check::Status), Some(&Outcome::Skipped));
+  }
+
+  #[test]
+  fn test_verify_failures() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+    let credential: VerifiableCredential = credential(&document, &keypair);
+
+    let report: VerificationReport = CredentialVerifier::with_clock(clock("2021-06-01T00:00:00Z"))
+      .trusted_issuer(Url::parse("did:example:other").unwrap())
+      .proof_purpose(MethodScope::Authentication)
+      .status_resolver(&Revoked)
+      .verify(&credential, &document);
+
+    let failures: Vec<Check> = report.failures().map(|(check, _)| check).collect();
+
+    assert_eq!(
+      failures,
+      [
+        Check::ProofPurpose,
+        Check::ExpirationDate,
+        Check::Status,
+        Check::TrustedIssuer
+      ]
+    );
+
+    let report: VerificationReport = CredentialVerifier::with_clock(clock("2019-06-01T00:00:00Z"))
+      .check(Check::Signature, false)
+      .verify(&credential, &issuer(&KeyPair::new_ed25519().unwrap()));
+
+    assert_eq!(report.outcome(Check::Signature), Some(&Outcome::Skipped));
+    assert!(report.outcome(Check::IssuanceDate).unwrap().is_failed());
+
+    let report: VerificationReport = CredentialVerifier::with_clock(clock("2020-06-01T00:00:00Z"))
+      .verify(&credential, &issuer(&KeyPair::new_ed25519().unwrap()));
+
+    assert!(report.outcome(Check::Signature).unwrap().is_failed());
+    assert!(!report.is_verified());
+  }
+
+  #[test]
+  fn test_verify_unsupported_suite() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+    let mut credential: VerifiableCredential = credential(&document, &keypair);
+
+    let options: SignatureOptions = SignatureOptions::new("did:example:issuer#key-1");
+    *credential.proof_mut() = Signature::new(MerkleKey::SIGNATURE_NAME, options).into();
+
+    let report: VerificationReport =
+      CredentialVerifier::with_clock(clock("2020-06-01T00:00:00Z")).verify(&credential, &document);
+
+    assert_eq!(
+      report.outcome(Check::Signature),
+      Some(&Outcome::Failed(
+        Error::UnsupportedSignatureType(MerkleKey::SIGNATURE_NAME.to_string()).to_string()
+      ))
+    );
+  }
+
+  #[test]
+  fn test_verify_data_integrity() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+
+    let credential: VerifiableCredential = CredentialBuilder::default()
+      .version(CredentialVersion::V2_0)
+      .subject(Subject::from_json_value(json!({ "id": "did:example:subject" })).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .valid_from(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+      .build()
+      .unwrap()
+      .sign_data_integrity(&document, ("#key-1", MethodScope::AssertionMethod), keypair.secret())
+      .unwrap();
+
+    let proof: &Signature = credential.proof().get(0).unwrap();
+
+    assert_eq!(proof.type_(), EddsaJcs2022::NAME);
+    assert_eq!(proof.cryptosuite(), Some(EddsaJcs2022::CRYPTOSUITE));
+    assert!(proof.data().try_proof().unwrap().starts_with('z'));
+
+    let report: VerificationReport =
+      CredentialVerifier::with_clock(clock("2020-06-01T00:00:00Z")).verify(&credential, &document);
+
+    assert!(report.is_verified());
+    assert_eq!(report.outcome(Check::Signature), Some(&Outcome::Passed));
+
+    let mut tampered: VerifiableCredential = credential;
+    tampered.valid_from = Some(Timestamp::parse("2019-01-01T00:00:00Z").unwrap());
+
+    let report: VerificationReport =
+      CredentialVerifier::with_clock(clock("2020-06-01T00:00:00Z")).verify(&tampered, &document);
+
+    assert!(report.outcome(Check::Signature).unwrap().is_failed());
+  }
+
+  #[test]
+  fn test_verify_jose() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+
+    let token: String = CredentialBuilder::default()
+      .version(CredentialVersion::V2_0)
+      .subject(Subject::from_json_value(json!({ "id": "did:example:subject" })).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .valid_from(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+      .build()
+      .unwrap()
+      .sign_jose(&document, ("#key-1", MethodScope::AssertionMethod), keypair.secret())
+      .unwrap();
+    let credential: VerifiableCredential = VerifiableCredential::from_jose(&token, &document).unwrap();
+
+    let report: VerificationReport =
+      CredentialVerifier::with_clock(clock("2020-06-01T00:00:00Z")).verify(&credential, &document);
+
+    assert!(report.is_verified());
+    assert_eq!(report.outcome(Check::Signature), Some(&Outcome::Passed));
+
+    let mut tampered: VerifiableCredential = credential;
+    tampered.valid_from = Some(Timestamp::parse("2019-01-01T00:00:00Z").unwrap());
+
+    let report: VerificationReport =
+      CredentialVerifier::with_clock(clock("2020-06-01T00:00:00Z")).verify(&tampered, &document);
+
+    assert!(report.outcome(Check::Signature).unwrap().is_failed());
+  }
+
+  #[test]
+  fn test_verify_deactivated_issuer() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = issuer(&keypair);
+    let credential: VerifiableCredential = credential(&document, &keypair);
+
+    let token: String = credential
+      .sign_jwt(&document, ("#key-1", MethodScope::AssertionMethod), keypair.secret())
+      .unwrap();
+    let decoded: VerifiableCredential = VerifiableCredential::from_jwt(&token, &document).unwrap();
+
+    let deactivated: MetaDocument = MetaDocument {
+      data: document,
+      meta: DocumentMetadata {
+        deactivated: Some(true),
+        ..DocumentMetadata::default()
+      },
+    };
+
+    for credential in [&credential, &decoded].iter() {
+      let report: VerificationReport =
+        CredentialVerifier::with_clock(clock("2020-06-01T00:00:00Z")).verify(*credential, &deactivated);
+
+      assert!(report.outcome(Check::Signature).unwrap().is_failed());
+
+      let report: VerificationReport = CredentialVerifier::with_clock(clock("2020-06-01T00:00:00Z"))
+        .historical(true)
+        .verify(*credential, &deactivated);
+
+      assert_eq!(report.outcome(Check::Signature), Some(&Outcome::Passed));
+    }
+  }
+}
@@ -0,0 +1,20 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Configurable verification of Verifiable Credentials.
+
+mod check;
+mod clock;
+mod credential_verifier;
+mod outcome;
+mod report;
+mod status_resolver;
+
+pub use self::check::Check;
+pub use self::clock::Clock;
+pub use self::clock::FixedClock;
+pub use self::clock::SystemClock;
+pub use self::credential_verifier::CredentialVerifier;
+pub use self::outcome::Outcome;
+pub use self::report::VerificationReport;
+pub use self::status_resolver::StatusResolver;
@@ -0,0 +1,41 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::error::Result;
+
+/// The outcome of an individual verification [`Check`][crate::verifier::Check].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+  /// The check passed.
+  Passed,
+  /// The check failed with the given reason.
+  Failed(String),
+  /// The check was disabled or is not applicable to the `Credential`.
+  Skipped,
+}
+
+impl Outcome {
+  /// Returns `true` if the check passed.
+  pub fn is_passed(&self) -> bool {
+    matches!(self, Self::Passed)
+  }
+
+  /// Returns `true` if the check failed.
+  pub fn is_failed(&self) -> bool {
+    matches!(self, Self::Failed(_))
+  }
+
+  /// Returns `true` if the check was skipped.
+  pub fn is_skipped(&self) -> bool {
+    matches!(self, Self::Skipped)
+  }
+}
+
+impl From<Result<()>> for Outcome {
+  fn from(other: Result<()>) -> Self {
+    match other {
+      Ok(()) => Self::Passed,
+      Err(error) => Self::Failed(error.to_string()),
+    }
+  }
+}
@@ -0,0 +1,49 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::verifier::Check;
+use crate::verifier::Outcome;
+
+/// The outcome of every check performed when verifying a `Credential`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VerificationReport {
+  outcomes: Vec<(Check, Outcome)>,
+}
+
+impl VerificationReport {
+  /// Creates a new, empty `VerificationReport`.
+  pub fn new() -> Self {
+    Self { outcomes: Vec::new() }
+  }
+
+  /// Returns `true` if no check failed.
+  pub fn is_verified(&self) -> bool {
+    !self.outcomes.iter().any(|(_, outcome)| outcome.is_failed())
+  }
+
+  /// Returns the outcome of the given `check`, if it was performed.
+  pub fn outcome(&self, check: Check) -> Option<&Outcome> {
+    self
+      .outcomes
+      .iter()
+      .find(|(this, _)| *this == check)
+      .map(|(_, outcome)| outcome)
+  }
+
+  /// Returns an iterator over the outcomes of all checks.
+  pub fn iter(&self) -> impl Iterator<Item = &(Check, Outcome)> + '_ {
+    self.outcomes.iter()
+  }
+
+  /// Returns an iterator over the failed checks and their reasons.
+  pub fn failures(&self) -> impl Iterator<Item = (Check, &str)> + '_ {
+    self.outcomes.iter().filter_map(|(check, outcome)| match outcome {
+      Outcome::Failed(reason) => Some((*check, reason.as_str())),
+      Outcome::Passed | Outcome::Skipped => None,
+    })
+  }
+
+  pub(crate) fn push(&mut self, check: Check, outcome: Outcome) {
+    self.outcomes.push((check, outcome));
+  }
+}
@@ -0,0 +1,21 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::credential::Status;
+use crate::error::Result;
+
+/// A source of `credentialStatus` information, e.g. a revocation list.
+pub trait StatusResolver {
+  /// Returns `true` if the given `status` reports the `Credential` as revoked
+  /// or suspended.
+  fn is_revoked(&self, status: &Status) -> Result<bool>;
+}
+
+impl<T> StatusResolver for &'_ T
+where
+  T: StatusResolver + ?Sized,
+{
+  fn is_revoked(&self, status: &Status) -> Result<bool> {
+    (**self).is_revoked(status)
+  }
+}
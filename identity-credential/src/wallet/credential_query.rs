@@ -0,0 +1,52 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+
+/// A query for credentials held in a `Wallet`.
+///
+/// All configured criteria must match; an empty query matches every
+/// credential.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CredentialQuery {
+  pub(crate) types: Vec<String>,
+  pub(crate) issuer: Option<String>,
+  pub(crate) subject: Option<String>,
+  pub(crate) valid_at: Option<Timestamp>,
+}
+
+impl CredentialQuery {
+  /// Creates a new `CredentialQuery` matching every credential.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Matches credentials with the given type.
+  #[must_use]
+  pub fn type_(mut self, value: impl Into<String>) -> Self {
+    self.types.push(value.into());
+    self
+  }
+
+  /// Matches credentials issued by `value`.
+  #[must_use]
+  pub fn issuer(mut self, value: &Url) -> Self {
+    self.issuer = Some(value.to_string());
+    self
+  }
+
+  /// Matches credentials with a subject identified by `value`.
+  #[must_use]
+  pub fn subject(mut self, value: &Url) -> Self {
+    self.subject = Some(value.to_string());
+    self
+  }
+
+  /// Matches credentials that are valid at the given time.
+  #[must_use]
+  pub fn valid_at(mut self, value: Timestamp) -> Self {
+    self.valid_at = Some(value);
+    self
+  }
+}
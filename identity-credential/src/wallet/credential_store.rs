@@ -0,0 +1,23 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::credential::VerifiableCredential;
+use crate::error::Result;
+
+/// A storage backend for the credentials of a `Wallet`.
+///
+/// Keys are assigned by the `Wallet` and consist of base58 characters only.
+pub trait CredentialStore {
+  /// Returns the keys of all stored credentials.
+  fn keys(&self) -> Result<Vec<String>>;
+
+  /// Returns the credential stored under `key`, if any.
+  fn get(&self, key: &str) -> Result<Option<VerifiableCredential>>;
+
+  /// Stores `credential` under `key`, replacing any existing credential.
+  fn insert(&mut self, key: &str, credential: &VerifiableCredential) -> Result<()>;
+
+  /// Removes the credential stored under `key` and returns `true` if it
+  /// existed.
+  fn remove(&mut self, key: &str) -> Result<bool>;
+}
@@ -0,0 +1,186 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::credential::VerifiableCredential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::wallet::CredentialStore;
+
+// The file extension of stored credentials.
+const EXTENSION: &str = "json";
+
+/// A [`CredentialStore`] that keeps each credential as a JSON file in a
+/// directory.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileStore {
+  path: PathBuf,
+}
+
+impl FileStore {
+  /// Creates a new `FileStore` in the directory at `path`, creating the
+  /// directory if it does not exist.
+  pub fn new(path: impl Into<PathBuf>) -> Result<Self> {
+    let path: PathBuf = path.into();
+
+    fs::create_dir_all(&path)?;
+
+    Ok(Self { path })
+  }
+
+  /// Returns the directory of the `FileStore`.
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  fn file(&self, key: &str) -> Result<PathBuf> {
+    if !Self::is_valid_key(key) {
+      return Err(Error::InvalidStorageKey);
+    }
+
+    Ok(self.path.join(key).with_extension(EXTENSION))
+  }
+
+  // Keys are used as file names and must not escape the directory
+  fn is_valid_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|char| char.is_ascii_alphanumeric())
+  }
+}
+
+impl CredentialStore for FileStore {
+  fn keys(&self) -> Result<Vec<String>> {
+    let mut keys: Vec<String> = Vec::new();
+
+    for entry in fs::read_dir(&self.path)? {
+      let path: PathBuf = entry?.path();
+
+      if path.extension().and_then(|extension| extension.to_str()) != Some(EXTENSION) {
+        continue;
+      }
+
+      // Skip files that were not written by the store
+      match path.file_stem().and_then(|stem| stem.to_str()) {
+        Some(key) if Self::is_valid_key(key) => keys.push(key.to_string()),
+        _ => {}
+      }
+    }
+
+    keys.sort();
+
+    Ok(keys)
+  }
+
+  fn get(&self, key: &str) -> Result<Option<VerifiableCredential>> {
+    match fs::read(self.file(key)?) {
+      Ok(data) => VerifiableCredential::from_json_slice(&data)
+        .map(Some)
+        .map_err(Into::into),
+      Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+      Err(error) => Err(error.into()),
+    }
+  }
+
+  fn insert(&mut self, key: &str, credential: &VerifiableCredential) -> Result<()> {
+    let file: PathBuf = self.file(key)?;
+    let temp: PathBuf = file.with_extension("tmp");
+
+    // Write to a temporary file first so a crash cannot leave a partial file
+    fs::write(&temp, credential.to_json_vec()?)?;
+    fs::rename(&temp, &file)?;
+
+    Ok(())
+  }
+
+  fn remove(&mut self, key: &str) -> Result<bool> {
+    match fs::remove_file(self.file(key)?) {
+      Ok(()) => Ok(true),
+      Err(error) if error.kind() == ErrorKind::NotFound => Ok(false),
+      Err(error) => Err(error.into()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+  use tempfile::TempDir;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::wallet::Wallet;
+
+  #[test]
+  fn test_file_store() {
+    let dir: TempDir = TempDir::new().unwrap();
+    let path: PathBuf = dir.path().join("wallet");
+
+    let credential: Credential = CredentialBuilder::default()
+      .subject(Subject::from_json_value(json!({ "id": "did:example:holder" })).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .build()
+      .unwrap();
+
+    let credential: VerifiableCredential = VerifiableCredential::new(credential, Vec::new());
+
+    let key: String = {
+      let mut wallet: Wallet<FileStore> = Wallet::new(FileStore::new(&path).unwrap()).unwrap();
+      wallet.insert(credential.clone()).unwrap()
+    };
+
+    // A new wallet re-indexes the persisted credentials
+    let mut wallet: Wallet<FileStore> = Wallet::new(FileStore::new(&path).unwrap()).unwrap();
+
+    assert_eq!(wallet.len(), 1);
+    assert_eq!(wallet.get(&key).unwrap(), Some(credential.clone()));
+    assert_eq!(wallet.remove(&key).unwrap(), Some(credential));
+    assert!(wallet.store().keys().unwrap().is_empty());
+
+    // Files with invalid keys are not listed
+    fs::write(path.join("not-a-key.json"), b"{}").unwrap();
+    fs::write(path.join("other.txt"), b"{}").unwrap();
+
+    let mut store: FileStore = FileStore::new(&path).unwrap();
+
+    assert!(store.keys().unwrap().is_empty());
+    assert!(matches!(store.remove("../escape"), Err(Error::InvalidStorageKey)));
+    assert!(!store.remove("missing").unwrap());
+  }
+
+  #[test]
+  fn test_corrupt_file() {
+    let dir: TempDir = TempDir::new().unwrap();
+
+    let credential: Credential = CredentialBuilder::default()
+      .subject(Subject::from_json_value(json!({ "id": "did:example:holder" })).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .build()
+      .unwrap();
+
+    let key: String = {
+      let mut wallet: Wallet<FileStore> = Wallet::new(FileStore::new(dir.path()).unwrap()).unwrap();
+      wallet
+        .insert(VerifiableCredential::new(credential, Vec::new()))
+        .unwrap()
+    };
+
+    fs::write(dir.path().join("corrupt.json"), b"{ not json").unwrap();
+
+    // Unreadable entries are skipped instead of failing the whole wallet
+    let wallet: Wallet<FileStore> = Wallet::new(FileStore::new(dir.path()).unwrap()).unwrap();
+
+    assert_eq!(wallet.len(), 1);
+    assert_eq!(wallet.keys().collect::<Vec<_>>(), [key.as_str()]);
+    assert!(wallet.get("corrupt").unwrap().is_none());
+    assert!(wallet.store().get("corrupt").is_err());
+  }
+}
@@ -0,0 +1,42 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::credential::VerifiableCredential;
+use crate::error::Result;
+use crate::wallet::CredentialStore;
+
+/// A [`CredentialStore`] that keeps credentials in memory.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryStore {
+  credentials: BTreeMap<String, VerifiableCredential>,
+}
+
+impl MemoryStore {
+  /// Creates a new, empty `MemoryStore`.
+  pub fn new() -> Self {
+    Self {
+      credentials: BTreeMap::new(),
+    }
+  }
+}
+
+impl CredentialStore for MemoryStore {
+  fn keys(&self) -> Result<Vec<String>> {
+    Ok(self.credentials.keys().cloned().collect())
+  }
+
+  fn get(&self, key: &str) -> Result<Option<VerifiableCredential>> {
+    Ok(self.credentials.get(key).cloned())
+  }
+
+  fn insert(&mut self, key: &str, credential: &VerifiableCredential) -> Result<()> {
+    self.credentials.insert(key.to_string(), credential.clone());
+    Ok(())
+  }
+
+  fn remove(&mut self, key: &str) -> Result<bool> {
+    Ok(self.credentials.remove(key).is_some())
+  }
+}
@@ -0,0 +1,18 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A holder-side wallet for storing, querying, and presenting credentials.
+
+#![allow(clippy::module_inception)]
+
+mod credential_query;
+mod credential_store;
+mod file_store;
+mod memory_store;
+mod wallet;
+
+pub use self::credential_query::CredentialQuery;
+pub use self::credential_store::CredentialStore;
+pub use self::file_store::FileStore;
+pub use self::memory_store::MemoryStore;
+pub use self::wallet::Wallet;
@@ -0,0 +1,470 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::crypto::SecretKey;
+use identity_core::utils::encode_b58;
+use identity_core::utils::jcs_sha256;
+use identity_did::document::Document;
+use identity_did::verification::MethodQuery;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::credential::CredentialVersion;
+use crate::credential::VerifiableCredential;
+use crate::error::Error;
+use crate::error::Result;
+use crate::presentation::PresentationBuilder;
+use crate::presentation::VerifiablePresentation;
+use crate::verifier::StatusResolver;
+use crate::wallet::CredentialQuery;
+use crate::wallet::CredentialStore;
+use crate::wallet::MemoryStore;
+
+/// A holder-side collection of `VerifiableCredential`s backed by a
+/// [`CredentialStore`].
+///
+/// Credentials are keyed by the base58-encoded hash of their JCS
+/// representation and indexed by type, issuer, subject, and expiration.
+#[derive(Clone, Debug)]
+pub struct Wallet<S = MemoryStore> {
+  store: S,
+  keys: BTreeSet<String>,
+  types: BTreeMap<String, BTreeSet<String>>,
+  issuers: BTreeMap<String, BTreeSet<String>>,
+  subjects: BTreeMap<String, BTreeSet<String>>,
+  expirations: BTreeSet<(Timestamp, String)>,
+}
+
+impl<S> Wallet<S>
+where
+  S: CredentialStore,
+{
+  /// Creates a new `Wallet` and indexes all credentials in `store`.
+  ///
+  /// Note: Entries that cannot be read or parsed as a `VerifiableCredential`
+  /// are skipped and remain untouched in `store`.
+  pub fn new(store: S) -> Result<Self> {
+    let mut this: Self = Self {
+      store,
+      keys: BTreeSet::new(),
+      types: BTreeMap::new(),
+      issuers: BTreeMap::new(),
+      subjects: BTreeMap::new(),
+      expirations: BTreeSet::new(),
+    };
+
+    for key in this.store.keys()? {
+      if let Ok(Some(credential)) = this.store.get(&key) {
+        this.index(key, &credential);
+      }
+    }
+
+    Ok(this)
+  }
+
+  /// Returns a reference to the underlying [`CredentialStore`].
+  pub fn store(&self) -> &S {
+    &self.store
+  }
+
+  /// Returns the number of credentials in the `Wallet`.
+  pub fn len(&self) -> usize {
+    self.keys.len()
+  }
+
+  /// Returns `true` if the `Wallet` contains no credentials.
+  pub fn is_empty(&self) -> bool {
+    self.keys.is_empty()
+  }
+
+  /// Returns an iterator over the keys of all credentials in the `Wallet`.
+  pub fn keys(&self) -> impl Iterator<Item = &str> + '_ {
+    self.keys.iter().map(String::as_str)
+  }
+
+  /// Adds `credential` to the `Wallet` and returns its key.
+  ///
+  /// Adding a credential that is already held has no effect.
+  pub fn insert(&mut self, credential: VerifiableCredential) -> Result<String> {
+    credential.check_structure()?;
+
+    let key: String = encode_b58(&jcs_sha256(&credential)?);
+
+    if !self.keys.contains(&key) {
+      self.store.insert(&key, &credential)?;
+      self.index(key.clone(), &credential);
+    }
+
+    Ok(key)
+  }
+
+  /// Parses a JSON-encoded `VerifiableCredential` and adds it to the
+  /// `Wallet`.
+  pub fn insert_json(&mut self, json: &str) -> Result<String> {
+    self.insert(VerifiableCredential::from_json(json)?)
+  }
+
+  /// Returns the credential with the given `key`, if any.
+  pub fn get(&self, key: &str) -> Result<Option<VerifiableCredential>> {
+    if self.keys.contains(key) {
+      self.store.get(key)
+    } else {
+      Ok(None)
+    }
+  }
+
+  /// Removes the credential with the given `key` from the `Wallet` and
+  /// returns it.
+  pub fn remove(&mut self, key: &str) -> Result<Option<VerifiableCredential>> {
+    let credential: Option<VerifiableCredential> = self.get(key)?;
+
+    if let Some(credential) = credential.as_ref() {
+      self.store.remove(key)?;
+      self.unindex(key, credential);
+    }
+
+    Ok(credential)
+  }
+
+  /// Returns the keys and credentials matching `query`, ordered by key.
+  pub fn query(&self, query: &CredentialQuery) -> Result<Vec<(String, VerifiableCredential)>> {
+    let mut candidates: BTreeSet<&String> = self.keys.iter().collect();
+
+    let indexed = query
+      .types
+      .iter()
+      .map(|type_| self.types.get(type_))
+      .chain(query.issuer.as_ref().map(|issuer| self.issuers.get(issuer)))
+      .chain(query.subject.as_ref().map(|subject| self.subjects.get(subject)));
+
+    for keys in indexed {
+      match keys {
+        Some(keys) => candidates = candidates.into_iter().filter(|key| keys.contains(*key)).collect(),
+        None => return Ok(Vec::new()),
+      }
+    }
+
+    if let Some(time) = query.valid_at {
+      for key in self.expired(time) {
+        candidates.remove(&key.to_string());
+      }
+    }
+
+    let mut output: Vec<(String, VerifiableCredential)> = Vec::with_capacity(candidates.len());
+
+    for key in candidates {
+      let credential: VerifiableCredential = self.store.get(key)?.ok_or(Error::CredentialNotFound)?;
+
+      // Credentials without a start of validity are considered valid
+      if let (Some(time), Some(start)) = (query.valid_at, credential.validity_start()) {
+        if start > time {
+          continue;
+        }
+      }
+
+      output.push((key.clone(), credential));
+    }
+
+    Ok(output)
+  }
+
+  /// Returns the keys of all credentials that are expired at `time`.
+  pub fn expired(&self, time: Timestamp) -> Vec<&str> {
+    self
+      .expirations
+      .iter()
+      .take_while(|(expiration, _)| *expiration <= time)
+      .map(|(_, key)| key.as_str())
+      .collect()
+  }
+
+  /// Removes all credentials that are expired at `time` and returns their
+  /// keys.
+  pub fn remove_expired(&mut self, time: Timestamp) -> Result<Vec<String>> {
+    let keys: Vec<String> = self.expired(time).into_iter().map(ToString::to_string).collect();
+
+    for key in keys.iter() {
+      self.remove(key)?;
+    }
+
+    Ok(keys)
+  }
+
+  /// Removes all credentials reported as revoked by `resolver` and returns
+  /// their keys.
+  pub fn remove_revoked<R>(&mut self, resolver: &R) -> Result<Vec<String>>
+  where
+    R: StatusResolver + ?Sized,
+  {
+    let mut revoked: Vec<String> = Vec::new();
+
+    for key in self.keys.iter() {
+      let credential: VerifiableCredential = self.store.get(key)?.ok_or(Error::CredentialNotFound)?;
+
+      for status in credential.credential_status.iter() {
+        if resolver.is_revoked(status)? {
+          revoked.push(key.clone());
+          break;
+        }
+      }
+    }
+
+    for key in revoked.iter() {
+      self.remove(key)?;
+    }
+
+    Ok(revoked)
+  }
+
+  /// Returns a `PresentationBuilder` containing the credentials with the
+  /// given `keys`.
+  ///
+  /// The VCDM 2.0 base context is used if all credentials are VCDM 2.0.
+  pub fn presentation(&self, keys: &[&str]) -> Result<PresentationBuilder> {
+    let mut builder: PresentationBuilder = PresentationBuilder::new(Object::new());
+    let mut v2: bool = !keys.is_empty();
+
+    for key in keys {
+      let credential: VerifiableCredential = self.get(key)?.ok_or(Error::CredentialNotFound)?;
+
+      v2 &= credential.version() == Some(CredentialVersion::V2_0);
+      builder = builder.credential(credential);
+    }
+
+    if v2 {
+      builder = builder.version(CredentialVersion::V2_0);
+    }
+
+    Ok(builder)
+  }
+
+  /// Creates a `VerifiablePresentation` of the credentials with the given
+  /// `keys`, held and signed by `holder` with `secret`.
+  pub fn present<'a, Q, D1, D2, D3>(
+    &self,
+    keys: &[&str],
+    holder: &Document<D1, D2, D3>,
+    query: Q,
+    secret: &SecretKey,
+  ) -> Result<VerifiablePresentation>
+  where
+    Q: Into<MethodQuery<'a>>,
+  {
+    self
+      .presentation(keys)?
+      .holder(Url::parse(holder.id().as_str())?)
+      .build()?
+      .sign(holder, query, secret)
+  }
+
+  fn index(&mut self, key: String, credential: &VerifiableCredential) {
+    for type_ in credential.types.iter() {
+      self.types.entry(type_.clone()).or_default().insert(key.clone());
+    }
+
+    self
+      .issuers
+      .entry(credential.issuer.url().to_string())
+      .or_default()
+      .insert(key.clone());
+
+    for subject in credential.credential_subject.iter() {
+      if let Some(id) = subject.id.as_ref() {
+        self.subjects.entry(id.to_string()).or_default().insert(key.clone());
+      }
+    }
+
+    if let Some(expiration) = credential.validity_end() {
+      self.expirations.insert((expiration, key.clone()));
+    }
+
+    self.keys.insert(key);
+  }
+
+  fn unindex(&mut self, key: &str, credential: &VerifiableCredential) {
+    fn remove_key(index: &mut BTreeMap<String, BTreeSet<String>>, value: String, key: &str) {
+      if let Some(keys) = index.get_mut(&value) {
+        keys.remove(key);
+
+        if keys.is_empty() {
+          index.remove(&value);
+        }
+      }
+    }
+
+    for type_ in credential.types.iter() {
+      remove_key(&mut self.types, type_.clone(), key);
+    }
+
+    remove_key(&mut self.issuers, credential.issuer.url().to_string(), key);
+
+    for subject in credential.credential_subject.iter() {
+      if let Some(id) = subject.id.as_ref() {
+        remove_key(&mut self.subjects, id.to_string(), key);
+      }
+    }
+
+    if let Some(expiration) = credential.validity_end() {
+      self.expirations.remove(&(expiration, key.to_string()));
+    }
+
+    self.keys.remove(key);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::crypto::JcsEd25519Signature2020;
+  use identity_core::crypto::KeyPair;
+  use identity_did::verifiable::LdSuite;
+  use identity_did::verification::MethodScope;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Status;
+  use crate::credential::Subject;
+  use crate::test_utils::document;
+
+  struct Revoked(Url);
+
+  impl StatusResolver for Revoked {
+    fn is_revoked(&self, status: &Status) -> Result<bool> {
+      Ok(status.id.as_str() == self.0.as_str())
+    }
+  }
+
+  fn credential(type_: &str, issuer: &str, expiration: &str, status: &str) -> VerifiableCredential {
+    let credential: Credential = CredentialBuilder::default()
+      .type_(type_)
+      .subject(Subject::from_json_value(json!({ "id": "did:example:holder" })).unwrap())
+      .issuer(Url::parse(issuer).unwrap())
+      .issuance_date(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+      .expiration_date(Timestamp::parse(expiration).unwrap())
+      .status(Status::new(
+        Url::parse(status).unwrap(),
+        "StatusList2021Entry".to_string(),
+      ))
+      .build()
+      .unwrap();
+
+    VerifiableCredential::new(credential, Vec::new())
+  }
+
+  fn wallet() -> (Wallet, Vec<String>) {
+    let mut wallet: Wallet = Wallet::new(MemoryStore::new()).unwrap();
+
+    let keys: Vec<String> = vec![
+      credential(
+        "UniversityDegreeCredential",
+        "did:example:university",
+        "2030-01-01T00:00:00Z",
+        "https://example.edu/status/1",
+      ),
+      credential(
+        "UniversityDegreeCredential",
+        "did:example:college",
+        "2021-01-01T00:00:00Z",
+        "https://example.edu/status/2",
+      ),
+      credential(
+        "DriversLicenseCredential",
+        "did:example:dmv",
+        "2030-01-01T00:00:00Z",
+        "https://example.gov/status/3",
+      ),
+    ]
+    .into_iter()
+    .map(|credential| wallet.insert(credential).unwrap())
+    .collect();
+
+    (wallet, keys)
+  }
+
+  fn keys(results: Vec<(String, VerifiableCredential)>) -> Vec<String> {
+    results.into_iter().map(|(key, _)| key).collect()
+  }
+
+  #[test]
+  fn test_insert_and_query() {
+    let (mut wallet, keys): (Wallet, Vec<String>) = wallet();
+
+    assert_eq!(wallet.len(), 3);
+
+    let json: String = wallet.get(&keys[0]).unwrap().unwrap().to_json().unwrap();
+    assert_eq!(wallet.insert_json(&json).unwrap(), keys[0]);
+    assert_eq!(wallet.len(), 3);
+
+    let query: CredentialQuery = CredentialQuery::new().type_("UniversityDegreeCredential");
+    let mut expected: Vec<String> = vec![keys[0].clone(), keys[1].clone()];
+    expected.sort();
+    assert_eq!(self::keys(wallet.query(&query).unwrap()), expected);
+
+    let query: CredentialQuery = query.valid_at(Timestamp::parse("2022-01-01T00:00:00Z").unwrap());
+    assert_eq!(self::keys(wallet.query(&query).unwrap()), vec![keys[0].clone()]);
+
+    let query: CredentialQuery = CredentialQuery::new().issuer(&Url::parse("did:example:dmv").unwrap());
+    assert_eq!(self::keys(wallet.query(&query).unwrap()), vec![keys[2].clone()]);
+
+    let query: CredentialQuery = CredentialQuery::new()
+      .subject(&Url::parse("did:example:holder").unwrap())
+      .valid_at(Timestamp::parse("2019-01-01T00:00:00Z").unwrap());
+    assert!(wallet.query(&query).unwrap().is_empty());
+
+    let query: CredentialQuery = CredentialQuery::new().type_("UnknownCredential");
+    assert!(wallet.query(&query).unwrap().is_empty());
+  }
+
+  #[test]
+  fn test_housekeeping() {
+    let (mut wallet, keys): (Wallet, Vec<String>) = wallet();
+
+    let expired: Vec<String> = wallet
+      .remove_expired(Timestamp::parse("2022-01-01T00:00:00Z").unwrap())
+      .unwrap();
+
+    assert_eq!(expired, vec![keys[1].clone()]);
+    assert!(wallet.get(&keys[1]).unwrap().is_none());
+
+    let resolver: Revoked = Revoked(Url::parse("https://example.gov/status/3").unwrap());
+    let revoked: Vec<String> = wallet.remove_revoked(&resolver).unwrap();
+
+    assert_eq!(revoked, vec![keys[2].clone()]);
+    assert_eq!(wallet.keys().collect::<Vec<_>>(), vec![keys[0].as_str()]);
+
+    let query: CredentialQuery = CredentialQuery::new().issuer(&Url::parse("did:example:dmv").unwrap());
+    assert!(wallet.query(&query).unwrap().is_empty());
+  }
+
+  #[test]
+  fn test_present() {
+    let (wallet, keys): (Wallet, Vec<String>) = wallet();
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document = document("did:example:holder", &keypair, &[MethodScope::Authentication]);
+
+    let presentation: VerifiablePresentation = wallet
+      .present(
+        &[keys[0].as_str(), keys[2].as_str()],
+        &document,
+        ("#key-1", MethodScope::Authentication),
+        keypair.secret(),
+      )
+      .unwrap();
+
+    assert_eq!(presentation.verifiable_credential.len(), 2);
+    assert_eq!(presentation.holder.as_ref().unwrap().as_str(), "did:example:holder");
+    assert!(LdSuite::new(JcsEd25519Signature2020)
+      .verify_data(&presentation, &document)
+      .is_ok());
+
+    assert!(matches!(
+      wallet.presentation(&["unknown"]),
+      Err(Error::CredentialNotFound)
+    ));
+  }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
+use identity_core::common::OneOrMany;
 use identity_core::common::Url;
 use identity_core::diff::Diff;
 use identity_core::diff::DiffString;
@@ -30,7 +31,7 @@ where
   #[serde(skip_serializing_if = "Option::is_none")]
   id: Option<DiffString>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  controller: Option<Option<DiffString>>,
+  controller: Option<DiffController>,
   #[serde(skip_serializing_if = "Option::is_none")]
   also_known_as: Option<DiffVec<Url>>,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,6 +52,17 @@ where
   properties: Option<<T as Diff>::Type>,
 }
 
+// The diff of the document controller(s).
+//
+// Diffs published before documents supported multiple controllers hold the
+// `DiffString` of a single DID (or `null` if the controller was removed).
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+enum DiffController {
+  Set(DiffVec<DID>),
+  One(Option<DiffString>),
+}
+
 impl<T, U, V> Diff for Document<T, U, V>
 where
   T: Diff + Serialize + for<'de> Deserialize<'de>,
@@ -69,11 +81,7 @@ where
       controller: if self.controller() == other.controller() {
         None
       } else {
-        match (self.controller(), other.controller()) {
-          (Some(a), Some(b)) => Some(Some(a.diff(&b)?)),
-          (None, Some(b)) => Some(Some(b.clone().into_diff()?)),
-          _ => Some(None),
-        }
+        Some(DiffController::Set(controller_vec(self).diff(&controller_vec(other))?))
       },
       also_known_as: if self.also_known_as() == other.also_known_as() {
         None
@@ -130,11 +138,11 @@ where
       .transpose()?
       .unwrap_or_else(|| self.id().clone());
 
-    let controller: Option<DID> = diff
-      .controller
-      .flatten()
-      .and_then(|value| self.controller().map(|controller| controller.merge(value)))
-      .transpose()?;
+    let controller: Option<OneOrMany<DID>> = match diff.controller {
+      Some(DiffController::Set(value)) => controller_set(controller_vec(self).merge(value)?),
+      Some(DiffController::One(value)) => value.map(DID::from_diff).transpose()?.map(Into::into),
+      None => self.controller().cloned(),
+    };
 
     let also_known_as: Vec<Url> = diff
       .also_known_as
@@ -212,14 +220,11 @@ where
       .transpose()?
       .ok_or_else(|| Error::convert("Missing field `id`"))?;
 
-    let controller: Option<DID> = diff
-      .controller
-      .map(|diff| match diff {
-        Some(diff) => Some(DID::from_diff(diff)).transpose(),
-        None => Ok(None),
-      })
-      .transpose()?
-      .ok_or_else(|| Error::convert("Missing field `controller`"))?;
+    let controller: Option<OneOrMany<DID>> = match diff.controller {
+      Some(DiffController::Set(value)) => controller_set(Diff::from_diff(value)?),
+      Some(DiffController::One(value)) => value.map(DID::from_diff).transpose()?.map(Into::into),
+      None => return Err(Error::convert("Missing field `controller`")),
+    };
 
     let also_known_as: Vec<Url> = diff
       .also_known_as
@@ -293,7 +298,7 @@ where
   fn into_diff(self) -> Result<Self::Type> {
     Ok(DiffDocument {
       id: Some(self.id().clone().into_diff()?),
-      controller: Some(self.controller().cloned().map(|value| value.into_diff()).transpose()?),
+      controller: Some(DiffController::Set(controller_vec(&self).into_diff()?)),
       also_known_as: Some(self.also_known_as().to_vec().into_diff()?),
       verification_method: Some(self.verification_method().to_vec().into_diff()?),
       authentication: Some(self.authentication().to_vec().into_diff()?),
@@ -306,3 +311,66 @@ where
     })
   }
 }
+
+fn controller_vec<T, U, V>(document: &Document<T, U, V>) -> Vec<DID> {
+  document.controllers().cloned().collect()
+}
+
+fn controller_set(controller: Vec<DID>) -> Option<OneOrMany<DID>> {
+  if controller.is_empty() {
+    None
+  } else {
+    Some(controller.into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Value;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use identity_core::json;
+
+  use super::*;
+  use crate::document::DocumentBuilder;
+
+  fn document(controller: &[&str]) -> Document {
+    controller
+      .iter()
+      .fold(
+        DocumentBuilder::default().id("did:example:1234".parse().unwrap()),
+        |builder, did| builder.controller(did.parse().unwrap()),
+      )
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_controller_diff() {
+    let a: Document = document(&["did:example:officer-1"]);
+    let b: Document = document(&["did:example:officer-1", "did:example:officer-2"]);
+
+    let diff: DiffDocument = a.diff(&b).unwrap();
+    let diff: DiffDocument = DiffDocument::from_json(&diff.to_json().unwrap()).unwrap();
+
+    assert_eq!(a.merge(diff).unwrap(), b);
+    assert_eq!(Document::from_diff(b.clone().into_diff().unwrap()).unwrap(), b);
+  }
+
+  #[test]
+  fn test_legacy_controller_diff() {
+    // Controller diffs published before documents supported multiple
+    // controllers hold a single DID
+    let a: Document = document(&["did:example:officer-1"]);
+    let diff: DiffDocument = DiffDocument::from_json(r#"{"controller":"did:example:officer-2"}"#).unwrap();
+
+    assert_eq!(a.merge(diff).unwrap(), document(&["did:example:officer-2"]));
+
+    let mut diff: Value = a.clone().into_diff().unwrap().to_json_value().unwrap();
+    diff["controller"] = json!("did:example:officer-1");
+
+    let diff: DiffDocument = DiffDocument::from_json_value(diff).unwrap();
+
+    assert_eq!(Document::from_diff(diff).unwrap(), a);
+  }
+}
@@ -9,9 +9,11 @@ mod method_ref;
 mod method_type;
 mod ordered_set;
 mod service;
+mod service_endpoint;
 
 pub use self::document::DiffDocument;
 pub use self::method::DiffMethod;
 pub use self::method_data::DiffMethodData;
 pub use self::method_ref::DiffMethodRef;
 pub use self::service::DiffService;
+pub use self::service_endpoint::DiffServiceEndpoint;
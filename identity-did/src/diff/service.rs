@@ -2,7 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
-use identity_core::common::Url;
 use identity_core::diff::Diff;
 use identity_core::diff::DiffString;
 use identity_core::diff::Error;
@@ -11,7 +10,9 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::did::DID;
+use crate::diff::DiffServiceEndpoint;
 use crate::service::Service;
+use crate::service::ServiceEndpoint;
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct DiffService<T = Object>
@@ -23,7 +24,7 @@ where
   #[serde(skip_serializing_if = "Option::is_none")]
   type_: Option<DiffString>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  service_endpoint: Option<DiffString>,
+  service_endpoint: Option<DiffServiceEndpoint>,
   #[serde(skip_serializing_if = "Option::is_none")]
   properties: Option<<T as Diff>::Type>,
 }
@@ -72,7 +73,7 @@ where
       .transpose()?
       .unwrap_or_else(|| self.type_().to_string());
 
-    let service_endpoint: Url = diff
+    let service_endpoint: ServiceEndpoint = diff
       .service_endpoint
       .map(|value| self.service_endpoint().merge(value))
       .transpose()?
@@ -105,9 +106,9 @@ where
       .transpose()?
       .ok_or_else(|| Error::convert("Missing field `type_`"))?;
 
-    let service_endpoint: Url = diff
+    let service_endpoint: ServiceEndpoint = diff
       .service_endpoint
-      .map(Url::from_diff)
+      .map(ServiceEndpoint::from_diff)
       .transpose()?
       .ok_or_else(|| Error::convert("Missing field `service_endpoint`"))?;
 
@@ -129,7 +130,7 @@ where
     Ok(DiffService {
       id: Some(self.id().to_string().into_diff()?),
       type_: Some(self.type_().to_string().into_diff()?),
-      service_endpoint: Some(self.service_endpoint().to_string().into_diff()?),
+      service_endpoint: Some(self.service_endpoint().clone().into_diff()?),
       properties: Some(self.properties().clone().into_diff()?),
     })
   }
@@ -0,0 +1,181 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::diff::Diff;
+use identity_core::diff::DiffObject;
+use identity_core::diff::DiffString;
+use identity_core::diff::DiffVec;
+use identity_core::diff::Error;
+use identity_core::diff::Result;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::service::ServiceEndpoint;
+
+/// The diff of a [`ServiceEndpoint`].
+///
+/// URL diffs are encoded as a plain [`DiffString`] so diffs published before
+/// map and set endpoints were supported remain readable; map and set diffs
+/// are tagged with their variant name.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DiffServiceEndpoint {
+  One(Option<DiffString>),
+  Map(Option<DiffObject>),
+  Set(Option<DiffVec<ServiceEndpoint>>),
+}
+
+#[derive(Serialize)]
+enum TaggedRef<'a> {
+  Map(&'a Option<DiffObject>),
+  Set(&'a Option<DiffVec<ServiceEndpoint>>),
+}
+
+#[derive(Deserialize)]
+enum Tagged {
+  One(Option<DiffString>),
+  Map(Option<DiffObject>),
+  Set(Option<DiffVec<ServiceEndpoint>>),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Encoded {
+  Url(Option<DiffString>),
+  Tagged(Tagged),
+}
+
+impl Serialize for DiffServiceEndpoint {
+  fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match self {
+      Self::One(value) => value.serialize(serializer),
+      Self::Map(value) => TaggedRef::Map(value).serialize(serializer),
+      Self::Set(value) => TaggedRef::Set(value).serialize(serializer),
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for DiffServiceEndpoint {
+  fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    match Encoded::deserialize(deserializer)? {
+      Encoded::Url(value) | Encoded::Tagged(Tagged::One(value)) => Ok(Self::One(value)),
+      Encoded::Tagged(Tagged::Map(value)) => Ok(Self::Map(value)),
+      Encoded::Tagged(Tagged::Set(value)) => Ok(Self::Set(value)),
+    }
+  }
+}
+
+impl Diff for ServiceEndpoint {
+  type Type = DiffServiceEndpoint;
+
+  fn diff(&self, other: &Self) -> Result<Self::Type> {
+    match (self, other) {
+      (Self::One(a), Self::One(b)) if a == b => Ok(DiffServiceEndpoint::One(None)),
+      (Self::One(a), Self::One(b)) => a.diff(b).map(Some).map(DiffServiceEndpoint::One),
+      (Self::Map(a), Self::Map(b)) if a == b => Ok(DiffServiceEndpoint::Map(None)),
+      (Self::Map(a), Self::Map(b)) => a.diff(b).map(Some).map(DiffServiceEndpoint::Map),
+      (Self::Set(a), Self::Set(b)) if a == b => Ok(DiffServiceEndpoint::Set(None)),
+      (Self::Set(a), Self::Set(b)) => a.diff(b).map(Some).map(DiffServiceEndpoint::Set),
+      (_, _) => other.clone().into_diff(),
+    }
+  }
+
+  fn merge(&self, diff: Self::Type) -> Result<Self> {
+    match (self, diff) {
+      (Self::One(a), DiffServiceEndpoint::One(Some(b))) => a.merge(b).map(Self::One),
+      (Self::One(a), DiffServiceEndpoint::One(None)) => Ok(Self::One(a.clone())),
+      (Self::Map(a), DiffServiceEndpoint::Map(Some(b))) => a.merge(b).map(Self::Map),
+      (Self::Map(a), DiffServiceEndpoint::Map(None)) => Ok(Self::Map(a.clone())),
+      (Self::Set(a), DiffServiceEndpoint::Set(Some(b))) => a.merge(b).map(Self::Set),
+      (Self::Set(a), DiffServiceEndpoint::Set(None)) => Ok(Self::Set(a.clone())),
+      (_, diff) => Self::from_diff(diff),
+    }
+  }
+
+  fn from_diff(diff: Self::Type) -> Result<Self> {
+    match diff {
+      DiffServiceEndpoint::One(Some(value)) => Diff::from_diff(value).map(Self::One),
+      DiffServiceEndpoint::One(None) => Err(Error::convert("Missing field `service_endpoint`")),
+      DiffServiceEndpoint::Map(Some(value)) => Diff::from_diff(value).map(Self::Map),
+      DiffServiceEndpoint::Map(None) => Ok(Self::Map(Default::default())),
+      DiffServiceEndpoint::Set(Some(value)) => Diff::from_diff(value).map(Self::Set),
+      DiffServiceEndpoint::Set(None) => Ok(Self::Set(Default::default())),
+    }
+  }
+
+  fn into_diff(self) -> Result<Self::Type> {
+    match self {
+      Self::One(value) => value.into_diff().map(Some).map(DiffServiceEndpoint::One),
+      Self::Map(value) => value.into_diff().map(Some).map(DiffServiceEndpoint::Map),
+      Self::Set(value) => value.into_diff().map(Some).map(DiffServiceEndpoint::Set),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use super::*;
+  use crate::utils::OrderedSet;
+
+  fn roundtrip(a: ServiceEndpoint, b: ServiceEndpoint) {
+    let diff: DiffServiceEndpoint = a.diff(&b).unwrap();
+    let json: String = diff.to_json().unwrap();
+    let decoded: DiffServiceEndpoint = DiffServiceEndpoint::from_json(&json).unwrap();
+
+    assert_eq!(decoded, diff);
+    assert_eq!(a.merge(decoded).unwrap(), b);
+  }
+
+  #[test]
+  fn test_deserialize_url_diff() {
+    // Service endpoint diffs published before map and set endpoints were
+    // supported hold a plain `DiffString`.
+    let diff: DiffServiceEndpoint = DiffServiceEndpoint::from_json(r#""https://example.org/""#).unwrap();
+    let endpoint: ServiceEndpoint = ServiceEndpoint::One(Url::parse("https://example.com/").unwrap());
+
+    assert_eq!(diff.to_json().unwrap(), r#""https://example.org/""#);
+    assert_eq!(
+      endpoint.merge(diff).unwrap(),
+      ServiceEndpoint::One(Url::parse("https://example.org/").unwrap())
+    );
+  }
+
+  #[test]
+  fn test_roundtrip() {
+    let url: ServiceEndpoint = ServiceEndpoint::One(Url::parse("https://example.com/").unwrap());
+    let map: ServiceEndpoint = ServiceEndpoint::from_json(r#"{"uri":"https://example.com/"}"#).unwrap();
+    let set: ServiceEndpoint =
+      ServiceEndpoint::from_json(r#"["https://example.com/",{"uri":"https://example.org/"}]"#).unwrap();
+
+    roundtrip(
+      url.clone(),
+      ServiceEndpoint::One(Url::parse("https://example.org/").unwrap()),
+    );
+    roundtrip(url.clone(), url.clone());
+    roundtrip(map.clone(), ServiceEndpoint::Map(Object::new()));
+    roundtrip(
+      map.clone(),
+      ServiceEndpoint::from_json(r#"{"uri":"https://example.org/"}"#).unwrap(),
+    );
+    roundtrip(set.clone(), ServiceEndpoint::Set(OrderedSet::new()));
+    roundtrip(
+      set.clone(),
+      ServiceEndpoint::from_json(r#"["https://example.org/"]"#).unwrap(),
+    );
+    roundtrip(url.clone(), map.clone());
+    roundtrip(map, set.clone());
+    roundtrip(set, url);
+  }
+}
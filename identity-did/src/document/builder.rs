@@ -16,7 +16,7 @@ use crate::verification::MethodRef;
 #[derive(Clone, Debug)]
 pub struct DocumentBuilder<T = Object, U = Object, V = Object> {
   pub(crate) id: Option<DID>,
-  pub(crate) controller: Option<DID>,
+  pub(crate) controller: Vec<DID>,
   pub(crate) also_known_as: Vec<Url>,
   pub(crate) verification_method: Vec<DIDKey<Method<U>>>,
   pub(crate) authentication: Vec<DIDKey<MethodRef<U>>>,
@@ -33,7 +33,7 @@ impl<T, U, V> DocumentBuilder<T, U, V> {
   pub fn new(properties: T) -> Self {
     Self {
       id: None,
-      controller: None,
+      controller: Vec::new(),
       also_known_as: Vec::new(),
       verification_method: Vec::new(),
       authentication: Vec::new(),
@@ -53,10 +53,10 @@ impl<T, U, V> DocumentBuilder<T, U, V> {
     self
   }
 
-  /// Sets the `controller` value of the generated `Document`.
+  /// Adds a value to the `controller` set of the generated `Document`.
   #[must_use]
   pub fn controller(mut self, value: DID) -> Self {
-    self.controller = Some(value);
+    self.controller.push(value);
     self
   }
 
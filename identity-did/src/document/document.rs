@@ -7,6 +7,7 @@ use core::fmt::Error as FmtError;
 use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
 use identity_core::common::Object;
+use identity_core::common::OneOrMany;
 use identity_core::common::Url;
 use identity_core::convert::ToJson;
 use identity_core::crypto::SignatureOptions;
@@ -21,6 +22,7 @@ use crate::utils::DIDKey;
 use crate::utils::OrderedSet;
 use crate::verifiable::ResolveMethod;
 use crate::verification::Method;
+use crate::verification::MethodFilter;
 use crate::verification::MethodQuery;
 use crate::verification::MethodRef;
 use crate::verification::MethodScope;
@@ -32,7 +34,7 @@ use crate::verification::MethodWrap;
 pub struct Document<T = Object, U = Object, V = Object> {
   pub(crate) id: DID,
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub(crate) controller: Option<DID>,
+  pub(crate) controller: Option<OneOrMany<DID>>,
   #[serde(default = "Default::default", rename = "alsoKnownAs", skip_serializing_if = "Vec::is_empty")]
   pub(crate) also_known_as: Vec<Url>,
   #[serde(default = "Default::default", rename = "verificationMethod", skip_serializing_if = "OrderedSet::is_empty")]
@@ -65,7 +67,7 @@ impl<T, U, V> Document<T, U, V> {
   pub fn from_builder(builder: DocumentBuilder<T, U, V>) -> Result<Self> {
     Ok(Self {
       id: builder.id.ok_or(Error::InvalidDocumentId)?,
-      controller: builder.controller,
+      controller: Self::controller_set(builder.controller)?,
       also_known_as: builder.also_known_as,
       verification_method: builder.verification_method.try_into()?,
       authentication: builder.authentication.try_into()?,
@@ -88,14 +90,24 @@ impl<T, U, V> Document<T, U, V> {
     &mut self.id
   }
 
-  /// Returns a reference to the `Document` controller.
-  pub fn controller(&self) -> Option<&DID> {
+  /// Returns a reference to the `Document` controller set.
+  pub fn controller(&self) -> Option<&OneOrMany<DID>> {
     self.controller.as_ref()
   }
 
-  /// Returns a mutable reference to the `Document` controller.
-  pub fn controller_mut(&mut self) -> Option<&mut DID> {
-    self.controller.as_mut()
+  /// Returns a mutable reference to the `Document` controller set.
+  pub fn controller_mut(&mut self) -> &mut Option<OneOrMany<DID>> {
+    &mut self.controller
+  }
+
+  /// Returns an iterator over the DIDs of the `Document` controllers.
+  pub fn controllers(&self) -> impl Iterator<Item = &DID> + '_ {
+    self.controller.iter().flat_map(|controller| controller.iter())
+  }
+
+  /// Returns `true` if `did` is a controller of the `Document`.
+  pub fn is_controller(&self, did: &DID) -> bool {
+    self.controllers().any(|controller| controller == did)
   }
 
   /// Returns a reference to the `Document` alsoKnownAs set.
@@ -255,6 +267,70 @@ impl<T, U, V> Document<T, U, V> {
     self.resolve(query).ok_or(Error::QueryMethodNotFound)
   }
 
+  /// Returns every verification `Method` matching the provided `MethodFilter`.
+  ///
+  /// Without a filter scope, the `verificationMethod` set and all embedded
+  /// methods are searched; referenced methods of a verification relationship
+  /// are resolved from the `verificationMethod` set. The index and scope of
+  /// each result refer to the set the method was found in.
+  pub fn methods<'a, F>(&self, filter: F) -> Vec<MethodWrap<'_, U>>
+  where
+    F: Into<MethodFilter<'a>>,
+  {
+    let filter: MethodFilter<'_> = filter.into();
+
+    let scopes: &[MethodScope] = match filter.scope {
+      Some(ref scope) => core::slice::from_ref(scope),
+      None => &[
+        MethodScope::VerificationMethod,
+        MethodScope::Authentication,
+        MethodScope::AssertionMethod,
+        MethodScope::KeyAgreement,
+        MethodScope::CapabilityDelegation,
+        MethodScope::CapabilityInvocation,
+      ],
+    };
+
+    let mut methods: Vec<MethodWrap<'_, U>> = Vec::new();
+
+    for scope in scopes.iter().copied() {
+      let relationship: &OrderedSet<DIDKey<MethodRef<U>>> = match scope {
+        MethodScope::VerificationMethod => {
+          methods.extend(
+            self
+              .verification_method
+              .iter()
+              .enumerate()
+              .map(|(index, method)| MethodWrap::new(method, index, scope)),
+          );
+          continue;
+        }
+        MethodScope::Authentication => &self.authentication,
+        MethodScope::AssertionMethod => &self.assertion_method,
+        MethodScope::KeyAgreement => &self.key_agreement,
+        MethodScope::CapabilityDelegation => &self.capability_delegation,
+        MethodScope::CapabilityInvocation => &self.capability_invocation,
+      };
+
+      for (index, method) in relationship.iter().enumerate() {
+        let method: &Method<U> = match (&**method, filter.scope) {
+          (MethodRef::Embed(method), _) => method,
+          // References are only resolved when querying a relationship.
+          (MethodRef::Refer(_), None) => continue,
+          (MethodRef::Refer(did), Some(_)) => match self.verification_method.iter().find(|method| method.id() == did) {
+            Some(method) => method,
+            None => continue,
+          },
+        };
+
+        methods.push(MethodWrap::new(method, index, scope));
+      }
+    }
+
+    methods.retain(|method| filter.matches(method));
+    methods
+  }
+
   pub fn resolve_bytes<'a, Q>(&self, query: Q) -> Option<Vec<u8>>
   where
     Q: Into<MethodQuery<'a>>,
@@ -282,6 +358,16 @@ impl<T, U, V> Document<T, U, V> {
     ))
   }
 
+  fn controller_set(controller: Vec<DID>) -> Result<Option<OneOrMany<DID>>> {
+    let controller: OrderedSet<DID> = controller.try_into()?;
+
+    if controller.is_empty() {
+      Ok(None)
+    } else {
+      Ok(Some(controller.into_vec().into()))
+    }
+  }
+
   fn resolve_method<'a>(&self, query: MethodQuery<'a>) -> Option<MethodWrap<'_, U>> {
     let iter = match query.scope {
       MethodScope::VerificationMethod => return self.resolve_verification_method(query),
@@ -334,12 +420,19 @@ impl<T, U, V> ResolveMethod<U> for Document<T, U, V> {
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::Object;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use identity_core::json;
+
   use crate::did::DID;
   use crate::document::Document;
   use crate::document::DocumentBuilder;
   use crate::verification::Method;
   use crate::verification::MethodBuilder;
   use crate::verification::MethodData;
+  use crate::verification::MethodDataKind;
+  use crate::verification::MethodFilter;
   use crate::verification::MethodScope;
   use crate::verification::MethodType;
 
@@ -432,4 +525,92 @@ mod tests {
     // Resolving an existing reference to a missing method returns None
     assert_eq!(document.resolve(("#key-4", MethodScope::KeyAgreement)), None);
   }
+
+  #[test]
+  fn test_controller_set() {
+    let officer_1: DID = "did:example:officer-1".parse().unwrap();
+    let officer_2: DID = "did:example:officer-2".parse().unwrap();
+
+    let single: Document = DocumentBuilder::default()
+      .id(controller())
+      .controller(officer_1.clone())
+      .build()
+      .unwrap();
+
+    let multiple: Document = DocumentBuilder::default()
+      .id(controller())
+      .controller(officer_1.clone())
+      .controller(officer_2.clone())
+      .build()
+      .unwrap();
+
+    assert_eq!(single.to_json_value().unwrap()["controller"], officer_1.as_str());
+    assert_eq!(
+      multiple.to_json_value().unwrap()["controller"],
+      json!([officer_1.as_str(), officer_2.as_str()])
+    );
+
+    assert!(multiple.is_controller(&officer_2));
+    assert!(!single.is_controller(&officer_2));
+    assert_eq!(
+      Document::from_json_value(multiple.to_json_value().unwrap()).unwrap(),
+      multiple
+    );
+
+    assert!(DocumentBuilder::<Object, Object, Object>::default()
+      .id(controller())
+      .controller(officer_1.clone())
+      .controller(officer_1)
+      .build()
+      .is_err());
+  }
+
+  #[test]
+  fn test_methods_filter() {
+    let document: Document = document();
+    let other: DID = "did:example:5678".parse().unwrap();
+
+    let ids = |filter: MethodFilter<'_>| -> Vec<String> {
+      document
+        .methods(filter)
+        .iter()
+        .map(|method| method.id().to_string())
+        .collect()
+    };
+
+    // All defined and embedded methods, without duplicating references
+    assert_eq!(
+      ids(MethodFilter::new()),
+      [
+        "did:example:1234#key-1",
+        "did:example:1234#key-2",
+        "did:example:1234#key-3",
+        "did:example:1234#auth-key"
+      ]
+    );
+
+    // References are resolved; dangling references are skipped
+    assert_eq!(
+      ids(MethodScope::Authentication.into()),
+      ["did:example:1234#auth-key", "did:example:1234#key-3"]
+    );
+    assert!(ids(MethodScope::KeyAgreement.into()).is_empty());
+
+    assert_eq!(
+      ids(
+        MethodFilter::new()
+          .key_type(MethodType::Ed25519VerificationKey2018)
+          .key_data(MethodDataKind::PublicKeyBase58)
+          .controller(&controller())
+      )
+      .len(),
+      4
+    );
+    assert!(ids(MethodType::MerkleKeyCollection2021.into()).is_empty());
+    assert!(ids(MethodFilter::new().controller(&other)).is_empty());
+
+    let methods = document.methods(MethodScope::Authentication);
+    assert_eq!(methods[1].index(), 1);
+    assert_eq!(methods[1].scope(), MethodScope::Authentication);
+  }
 }
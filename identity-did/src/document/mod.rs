@@ -5,6 +5,9 @@
 
 mod builder;
 mod document;
+mod relationship;
+mod validator;
 
 pub use self::builder::DocumentBuilder;
 pub use self::document::Document;
+pub use self::validator::Violation;
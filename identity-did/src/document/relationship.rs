@@ -0,0 +1,271 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::did::DID;
+use crate::document::Document;
+use crate::error::Error;
+use crate::error::Result;
+use crate::utils::DIDKey;
+use crate::utils::OrderedSet;
+use crate::verification::Method;
+use crate::verification::MethodRef;
+use crate::verification::MethodScope;
+
+impl<T, U, V> Document<T, U, V> {
+  /// Adds a verification `Method` to the `verificationMethod` set and
+  /// references it from the verification relationships of the given `scopes`.
+  ///
+  /// Note: `MethodScope::VerificationMethod` is implied and ignored.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a method with the same id exists in the `Document`.
+  pub fn insert_method(&mut self, method: Method<U>, scopes: &[MethodScope]) -> Result<()> {
+    if self.contains_method(method.id()) {
+      return Err(Error::DuplicateMethod);
+    }
+
+    let id: DID = method.id().clone();
+
+    self.verification_method.append(DIDKey::new(method));
+
+    for scope in scopes {
+      if let Some(relationship) = self.relationship_mut(*scope) {
+        relationship.append(DIDKey::new(MethodRef::Refer(id.clone())));
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Embeds a verification `Method` in the verification relationship of the
+  /// given `scope`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a method with the same id exists in the `Document` or `scope` is
+  /// not a verification relationship.
+  pub fn insert_embedded_method(&mut self, method: Method<U>, scope: MethodScope) -> Result<()> {
+    if self.contains_method(method.id()) {
+      return Err(Error::DuplicateMethod);
+    }
+
+    self
+      .relationship_mut(scope)
+      .ok_or(Error::InvalidMethodScope)?
+      .append(DIDKey::new(MethodRef::Embed(method)));
+
+    Ok(())
+  }
+
+  /// References the verification method with the given `id` from the
+  /// verification relationship of the given `scope`; returns `false` if the
+  /// method is already part of the relationship.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the method is not in the `verificationMethod` set or `scope` is
+  /// not a verification relationship.
+  pub fn attach_method(&mut self, id: &DID, scope: MethodScope) -> Result<bool> {
+    if !self.verification_method.iter().any(|method| method.id() == id) {
+      return Err(Error::QueryMethodNotFound);
+    }
+
+    let relationship: &mut OrderedSet<DIDKey<MethodRef<U>>> =
+      self.relationship_mut(scope).ok_or(Error::InvalidMethodScope)?;
+
+    Ok(relationship.append(DIDKey::new(MethodRef::Refer(id.clone()))))
+  }
+
+  /// Removes the verification method with the given `id` from the
+  /// verification relationship of the given `scope`; returns `false` if the
+  /// method is not part of the relationship.
+  ///
+  /// Note: Embedded methods are removed from the `Document`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `scope` is not a verification relationship or the method is the
+  /// last `authentication` method.
+  pub fn detach_method(&mut self, id: &DID, scope: MethodScope) -> Result<bool> {
+    if scope == MethodScope::Authentication && self.is_last_authentication(id) {
+      return Err(Error::LastAuthenticationMethod);
+    }
+
+    let relationship: &mut OrderedSet<DIDKey<MethodRef<U>>> =
+      self.relationship_mut(scope).ok_or(Error::InvalidMethodScope)?;
+
+    Ok(relationship.remove(id))
+  }
+
+  /// Removes the verification method with the given `id` and all references to
+  /// it from the `Document`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the method does not exist or is the last `authentication`
+  /// method.
+  pub fn remove_method(&mut self, id: &DID) -> Result<()> {
+    if !self.contains_method(id) {
+      return Err(Error::QueryMethodNotFound);
+    }
+
+    if self.is_last_authentication(id) {
+      return Err(Error::LastAuthenticationMethod);
+    }
+
+    self.verification_method.remove(id);
+    self.authentication.remove(id);
+    self.assertion_method.remove(id);
+    self.key_agreement.remove(id);
+    self.capability_delegation.remove(id);
+    self.capability_invocation.remove(id);
+
+    Ok(())
+  }
+
+  /// Returns `true` if a verification method with the given `id` is in the
+  /// `verificationMethod` set or embedded in a verification relationship.
+  pub fn contains_method(&self, id: &DID) -> bool {
+    self.verification_method.iter().any(|method| method.id() == id)
+      || [
+        &self.authentication,
+        &self.assertion_method,
+        &self.key_agreement,
+        &self.capability_delegation,
+        &self.capability_invocation,
+      ]
+      .iter()
+      .flat_map(|relationship| relationship.iter())
+      .any(|method| matches!(&**method, MethodRef::Embed(method) if method.id() == id))
+  }
+
+  fn is_last_authentication(&self, id: &DID) -> bool {
+    self.authentication.len() == 1 && self.authentication.iter().all(|method| method.id() == id)
+  }
+
+  fn relationship_mut(&mut self, scope: MethodScope) -> Option<&mut OrderedSet<DIDKey<MethodRef<U>>>> {
+    match scope {
+      MethodScope::VerificationMethod => None,
+      MethodScope::Authentication => Some(&mut self.authentication),
+      MethodScope::AssertionMethod => Some(&mut self.assertion_method),
+      MethodScope::KeyAgreement => Some(&mut self.key_agreement),
+      MethodScope::CapabilityDelegation => Some(&mut self.capability_delegation),
+      MethodScope::CapabilityInvocation => Some(&mut self.capability_invocation),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::document::DocumentBuilder;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+  use crate::verification::MethodType;
+  use identity_core::common::Object;
+
+  fn did() -> DID {
+    "did:example:123".parse().unwrap()
+  }
+
+  fn method(fragment: &str) -> Method {
+    MethodBuilder::default()
+      .id(did().join(fragment).unwrap())
+      .controller(did())
+      .key_type(MethodType::Ed25519VerificationKey2018)
+      .key_data(MethodData::new_b58([1; 32]))
+      .build()
+      .unwrap()
+  }
+
+  fn ids(relationship: &OrderedSet<DIDKey<MethodRef>>) -> Vec<String> {
+    relationship.iter().map(|method| method.id().to_string()).collect()
+  }
+
+  #[test]
+  fn test_insert_and_attach() {
+    let mut document: Document = DocumentBuilder::new(Object::new()).id(did()).build().unwrap();
+
+    document
+      .insert_method(
+        method("#key-1"),
+        &[MethodScope::Authentication, MethodScope::AssertionMethod],
+      )
+      .unwrap();
+    document.insert_method(method("#key-2"), &[]).unwrap();
+    document
+      .insert_embedded_method(method("#key-3"), MethodScope::AssertionMethod)
+      .unwrap();
+
+    assert!(matches!(
+      document.insert_method(method("#key-3"), &[]),
+      Err(Error::DuplicateMethod)
+    ));
+
+    assert!(document
+      .attach_method(&did().join("#key-2").unwrap(), MethodScope::AssertionMethod)
+      .unwrap());
+    assert!(!document
+      .attach_method(&did().join("#key-1").unwrap(), MethodScope::AssertionMethod)
+      .unwrap());
+    assert!(document
+      .attach_method(&did().join("#key-3").unwrap(), MethodScope::KeyAgreement)
+      .is_err());
+
+    assert_eq!(
+      ids(document.assertion_method()),
+      [
+        "did:example:123#key-1",
+        "did:example:123#key-3",
+        "did:example:123#key-2"
+      ]
+    );
+    assert!(matches!(
+      &**document.authentication().head().unwrap(),
+      MethodRef::Refer(_)
+    ));
+  }
+
+  #[test]
+  fn test_detach_and_remove() {
+    let mut document: Document = DocumentBuilder::new(Object::new()).id(did()).build().unwrap();
+    let key_1: DID = did().join("#key-1").unwrap();
+    let key_2: DID = did().join("#key-2").unwrap();
+
+    document
+      .insert_method(
+        method("#key-1"),
+        &[MethodScope::Authentication, MethodScope::AssertionMethod],
+      )
+      .unwrap();
+    document
+      .insert_method(
+        method("#key-2"),
+        &[MethodScope::Authentication, MethodScope::AssertionMethod],
+      )
+      .unwrap();
+
+    assert!(document.detach_method(&key_1, MethodScope::AssertionMethod).unwrap());
+    assert!(!document.detach_method(&key_1, MethodScope::AssertionMethod).unwrap());
+
+    document.remove_method(&key_1).unwrap();
+
+    assert!(!document.contains_method(&key_1));
+    assert_eq!(ids(document.authentication()), ["did:example:123#key-2"]);
+    assert_eq!(ids(document.assertion_method()), ["did:example:123#key-2"]);
+
+    assert!(matches!(
+      document.detach_method(&key_2, MethodScope::Authentication),
+      Err(Error::LastAuthenticationMethod)
+    ));
+    assert!(matches!(
+      document.remove_method(&key_2),
+      Err(Error::LastAuthenticationMethod)
+    ));
+    assert!(matches!(
+      document.remove_method(&key_1),
+      Err(Error::QueryMethodNotFound)
+    ));
+  }
+}
@@ -0,0 +1,257 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use std::collections::BTreeMap;
+
+use crate::did::DID;
+use crate::document::Document;
+use crate::utils::DIDKey;
+use crate::verification::Method;
+use crate::verification::MethodData;
+use crate::verification::MethodRef;
+use crate::verification::MethodScope;
+use crate::verification::MethodType;
+
+// The size of Ed25519 and X25519 public keys.
+const PUBLIC_KEY_LEN: usize = 32;
+
+/// A DID Core conformance violation found in a [`Document`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Violation {
+  /// A verification method or service id is used more than once.
+  DuplicateId(DID),
+  /// A verification method or service id fragment is used by more than one
+  /// distinct id.
+  DuplicateFragment(DID),
+  /// A verification relationship references a method that is not defined in
+  /// the `verificationMethod` set.
+  MissingMethod(MethodScope, DID),
+  /// A controller is not a DID without a path, query, or fragment.
+  InvalidController(DID),
+  /// The key data of a verification method does not decode for its `type`.
+  InvalidKeyData(DID),
+  /// The `serviceEndpoint` of a service is an empty or nested set.
+  InvalidServiceEndpoint(DID),
+}
+
+impl Display for Violation {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::DuplicateId(did) => write!(f, "Duplicate Id: `{}`", did),
+      Self::DuplicateFragment(did) => write!(f, "Duplicate Fragment: `{}`", did),
+      Self::MissingMethod(scope, did) => write!(f, "Missing Method: `{}` in `{}`", did, scope.as_str()),
+      Self::InvalidController(did) => write!(f, "Invalid Controller: `{}`", did),
+      Self::InvalidKeyData(did) => write!(f, "Invalid Key Data: `{}`", did),
+      Self::InvalidServiceEndpoint(did) => write!(f, "Invalid Service Endpoint: `{}`", did),
+    }
+  }
+}
+
+impl<T, U, V> Document<T, U, V> {
+  /// Checks the `Document` for conformance with DID Core and returns every
+  /// [`Violation`] found.
+  pub fn validate(&self) -> Vec<Violation> {
+    let mut violations: Vec<Violation> = Vec::new();
+
+    let mut ids: Ids<'_> = Ids::new();
+
+    for controller in self.controllers() {
+      if !is_controller(controller) {
+        violations.push(Violation::InvalidController(controller.clone()));
+      }
+    }
+
+    let embedded = self
+      .relationships()
+      .flat_map(|(_, set)| set)
+      .filter_map(|method| match &**method {
+        MethodRef::Embed(method) => Some(method),
+        MethodRef::Refer(_) => None,
+      });
+
+    for method in self
+      .verification_method()
+      .iter()
+      .map(|method| &**method)
+      .chain(embedded)
+    {
+      ids.check(method.id(), &mut violations);
+
+      if !is_controller(method.controller()) {
+        violations.push(Violation::InvalidController(method.controller().clone()));
+      }
+
+      if !is_key_data(method) {
+        violations.push(Violation::InvalidKeyData(method.id().clone()));
+      }
+    }
+
+    for service in self.service().iter() {
+      ids.check(service.id(), &mut violations);
+
+      if !service.service_endpoint().is_valid() {
+        violations.push(Violation::InvalidServiceEndpoint(service.id().clone()));
+      }
+    }
+
+    for (scope, set) in self.relationships() {
+      for method in set.iter() {
+        if let MethodRef::Refer(did) = &**method {
+          // References to methods of other DID documents are not checked.
+          if is_same_did(did, self.id()) && !self.verification_method().iter().any(|method| method.id() == did) {
+            violations.push(Violation::MissingMethod(scope, did.clone()));
+          }
+        }
+      }
+    }
+
+    violations
+  }
+
+  /// Returns `true` if the `Document` conforms with DID Core.
+  ///
+  /// See [`Document::validate`] for more information.
+  pub fn is_conformant(&self) -> bool {
+    self.validate().is_empty()
+  }
+
+  fn relationships(&self) -> impl Iterator<Item = (MethodScope, &[DIDKey<MethodRef<U>>])> + '_ {
+    vec![
+      (MethodScope::Authentication, self.authentication().as_slice()),
+      (MethodScope::AssertionMethod, self.assertion_method().as_slice()),
+      (MethodScope::KeyAgreement, self.key_agreement().as_slice()),
+      (
+        MethodScope::CapabilityDelegation,
+        self.capability_delegation().as_slice(),
+      ),
+      (
+        MethodScope::CapabilityInvocation,
+        self.capability_invocation().as_slice(),
+      ),
+    ]
+    .into_iter()
+  }
+}
+
+// Tracks the verification method and service ids of a document.
+struct Ids<'a> {
+  ids: Vec<&'a DID>,
+  // Maps each fragment to the first id it was found in.
+  fragments: BTreeMap<&'a str, &'a DID>,
+}
+
+impl<'a> Ids<'a> {
+  fn new() -> Self {
+    Self {
+      ids: Vec::new(),
+      fragments: BTreeMap::new(),
+    }
+  }
+
+  fn check(&mut self, id: &'a DID, violations: &mut Vec<Violation>) {
+    if self.ids.contains(&id) {
+      violations.push(Violation::DuplicateId(id.clone()));
+      return;
+    }
+
+    if let Some(fragment) = id.fragment() {
+      if self.fragments.contains_key(fragment) {
+        violations.push(Violation::DuplicateFragment(id.clone()));
+      } else {
+        self.fragments.insert(fragment, id);
+      }
+    }
+
+    self.ids.push(id);
+  }
+}
+
+fn is_controller(did: &DID) -> bool {
+  !did.method_id().is_empty() && did.path().is_empty() && did.query().is_none() && did.fragment().is_none()
+}
+
+fn is_same_did(lhs: &DID, rhs: &DID) -> bool {
+  lhs.method() == rhs.method() && lhs.method_id() == rhs.method_id()
+}
+
+fn is_key_data<T>(method: &Method<T>) -> bool {
+  match method.key_type() {
+    MethodType::Ed25519VerificationKey2018 | MethodType::JcsEd25519Key2020 | MethodType::X25519KeyAgreementKey2019 => {
+      matches!(method.key_data().try_decode(), Ok(data) if data.len() == PUBLIC_KEY_LEN)
+    }
+    MethodType::MerkleKeyCollection2021 => method.key_data().try_decode().is_ok(),
+    MethodType::JwsVerificationKey2020 => matches!(method.key_data(), MethodData::PublicKeyJwk(_)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::document::DocumentBuilder;
+  use crate::service::Service;
+  use crate::verification::MethodBuilder;
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+
+  fn method(did: &DID, fragment: &str, data: MethodData) -> Method {
+    MethodBuilder::default()
+      .id(did.join(fragment).unwrap())
+      .controller(did.clone())
+      .key_type(MethodType::Ed25519VerificationKey2018)
+      .key_data(data)
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_conformant() {
+    let did: DID = "did:example:123".parse().unwrap();
+
+    let document: Document = DocumentBuilder::new(Object::new())
+      .id(did.clone())
+      .verification_method(method(&did, "#key-1", MethodData::new_b58([1; 32])))
+      .authentication(did.join("#key-1").unwrap())
+      .assertion_method("did:example:456#key-1".parse::<DID>().unwrap())
+      .build()
+      .unwrap();
+
+    assert!(document.is_conformant());
+  }
+
+  #[test]
+  fn test_violations() {
+    let did: DID = "did:example:123".parse().unwrap();
+
+    let service: Service = Service::builder(Object::new())
+      .id(did.join("#key-1").unwrap())
+      .type_("Example")
+      .service_endpoint(Url::parse("https://example.com").unwrap())
+      .build()
+      .unwrap();
+
+    let document: Document = DocumentBuilder::new(Object::new())
+      .id(did.clone())
+      .verification_method(method(&did, "#key-1", MethodData::new_b58([1; 32])))
+      .verification_method(method(&did, "#key-2", MethodData::new_b58([1; 16])))
+      .authentication(method(&did, "#key-2", MethodData::new_b58([1; 32])))
+      .assertion_method(did.join("#key-3").unwrap())
+      .service(service)
+      .build()
+      .unwrap();
+
+    let violations: Vec<Violation> = document.validate();
+
+    assert_eq!(
+      violations,
+      vec![
+        Violation::InvalidKeyData(did.join("#key-2").unwrap()),
+        Violation::DuplicateId(did.join("#key-2").unwrap()),
+        Violation::DuplicateId(did.join("#key-1").unwrap()),
+        Violation::MissingMethod(MethodScope::AssertionMethod, did.join("#key-3").unwrap()),
+      ]
+    );
+  }
+}
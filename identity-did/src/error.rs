@@ -15,6 +15,9 @@ pub enum Error {
   /// Caused by errors from the `did_url` crate.
   #[error("{0}")]
   DIDError(#[from] ::did_url::Error),
+  /// Caused by errors from the `serde_cbor` crate.
+  #[error("{0}")]
+  CborError(#[from] ::serde_cbor::Error),
 
   #[error("Duplicate Item in Ordered Set")]
   OrderedSetDuplicate,
@@ -48,6 +51,16 @@ pub enum Error {
   UnknownMethodType,
   #[error("Unknown Signature Type")]
   UnknownSignatureType,
+  #[error("Unknown Document Controller")]
+  UnknownController,
+  #[error("Invalid Method Scope")]
+  InvalidMethodScope,
+  #[error("Duplicate Verification Method")]
+  DuplicateMethod,
+  #[error("Cannot Remove Last Authentication Method")]
+  LastAuthenticationMethod,
+  #[error("Deactivated DID Document")]
+  DeactivatedDocument,
 
   #[error("Invalid Key Data")]
   InvalidKeyData,
@@ -70,4 +83,19 @@ pub enum Error {
   InvalidDIDFragment,
   #[error("Invalid DID Resolution Service")]
   InvalidServiceProtocol,
+  #[error("Unsupported DID URL Dereference")]
+  UnsupportedDereference,
+  #[error("Invalid DID Document Representation")]
+  InvalidRepresentation,
+  #[error("DID Resolver Method Error: {0}")]
+  ResolverMethodError(String),
+
+  #[error("Invalid Peer DID Numalgo")]
+  InvalidPeerNumalgo,
+  #[error("Invalid Peer DID Purpose")]
+  InvalidPeerPurpose,
+  #[error("Invalid Peer DID Key")]
+  InvalidPeerKey,
+  #[error("Invalid Peer DID Service")]
+  InvalidPeerService,
 }
@@ -24,8 +24,10 @@ pub use did_url as did;
 pub mod diff;
 pub mod document;
 pub mod error;
+pub mod peer;
 pub mod resolution;
 pub mod service;
+pub mod url;
 pub mod utils;
 pub mod verifiable;
 pub mod verification;
@@ -0,0 +1,104 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::peer::encoding::encode_key;
+use crate::peer::encoding::encode_service;
+use crate::peer::Numalgo;
+use crate::peer::PeerDID;
+use crate::peer::Purpose;
+use crate::service::Service;
+use crate::verification::MethodType;
+
+/// A `PeerDIDBuilder` is used to generate a numalgo 2 `PeerDID`.
+///
+/// Keys and services are encoded in the order they are added to the builder.
+#[derive(Clone, Debug, Default)]
+pub struct PeerDIDBuilder {
+  pub(crate) keys: Vec<(Purpose, MethodType, Vec<u8>)>,
+  pub(crate) service: Vec<Service>,
+}
+
+impl PeerDIDBuilder {
+  /// Creates a new `PeerDIDBuilder`.
+  pub fn new() -> Self {
+    Self {
+      keys: Vec::new(),
+      service: Vec::new(),
+    }
+  }
+
+  /// Adds a key to the `assertionMethod` set of the generated `PeerDID`.
+  #[must_use]
+  pub fn assertion_method(self, key_type: MethodType, public: &[u8]) -> Self {
+    self.key(Purpose::Assertion, key_type, public)
+  }
+
+  /// Adds a key to the `keyAgreement` set of the generated `PeerDID`.
+  #[must_use]
+  pub fn key_agreement(self, key_type: MethodType, public: &[u8]) -> Self {
+    self.key(Purpose::Encryption, key_type, public)
+  }
+
+  /// Adds a key to the `authentication` set of the generated `PeerDID`.
+  #[must_use]
+  pub fn authentication(self, key_type: MethodType, public: &[u8]) -> Self {
+    self.key(Purpose::Verification, key_type, public)
+  }
+
+  /// Adds a key to the `capabilityInvocation` set of the generated `PeerDID`.
+  #[must_use]
+  pub fn capability_invocation(self, key_type: MethodType, public: &[u8]) -> Self {
+    self.key(Purpose::CapabilityInvocation, key_type, public)
+  }
+
+  /// Adds a key to the `capabilityDelegation` set of the generated `PeerDID`.
+  #[must_use]
+  pub fn capability_delegation(self, key_type: MethodType, public: &[u8]) -> Self {
+    self.key(Purpose::CapabilityDelegation, key_type, public)
+  }
+
+  /// Adds a value to the `service` set of the generated `PeerDID`.
+  ///
+  /// Note: The `id` of the service is replaced when the `PeerDID` is expanded.
+  #[must_use]
+  pub fn service(mut self, value: Service) -> Self {
+    self.service.push(value);
+    self
+  }
+
+  /// Returns a new `PeerDID` based on the `PeerDIDBuilder` configuration.
+  pub fn build(self) -> Result<PeerDID> {
+    if self.keys.is_empty() {
+      return Err(Error::InvalidPeerKey);
+    }
+
+    let mut did: String = format!(
+      "{}:{}:{}",
+      DID::SCHEME,
+      PeerDID::METHOD,
+      Numalgo::MultipleInceptionKeys.as_char()
+    );
+
+    for (purpose, key_type, public) in self.keys.iter() {
+      did.push('.');
+      did.push(purpose.as_char());
+      did.push_str(&encode_key(*key_type, public)?);
+    }
+
+    for service in self.service.iter() {
+      did.push('.');
+      did.push(Purpose::Service.as_char());
+      did.push_str(&encode_service(service)?);
+    }
+
+    PeerDID::parse(did)
+  }
+
+  fn key(mut self, purpose: Purpose, key_type: MethodType, public: &[u8]) -> Self {
+    self.keys.push((purpose, key_type, public.to_vec()));
+    self
+  }
+}
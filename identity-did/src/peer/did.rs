@@ -0,0 +1,405 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::ops::Deref;
+use core::str::FromStr;
+use core::str::Split;
+
+use crate::did::Error as DIDError;
+use crate::did::DID;
+use crate::document::Document;
+use crate::document::DocumentBuilder;
+use crate::error::Error;
+use crate::error::Result;
+use crate::peer::encoding::decode_key;
+use crate::peer::encoding::decode_service;
+use crate::peer::encoding::encode_key;
+use crate::peer::PeerDIDBuilder;
+use crate::peer::Purpose;
+use crate::verification::Method;
+use crate::verification::MethodBuilder;
+use crate::verification::MethodData;
+use crate::verification::MethodType;
+
+/// The algorithm used to generate a peer DID.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Numalgo {
+  /// A DID derived from a single inception key, without a genesis document.
+  InceptionKey,
+  /// A DID encoding multiple inception keys and services, without a genesis document.
+  MultipleInceptionKeys,
+}
+
+impl Numalgo {
+  /// Returns the character identifying the `Numalgo` in a peer DID.
+  pub const fn as_char(self) -> char {
+    match self {
+      Self::InceptionKey => '0',
+      Self::MultipleInceptionKeys => '2',
+    }
+  }
+}
+
+/// A DID conforming to the `did:peer` method specification.
+///
+/// [Specification](https://identity.foundation/peer-did-method-spec/)
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[repr(transparent)]
+#[serde(into = "DID", try_from = "DID")]
+pub struct PeerDID(DID);
+
+impl PeerDID {
+  /// The DID method name.
+  pub const METHOD: &'static str = "peer";
+
+  /// Creates a new numalgo 0 `PeerDID` from the given inception key.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the key type is not supported or the key is invalid.
+  pub fn from_inception_key(key_type: MethodType, public: &[u8]) -> Result<Self> {
+    let key: String = encode_key(key_type, public)?;
+
+    Self::parse(format!(
+      "{}:{}:{}{}",
+      DID::SCHEME,
+      Self::METHOD,
+      Numalgo::InceptionKey.as_char(),
+      key
+    ))
+  }
+
+  /// Creates a `PeerDIDBuilder` to configure a new numalgo 2 `PeerDID`.
+  ///
+  /// This is the same as `PeerDIDBuilder::new()`.
+  pub fn builder() -> PeerDIDBuilder {
+    PeerDIDBuilder::new()
+  }
+
+  /// Converts a borrowed `DID` to a `PeerDID`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input is not a valid `PeerDID`.
+  pub fn try_from_borrowed(did: &DID) -> Result<&Self> {
+    Self::check_validity(did)?;
+
+    // SAFETY: we performed the necessary validation in `check_validity`.
+    Ok(unsafe { Self::new_unchecked_ref(did) })
+  }
+
+  /// Converts an owned `DID` to a `PeerDID`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input is not a valid `PeerDID`.
+  pub fn try_from_owned(did: DID) -> Result<Self> {
+    Self::check_validity(&did)?;
+
+    Ok(Self(did))
+  }
+
+  /// Converts a `DID` reference to a `PeerDID` reference without performing
+  /// validation checks.
+  ///
+  /// # Safety
+  ///
+  /// This must be guaranteed safe by the caller.
+  pub unsafe fn new_unchecked_ref(did: &DID) -> &Self {
+    // SAFETY: This is guaranteed safe by the caller.
+    &*(did as *const DID as *const PeerDID)
+  }
+
+  /// Parses a `PeerDID` from the given `input`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input is not a valid `PeerDID`.
+  pub fn parse(input: impl AsRef<str>) -> Result<Self> {
+    DID::parse(input).map_err(Into::into).and_then(Self::try_from_owned)
+  }
+
+  /// Checks if the given `DID` is a valid `PeerDID`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input is not a valid `PeerDID`.
+  pub fn check_validity(did: &DID) -> Result<()> {
+    if did.method() != Self::METHOD {
+      return Err(Error::DIDError(DIDError::InvalidMethodName));
+    }
+
+    Self::expand(did).map(|_| ())
+  }
+
+  /// Returns a `bool` indicating if the given `DID` is a valid `PeerDID`.
+  pub fn is_valid(did: &DID) -> bool {
+    Self::check_validity(did).is_ok()
+  }
+
+  /// Returns the algorithm used to generate the `PeerDID`.
+  pub fn numalgo(&self) -> Numalgo {
+    // We checked the validity of the numalgo in `check_validity`.
+    match self.method_id().chars().next() {
+      Some('0') => Numalgo::InceptionKey,
+      _ => Numalgo::MultipleInceptionKeys,
+    }
+  }
+
+  /// Returns the `PeerDID` without any path, query, or fragment components.
+  pub fn base(&self) -> Result<DID> {
+    format!("{}:{}:{}", DID::SCHEME, Self::METHOD, self.method_id())
+      .parse()
+      .map_err(Into::into)
+  }
+
+  /// Expands the `PeerDID` into a DID `Document`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the encoded keys or services are invalid.
+  pub fn to_document(&self) -> Result<Document> {
+    let base: DID = self.base()?;
+    let mut builder: DocumentBuilder = DocumentBuilder::default().id(base.clone());
+    let mut keys: usize = 0;
+    let mut services: usize = 0;
+
+    for element in Self::expand(self)? {
+      match element {
+        Element::Key(purpose, key_type, public) => {
+          keys += 1;
+
+          let id: DID = match self.numalgo() {
+            Numalgo::InceptionKey => base.join(format!("#{}", &self.method_id()[1..]))?,
+            Numalgo::MultipleInceptionKeys => base.join(format!("#key-{}", keys))?,
+          };
+
+          let method: Method = MethodBuilder::default()
+            .id(id.clone())
+            .controller(base.clone())
+            .key_type(key_type)
+            .key_data(MethodData::new_b58(public))
+            .build()?;
+
+          builder = builder.verification_method(method);
+
+          builder = match purpose {
+            // An inception key is used for all relationships supported by the key type.
+            None if key_type == MethodType::X25519KeyAgreementKey2019 => builder.key_agreement(id),
+            None => builder
+              .authentication(id.clone())
+              .assertion_method(id.clone())
+              .capability_invocation(id.clone())
+              .capability_delegation(id),
+            Some(Purpose::Assertion) => builder.assertion_method(id),
+            Some(Purpose::Encryption) => builder.key_agreement(id),
+            Some(Purpose::Verification) => builder.authentication(id),
+            Some(Purpose::CapabilityInvocation) => builder.capability_invocation(id),
+            Some(Purpose::CapabilityDelegation) => builder.capability_delegation(id),
+            Some(Purpose::Service) => unreachable!(),
+          };
+        }
+        Element::Service(data) => {
+          let id: DID = match services {
+            0 => base.join("#service")?,
+            index => base.join(format!("#service-{}", index))?,
+          };
+
+          services += 1;
+          builder = builder.service(decode_service(&data, id)?);
+        }
+      }
+    }
+
+    builder.build()
+  }
+
+  fn expand(did: &DID) -> Result<Vec<Element>> {
+    let method_id: &str = did.method_id();
+
+    match method_id.chars().next() {
+      Some('0') => {
+        let (key_type, public): (MethodType, Vec<u8>) = decode_key(&method_id[1..])?;
+
+        Ok(vec![Element::Key(None, key_type, public)])
+      }
+      Some('2') => {
+        let mut segments: Split<'_, char> = method_id.split('.');
+        let mut elements: Vec<Element> = Vec::new();
+
+        // The first segment contains the numalgo and nothing else.
+        if segments.next() != Some("2") {
+          return Err(Error::InvalidPeerNumalgo);
+        }
+
+        for segment in segments {
+          let purpose: Purpose = segment
+            .chars()
+            .next()
+            .ok_or(Error::InvalidPeerPurpose)
+            .and_then(Purpose::try_from)?;
+
+          let data: &str = &segment[1..];
+
+          if purpose == Purpose::Service {
+            elements.push(Element::Service(data.to_string()));
+          } else {
+            let (key_type, public): (MethodType, Vec<u8>) = decode_key(data)?;
+
+            elements.push(Element::Key(Some(purpose), key_type, public));
+          }
+        }
+
+        // A peer DID must encode at least one key.
+        if !elements.iter().any(|element| matches!(element, Element::Key(..))) {
+          return Err(Error::InvalidPeerKey);
+        }
+
+        Ok(elements)
+      }
+      _ => Err(Error::InvalidPeerNumalgo),
+    }
+  }
+}
+
+// An element encoded in the method-specific id of a peer DID.
+enum Element {
+  Key(Option<Purpose>, MethodType, Vec<u8>),
+  Service(String),
+}
+
+impl Display for PeerDID {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}", self.0)
+  }
+}
+
+impl Debug for PeerDID {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "{}", self.0)
+  }
+}
+
+impl Deref for PeerDID {
+  type Target = DID;
+
+  fn deref(&self) -> &Self::Target {
+    self.as_ref()
+  }
+}
+
+impl AsRef<DID> for PeerDID {
+  fn as_ref(&self) -> &DID {
+    &self.0
+  }
+}
+
+impl PartialEq<DID> for PeerDID {
+  fn eq(&self, other: &DID) -> bool {
+    self.0.eq(other)
+  }
+}
+
+impl From<PeerDID> for DID {
+  fn from(other: PeerDID) -> Self {
+    other.0
+  }
+}
+
+impl TryFrom<DID> for PeerDID {
+  type Error = Error;
+
+  fn try_from(other: DID) -> Result<Self, Self::Error> {
+    Self::try_from_owned(other)
+  }
+}
+
+impl<'a> TryFrom<&'a DID> for &'a PeerDID {
+  type Error = Error;
+
+  fn try_from(other: &'a DID) -> Result<Self, Self::Error> {
+    PeerDID::try_from_borrowed(other)
+  }
+}
+
+impl FromStr for PeerDID {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Self::parse(string)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+
+  use super::*;
+  use crate::service::Service;
+  use crate::verification::MethodScope;
+
+  const ED25519: [u8; 32] = [1; 32];
+  const X25519: [u8; 32] = [2; 32];
+
+  fn service() -> Service {
+    Service::builder(Object::new())
+      .id("did:example:123#service".parse().unwrap())
+      .type_("DIDCommMessaging")
+      .service_endpoint(Url::parse("https://example.com/endpoint").unwrap())
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_numalgo0() {
+    let did: PeerDID = PeerDID::from_inception_key(MethodType::Ed25519VerificationKey2018, &ED25519).unwrap();
+
+    assert!(did.as_str().starts_with("did:peer:0z6Mk"));
+    assert_eq!(did.numalgo(), Numalgo::InceptionKey);
+
+    let document: Document = did.to_document().unwrap();
+
+    assert_eq!(document.id(), &*did);
+    assert_eq!(document.verification_method().len(), 1);
+    assert_eq!(document.authentication().len(), 1);
+    assert!(document.key_agreement().is_empty());
+    assert_eq!(document.try_resolve_bytes(0).unwrap(), ED25519.to_vec());
+  }
+
+  #[test]
+  fn test_numalgo2() {
+    let did: PeerDID = PeerDID::builder()
+      .key_agreement(MethodType::X25519KeyAgreementKey2019, &X25519)
+      .authentication(MethodType::Ed25519VerificationKey2018, &ED25519)
+      .service(service())
+      .build()
+      .unwrap();
+
+    assert!(did.as_str().starts_with("did:peer:2.Ez6LS"));
+    assert_eq!(did.numalgo(), Numalgo::MultipleInceptionKeys);
+
+    let document: Document = did.to_document().unwrap();
+    let method = document.resolve(("#key-2", MethodScope::Authentication)).unwrap();
+
+    assert_eq!(method.key_type(), MethodType::Ed25519VerificationKey2018);
+    assert_eq!(method.key_data().try_decode().unwrap(), ED25519.to_vec());
+    assert_eq!(document.key_agreement().len(), 1);
+    assert_eq!(document.service().len(), 1);
+    assert_eq!(document.service()[0].id().fragment(), Some("service"));
+    assert_eq!(document.service()[0].type_(), "DIDCommMessaging");
+  }
+
+  #[test]
+  fn test_parse_invalid() {
+    assert!(PeerDID::parse("did:peer:1z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH").is_err());
+    assert!(PeerDID::parse("did:peer:2").is_err());
+    assert!(PeerDID::parse("did:peer:2.Xz6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH").is_err());
+    assert!(PeerDID::parse("did:example:0z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH").is_err());
+  }
+}
@@ -0,0 +1,267 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::SerdeInto;
+use identity_core::convert::ToJson;
+use identity_core::utils::decode_b58;
+use identity_core::utils::decode_b64_unpadded;
+use identity_core::utils::encode_b58;
+use identity_core::utils::encode_b64_unpadded;
+
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::service::Service;
+use crate::service::ServiceEndpoint;
+use crate::verification::MethodType;
+
+// The multibase prefix of base58-btc encoded data.
+const MULTIBASE_B58: char = 'z';
+
+// The varint-encoded multicodec prefixes of supported public keys.
+const CODEC_ED25519: [u8; 2] = [0xed, 0x01];
+const CODEC_X25519: [u8; 2] = [0xec, 0x01];
+
+// The size of supported public keys.
+const PUBLIC_KEY_LEN: usize = 32;
+
+// Abbreviations applied to the keys of an encoded service block.
+const SERVICE_KEYS: &[(&str, &str)] = &[
+  ("type", "t"),
+  ("serviceEndpoint", "s"),
+  ("routingKeys", "r"),
+  ("accept", "a"),
+];
+
+// Abbreviations applied to the values of an encoded service block.
+const SERVICE_VALUES: &[(&str, &str)] = &[("DIDCommMessaging", "dm")];
+
+/// Encodes a public key as a multibase/multicodec string.
+pub(crate) fn encode_key(key_type: MethodType, public: &[u8]) -> Result<String> {
+  let codec: &[u8] = match key_type {
+    MethodType::Ed25519VerificationKey2018 | MethodType::JcsEd25519Key2020 => &CODEC_ED25519,
+    MethodType::X25519KeyAgreementKey2019 => &CODEC_X25519,
+    _ => return Err(Error::InvalidPeerKey),
+  };
+
+  if public.len() != PUBLIC_KEY_LEN {
+    return Err(Error::InvalidPeerKey);
+  }
+
+  let mut data: Vec<u8> = Vec::with_capacity(codec.len() + public.len());
+
+  data.extend_from_slice(codec);
+  data.extend_from_slice(public);
+
+  Ok(format!("{}{}", MULTIBASE_B58, encode_b58(&data)))
+}
+
+/// Decodes a multibase/multicodec string as a public key.
+pub(crate) fn decode_key(data: &str) -> Result<(MethodType, Vec<u8>)> {
+  if !data.starts_with(MULTIBASE_B58) {
+    return Err(Error::InvalidPeerKey);
+  }
+
+  let data: Vec<u8> = decode_b58(&data[1..]).map_err(|_| Error::InvalidPeerKey)?;
+
+  let key_type: MethodType = if data.starts_with(&CODEC_ED25519) {
+    MethodType::Ed25519VerificationKey2018
+  } else if data.starts_with(&CODEC_X25519) {
+    MethodType::X25519KeyAgreementKey2019
+  } else {
+    return Err(Error::InvalidPeerKey);
+  };
+
+  // Both supported codecs have a prefix of the same size.
+  let public: &[u8] = &data[CODEC_ED25519.len()..];
+
+  if public.len() != PUBLIC_KEY_LEN {
+    return Err(Error::InvalidPeerKey);
+  }
+
+  Ok((key_type, public.to_vec()))
+}
+
+/// Encodes a `Service` as an abbreviated, base64url-encoded JSON object.
+///
+/// Note: The service `id` is not encoded; it is derived from the position of
+/// the service when the peer DID is expanded.
+pub(crate) fn encode_service(service: &Service) -> Result<String> {
+  let mut object: Object = service
+    .properties()
+    .iter()
+    .map(|(key, value)| (abbreviate(SERVICE_KEYS, key), value.clone()))
+    .collect();
+
+  object.insert("t".into(), abbreviate(SERVICE_VALUES, service.type_()).into());
+  object.insert("s".into(), encode_endpoint(service.service_endpoint())?);
+
+  object
+    .to_json_vec()
+    .map(|json| encode_b64_unpadded(&json))
+    .map_err(Into::into)
+}
+
+/// Decodes an abbreviated, base64url-encoded JSON object as a `Service`.
+pub(crate) fn decode_service(data: &str, id: DID) -> Result<Service> {
+  let json: Vec<u8> = decode_b64_unpadded(data).map_err(|_| Error::InvalidPeerService)?;
+  let mut object: Object = Object::from_json_slice(&json).map_err(|_| Error::InvalidPeerService)?;
+
+  let type_: String = match object.remove("t") {
+    Some(Value::String(value)) => expand(SERVICE_VALUES, &value),
+    _ => return Err(Error::InvalidPeerService),
+  };
+
+  let service_endpoint: ServiceEndpoint = match object.remove("s") {
+    Some(value) => decode_endpoint(value)?,
+    None => return Err(Error::InvalidPeerService),
+  };
+
+  let properties: Object = object
+    .into_iter()
+    .map(|(key, value)| (expand(SERVICE_KEYS, &key), value))
+    .collect();
+
+  Service::builder(properties)
+    .id(id)
+    .type_(type_)
+    .service_endpoint(service_endpoint)
+    .build()
+}
+
+// Encodes a service endpoint; the keys of map endpoints are abbreviated.
+fn encode_endpoint(endpoint: &ServiceEndpoint) -> Result<Value> {
+  match endpoint {
+    ServiceEndpoint::One(url) => Ok(url.to_string().into()),
+    ServiceEndpoint::Map(map) => Ok(Value::Object(
+      map
+        .iter()
+        .map(|(key, value)| (abbreviate(SERVICE_KEYS, key), value.clone()))
+        .collect(),
+    )),
+    ServiceEndpoint::Set(set) => set.iter().map(encode_endpoint).collect::<Result<_>>().map(Value::Array),
+  }
+}
+
+// Decodes a service endpoint; the keys of map endpoints are expanded.
+fn decode_endpoint(value: Value) -> Result<ServiceEndpoint> {
+  let value: Value = match value {
+    Value::Object(map) => Value::Object(
+      map
+        .into_iter()
+        .map(|(key, value)| (expand(SERVICE_KEYS, &key), value))
+        .collect(),
+    ),
+    Value::Array(set) => Value::Array(
+      set
+        .into_iter()
+        .map(|value| decode_endpoint(value).and_then(|endpoint| endpoint.serde_into().map_err(Into::into)))
+        .collect::<Result<_>>()?,
+    ),
+    value => value,
+  };
+
+  value.serde_into().map_err(|_| Error::InvalidPeerService)
+}
+
+fn abbreviate(table: &[(&str, &str)], value: &str) -> String {
+  table
+    .iter()
+    .find(|(long, _)| *long == value)
+    .map_or(value, |(_, short)| *short)
+    .to_string()
+}
+
+fn expand(table: &[(&str, &str)], value: &str) -> String {
+  table
+    .iter()
+    .find(|(_, short)| *short == value)
+    .map_or(value, |(long, _)| *long)
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use identity_core::common::Url;
+
+  #[test]
+  fn test_key_roundtrip() {
+    let public: [u8; 32] = [7; 32];
+
+    let encoded: String = encode_key(MethodType::Ed25519VerificationKey2018, &public).unwrap();
+    assert!(encoded.starts_with("z6Mk"));
+    assert_eq!(
+      decode_key(&encoded).unwrap(),
+      (MethodType::Ed25519VerificationKey2018, public.to_vec())
+    );
+
+    let encoded: String = encode_key(MethodType::X25519KeyAgreementKey2019, &public).unwrap();
+    assert!(encoded.starts_with("z6LS"));
+    assert_eq!(
+      decode_key(&encoded).unwrap(),
+      (MethodType::X25519KeyAgreementKey2019, public.to_vec())
+    );
+  }
+
+  #[test]
+  fn test_key_invalid() {
+    assert!(encode_key(MethodType::MerkleKeyCollection2021, &[7; 32]).is_err());
+    assert!(encode_key(MethodType::Ed25519VerificationKey2018, &[7; 16]).is_err());
+    assert!(decode_key("6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH").is_err());
+    assert!(decode_key("z111111").is_err());
+  }
+
+  #[test]
+  fn test_service_roundtrip() {
+    let mut properties: Object = Object::new();
+    properties.insert("routingKeys".into(), vec!["did:example:mediator#key-1"].into());
+
+    let id: DID = "did:peer:2#service".parse().unwrap();
+    let service: Service = Service::builder(properties)
+      .id(id.clone())
+      .type_("DIDCommMessaging")
+      .service_endpoint(Url::parse("https://example.com/endpoint").unwrap())
+      .build()
+      .unwrap();
+
+    let encoded: String = encode_service(&service).unwrap();
+    let json: Vec<u8> = decode_b64_unpadded(&encoded).unwrap();
+    let object: Object = Object::from_json_slice(&json).unwrap();
+
+    assert_eq!(object["t"], "dm");
+    assert_eq!(object["s"], "https://example.com/endpoint");
+    assert!(object.contains_key("r"));
+
+    assert_eq!(decode_service(&encoded, id).unwrap(), service);
+  }
+
+  #[test]
+  fn test_service_endpoint_map_roundtrip() {
+    let mut endpoint: Object = Object::new();
+    endpoint.insert("uri".into(), "https://example.com/endpoint".into());
+    endpoint.insert("accept".into(), vec!["didcomm/v2"].into());
+    endpoint.insert("routingKeys".into(), vec!["did:example:mediator#key-1"].into());
+
+    let id: DID = "did:peer:2#service".parse().unwrap();
+    let service: Service = Service::builder(Object::new())
+      .id(id.clone())
+      .type_("DIDCommMessaging")
+      .service_endpoint(endpoint)
+      .build()
+      .unwrap();
+
+    let encoded: String = encode_service(&service).unwrap();
+    let json: Vec<u8> = decode_b64_unpadded(&encoded).unwrap();
+    let object: Object = Object::from_json_slice(&json).unwrap();
+
+    assert_eq!(object["s"]["uri"], "https://example.com/endpoint");
+    assert_eq!(object["s"]["a"][0], "didcomm/v2");
+    assert_eq!(object["s"]["r"][0], "did:example:mediator#key-1");
+
+    assert_eq!(decode_service(&encoded, id).unwrap(), service);
+  }
+}
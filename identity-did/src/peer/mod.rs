@@ -0,0 +1,21 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types for creating and resolving `did:peer` DIDs.
+//!
+//! Numalgo 0 (a single inception key) and numalgo 2 (multiple inception keys
+//! and services) are supported.
+//!
+//! [Specification](https://identity.foundation/peer-did-method-spec/)
+
+mod builder;
+mod did;
+mod encoding;
+mod purpose;
+mod resolver;
+
+pub use self::builder::PeerDIDBuilder;
+pub use self::did::Numalgo;
+pub use self::did::PeerDID;
+pub use self::purpose::Purpose;
+pub use self::resolver::PeerResolver;
@@ -0,0 +1,54 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// The purpose code of an element encoded in a numalgo 2 peer DID.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Purpose {
+  /// A key used in the `assertionMethod` relationship.
+  Assertion,
+  /// A key used in the `keyAgreement` relationship.
+  Encryption,
+  /// A key used in the `authentication` relationship.
+  Verification,
+  /// A key used in the `capabilityInvocation` relationship.
+  CapabilityInvocation,
+  /// A key used in the `capabilityDelegation` relationship.
+  CapabilityDelegation,
+  /// An encoded service block.
+  Service,
+}
+
+impl Purpose {
+  /// Returns the single-character code of the `Purpose`.
+  pub const fn as_char(self) -> char {
+    match self {
+      Self::Assertion => 'A',
+      Self::Encryption => 'E',
+      Self::Verification => 'V',
+      Self::CapabilityInvocation => 'I',
+      Self::CapabilityDelegation => 'D',
+      Self::Service => 'S',
+    }
+  }
+}
+
+impl TryFrom<char> for Purpose {
+  type Error = Error;
+
+  fn try_from(other: char) -> Result<Self, Self::Error> {
+    match other {
+      'A' => Ok(Self::Assertion),
+      'E' => Ok(Self::Encryption),
+      'V' => Ok(Self::Verification),
+      'I' => Ok(Self::CapabilityInvocation),
+      'D' => Ok(Self::CapabilityDelegation),
+      'S' => Ok(Self::Service),
+      _ => Err(Error::InvalidPeerPurpose),
+    }
+  }
+}
@@ -0,0 +1,93 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use crate::did::DID;
+use crate::error::Result;
+use crate::peer::PeerDID;
+use crate::resolution::DocumentMetadata;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+
+/// A DID resolver for the `did:peer` method.
+///
+/// Peer DIDs are resolved by expanding the keys and services encoded in the
+/// DID; no network requests are performed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PeerResolver;
+
+impl PeerResolver {
+  /// Creates a new `PeerResolver`.
+  pub const fn new() -> Self {
+    Self
+  }
+}
+
+#[async_trait(?Send)]
+impl ResolverMethod for PeerResolver {
+  fn is_supported(&self, did: &DID) -> bool {
+    PeerDID::is_valid(did)
+  }
+
+  async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let did: &PeerDID = PeerDID::try_from_borrowed(did)?;
+
+    Ok(Some(MetaDocument {
+      data: did.to_document()?,
+      meta: DocumentMetadata::new(),
+    }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use futures::executor::block_on;
+
+  use super::*;
+  use crate::resolution::resolve;
+  use crate::resolution::Resolution;
+  use crate::verification::MethodType;
+
+  fn read(did: &PeerDID) -> MetaDocument {
+    block_on(PeerResolver::new().read(did, InputMetadata::new()))
+      .unwrap()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_read() {
+    let numalgo0: PeerDID = PeerDID::from_inception_key(MethodType::Ed25519VerificationKey2018, &[1; 32]).unwrap();
+    let numalgo2: PeerDID = PeerDID::builder()
+      .key_agreement(MethodType::X25519KeyAgreementKey2019, &[2; 32])
+      .authentication(MethodType::Ed25519VerificationKey2018, &[1; 32])
+      .build()
+      .unwrap();
+
+    for did in [numalgo0, numalgo2].iter() {
+      let document: MetaDocument = read(did);
+
+      assert!(PeerResolver::new().is_supported(did));
+      assert_eq!(document.data, did.to_document().unwrap());
+      assert_eq!(document.data.id(), &**did);
+      assert_eq!(document.meta, DocumentMetadata::new());
+
+      let resolution: Resolution = block_on(resolve(did.as_str(), InputMetadata::new(), PeerResolver::new())).unwrap();
+
+      assert_eq!(resolution.metadata.error, None);
+      assert_eq!(resolution.document, Some(document.data));
+      assert_eq!(resolution.document_metadata, Some(document.meta));
+    }
+  }
+
+  #[test]
+  fn test_read_invalid() {
+    let did: DID = "did:example:0z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH"
+      .parse()
+      .unwrap();
+
+    assert!(!PeerResolver::new().is_supported(&did));
+    assert!(block_on(PeerResolver::new().read(&did, InputMetadata::new())).is_err());
+  }
+}
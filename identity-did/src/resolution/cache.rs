@@ -0,0 +1,60 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+
+use crate::error::Result;
+use crate::resolution::MetaDocument;
+
+/// A resolved [`MetaDocument`] stored in a resolver cache.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CacheEntry {
+  /// The cached DID Document and associated metadata.
+  pub document: MetaDocument,
+  /// The timestamp at which the entry was stored.
+  pub cached: Timestamp,
+}
+
+impl CacheEntry {
+  /// Creates a new `CacheEntry` stored at the current time.
+  pub fn new(document: MetaDocument) -> Self {
+    Self {
+      document,
+      cached: Timestamp::now(),
+    }
+  }
+
+  /// Returns the age of the entry in seconds.
+  pub fn age(&self) -> u64 {
+    let age: i64 = Timestamp::now().to_unix() - self.cached.to_unix();
+
+    if age < 0 {
+      0
+    } else {
+      age as u64
+    }
+  }
+}
+
+/// A trait for persistent storage of resolver cache entries.
+///
+/// Entries are keyed by the DID URL used for resolution, without a fragment.
+///
+/// Note: All methods take `&self`; implementations are expected to handle
+/// synchronization internally.
+pub trait CacheStore {
+  /// Returns the entry stored for `key`, if any.
+  fn get(&self, key: &str) -> Result<Option<CacheEntry>>;
+
+  /// Stores `entry` for `key`, replacing any existing entry.
+  fn set(&self, key: &str, entry: &CacheEntry) -> Result<()>;
+
+  /// Returns the keys of all stored entries.
+  fn keys(&self) -> Result<Vec<String>>;
+
+  /// Removes the entry stored for `key`, if any.
+  fn remove(&self, key: &str) -> Result<()>;
+
+  /// Removes all stored entries.
+  fn clear(&self) -> Result<()>;
+}
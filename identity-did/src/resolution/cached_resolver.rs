@@ -0,0 +1,507 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+
+use crate::did::DID;
+use crate::error::Result;
+use crate::resolution::CacheEntry;
+use crate::resolution::CacheStore;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::PrimaryResource;
+use crate::resolution::ResolverMethod;
+
+type Store = Box<dyn CacheStore>;
+
+/// A [`ResolverMethod`] that caches the documents read by another
+/// [`ResolverMethod`].
+///
+/// Cached documents are returned with [`DocumentMetadata::cached`][crate::resolution::DocumentMetadata::cached]
+/// set to the time the document was stored. Resolution may bypass the cache
+/// by setting [`InputMetadata::no_cache`].
+pub struct CachedResolver<R> {
+  method: R,
+  ttl: Duration,
+  method_ttl: BTreeMap<String, Duration>,
+  capacity: usize,
+  store: Option<Store>,
+  entries: Mutex<BTreeMap<String, CacheEntry>>,
+}
+
+impl<R> CachedResolver<R> {
+  /// The default time-to-live of cached documents.
+  pub const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+  /// The default maximum number of documents held in memory.
+  pub const DEFAULT_CAPACITY: usize = 1024;
+
+  /// Creates a new `CachedResolver` with the default configuration.
+  pub fn new(method: R) -> Self {
+    Self::builder().build(method)
+  }
+
+  /// Creates a new [`CachedResolverBuilder`] to configure a `CachedResolver`.
+  pub fn builder() -> CachedResolverBuilder {
+    CachedResolverBuilder::new()
+  }
+
+  /// Returns a reference to the wrapped [`ResolverMethod`].
+  pub fn method(&self) -> &R {
+    &self.method
+  }
+
+  /// Returns the time-to-live of documents resolved with the given DID method.
+  pub fn ttl(&self, method: &str) -> Duration {
+    self.method_ttl.get(method).copied().unwrap_or(self.ttl)
+  }
+
+  /// Returns the number of documents held in memory.
+  pub fn len(&self) -> usize {
+    self.entries().len()
+  }
+
+  /// Returns `true` if no documents are held in memory.
+  pub fn is_empty(&self) -> bool {
+    self.entries().is_empty()
+  }
+
+  /// Removes all cached documents of the given `did`, regardless of the DID
+  /// URL path or query used to resolve them.
+  pub fn invalidate(&self, did: &DID) -> Result<()> {
+    let base: String = format!("{}:{}:{}", DID::SCHEME, did.method(), did.method_id());
+
+    self.entries().retain(|key, _| !Self::matches(&base, key));
+
+    if let Some(store) = self.store.as_deref() {
+      for key in store.keys()?.iter().filter(|key| Self::matches(&base, key)) {
+        store.remove(key)?;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Removes all cached documents.
+  pub fn clear(&self) -> Result<()> {
+    self.entries().clear();
+
+    if let Some(store) = self.store.as_deref() {
+      store.clear()?;
+    }
+
+    Ok(())
+  }
+
+  fn load(&self, key: &str, ttl: Duration) -> Result<Option<CacheEntry>> {
+    {
+      let mut entries: MutexGuard<'_, _> = self.entries();
+
+      match entries.get(key) {
+        Some(entry) if entry.age() < ttl.as_secs() => return Ok(Some(entry.clone())),
+        Some(_) => {
+          entries.remove(key);
+        }
+        None => {}
+      }
+    }
+
+    if let Some(store) = self.store.as_deref() {
+      match store.get(key)? {
+        Some(entry) if entry.age() < ttl.as_secs() => {
+          self.insert(key, entry.clone());
+          return Ok(Some(entry));
+        }
+        Some(_) => store.remove(key)?,
+        None => {}
+      }
+    }
+
+    Ok(None)
+  }
+
+  fn save(&self, key: &str, entry: CacheEntry) -> Result<()> {
+    if let Some(store) = self.store.as_deref() {
+      store.set(key, &entry)?;
+    }
+
+    self.insert(key, entry);
+
+    Ok(())
+  }
+
+  fn insert(&self, key: &str, entry: CacheEntry) {
+    if self.capacity == 0 {
+      return;
+    }
+
+    let mut entries: MutexGuard<'_, _> = self.entries();
+
+    // Evict the oldest entry to make room for a new key.
+    if !entries.contains_key(key) && entries.len() >= self.capacity {
+      let oldest: Option<String> = entries
+        .iter()
+        .min_by_key(|(_, entry)| entry.cached)
+        .map(|(key, _)| key.clone());
+
+      if let Some(oldest) = oldest {
+        entries.remove(&oldest);
+      }
+    }
+
+    entries.insert(key.to_string(), entry);
+  }
+
+  fn entries(&self) -> MutexGuard<'_, BTreeMap<String, CacheEntry>> {
+    self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+
+  fn matches(base: &str, key: &str) -> bool {
+    key.strip_prefix(base).map_or(false, |rest| {
+      rest.is_empty() || rest.starts_with('/') || rest.starts_with('?')
+    })
+  }
+
+  // Documents are keyed by the DID URL without query and fragment, with
+  // the requested version appended as a query.
+  fn key(did: &DID, input: &InputMetadata) -> String {
+    let mut did: DID = did.clone();
+    did.set_query(None);
+    did.set_fragment(None);
+
+    let mut key: String = did.to_string();
+
+    if let Some(version_id) = input.version_id.as_deref() {
+      key.push_str("?versionId=");
+      key.push_str(version_id);
+    } else if let Some(version_time) = input.version_time {
+      key.push_str("?versionTime=");
+      key.push_str(&version_time.to_rfc3339());
+    }
+
+    key
+  }
+}
+
+impl<R> Debug for CachedResolver<R>
+where
+  R: Debug,
+{
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_struct("CachedResolver")
+      .field("method", &self.method)
+      .field("ttl", &self.ttl)
+      .field("method_ttl", &self.method_ttl)
+      .field("capacity", &self.capacity)
+      .field("store", &self.store.is_some())
+      .finish()
+  }
+}
+
+#[async_trait(?Send)]
+impl<R> ResolverMethod for CachedResolver<R>
+where
+  R: ResolverMethod,
+{
+  fn is_supported(&self, did: &DID) -> bool {
+    self.method.is_supported(did)
+  }
+
+  async fn read(&self, did: &DID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let key: String = Self::key(did, &input);
+    let ttl: Duration = self.ttl(did.method());
+    let enabled: bool = ttl.as_secs() > 0;
+
+    if enabled && !input.no_cache.unwrap_or(false) {
+      if let Some(entry) = self.load(&key, ttl)? {
+        let mut document: MetaDocument = entry.document;
+        document.meta.cached = Some(entry.cached);
+        return Ok(Some(document));
+      }
+    }
+
+    let document: Option<MetaDocument> = self.method.read(did, input).await?;
+
+    if let Some(document) = document.as_ref().filter(|_| enabled) {
+      self.save(&key, CacheEntry::new(document.clone()))?;
+    }
+
+    Ok(document)
+  }
+
+  async fn dereference_url(&self, did: &DID, document: &MetaDocument) -> Result<Option<PrimaryResource>> {
+    self.method.dereference_url(did, document).await
+  }
+}
+
+// =============================================================================
+// Cached Resolver Builder
+// =============================================================================
+
+/// A `CachedResolverBuilder` is used to generate a customized `CachedResolver`.
+pub struct CachedResolverBuilder {
+  ttl: Duration,
+  method_ttl: BTreeMap<String, Duration>,
+  capacity: usize,
+  store: Option<Store>,
+}
+
+impl CachedResolverBuilder {
+  /// Creates a new `CachedResolverBuilder`.
+  pub fn new() -> Self {
+    Self {
+      ttl: CachedResolver::<()>::DEFAULT_TTL,
+      method_ttl: BTreeMap::new(),
+      capacity: CachedResolver::<()>::DEFAULT_CAPACITY,
+      store: None,
+    }
+  }
+
+  /// Sets the default time-to-live of cached documents.
+  ///
+  /// Note: Documents are cached with a precision of one second; a zero
+  /// duration disables caching.
+  #[must_use]
+  pub fn ttl(mut self, value: Duration) -> Self {
+    self.ttl = value;
+    self
+  }
+
+  /// Sets the time-to-live of documents resolved with the given DID method.
+  #[must_use]
+  pub fn method_ttl(mut self, method: impl Into<String>, value: Duration) -> Self {
+    self.method_ttl.insert(method.into(), value);
+    self
+  }
+
+  /// Sets the maximum number of documents held in memory.
+  #[must_use]
+  pub fn capacity(mut self, value: usize) -> Self {
+    self.capacity = value;
+    self
+  }
+
+  /// Sets a persistent [`CacheStore`] used in addition to the in-memory cache.
+  #[must_use]
+  pub fn store<S>(mut self, value: S) -> Self
+  where
+    S: CacheStore + 'static,
+  {
+    self.store = Some(Box::new(value));
+    self
+  }
+
+  /// Returns a new `CachedResolver` wrapping `method`, based on the
+  /// `CachedResolverBuilder` configuration.
+  pub fn build<R>(self, method: R) -> CachedResolver<R> {
+    CachedResolver {
+      method,
+      ttl: self.ttl,
+      method_ttl: self.method_ttl,
+      capacity: self.capacity,
+      store: self.store,
+      entries: Mutex::new(BTreeMap::new()),
+    }
+  }
+}
+
+impl Default for CachedResolverBuilder {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Debug for CachedResolverBuilder {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_struct("CachedResolverBuilder")
+      .field("ttl", &self.ttl)
+      .field("method_ttl", &self.method_ttl)
+      .field("capacity", &self.capacity)
+      .field("store", &self.store.is_some())
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::cell::Cell;
+  use futures::executor::block_on;
+  use std::sync::Arc;
+
+  use super::*;
+  use crate::peer::PeerDID;
+  use crate::resolution::resolve;
+  use crate::resolution::DocumentMetadata;
+  use crate::resolution::Resolution;
+  use crate::verification::MethodType;
+
+  // Returns the same document for every DID and counts the reads.
+  struct Counter(MetaDocument, Cell<usize>);
+
+  #[async_trait(?Send)]
+  impl ResolverMethod for Counter {
+    fn is_supported(&self, did: &DID) -> bool {
+      did.method() == PeerDID::METHOD
+    }
+
+    async fn read(&self, _did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+      self.1.set(self.1.get() + 1);
+      Ok(Some(self.0.clone()))
+    }
+  }
+
+  #[derive(Clone, Default)]
+  struct MemoryStore(Arc<Mutex<BTreeMap<String, CacheEntry>>>);
+
+  impl MemoryStore {
+    fn entries(&self) -> MutexGuard<'_, BTreeMap<String, CacheEntry>> {
+      self.0.lock().unwrap()
+    }
+  }
+
+  impl CacheStore for MemoryStore {
+    fn get(&self, key: &str) -> Result<Option<CacheEntry>> {
+      Ok(self.entries().get(key).cloned())
+    }
+
+    fn set(&self, key: &str, entry: &CacheEntry) -> Result<()> {
+      self.entries().insert(key.to_string(), entry.clone());
+      Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<String>> {
+      Ok(self.entries().keys().cloned().collect())
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+      self.entries().remove(key);
+      Ok(())
+    }
+
+    fn clear(&self) -> Result<()> {
+      self.entries().clear();
+      Ok(())
+    }
+  }
+
+  fn counter(seed: u8) -> (DID, Counter) {
+    let did: PeerDID = PeerDID::from_inception_key(MethodType::Ed25519VerificationKey2018, &[seed; 32]).unwrap();
+
+    let document: MetaDocument = MetaDocument {
+      data: did.to_document().unwrap(),
+      meta: DocumentMetadata::new(),
+    };
+
+    (did.into(), Counter(document, Cell::new(0)))
+  }
+
+  fn read<R: ResolverMethod>(resolver: &R, did: &DID, input: InputMetadata) -> MetaDocument {
+    block_on(resolver.read(did, input)).unwrap().unwrap()
+  }
+
+  #[test]
+  fn test_read_cached() {
+    let (did, method): (DID, Counter) = counter(1);
+    let resolver: CachedResolver<Counter> = CachedResolver::new(method);
+
+    let document: MetaDocument = read(&resolver, &did, InputMetadata::new());
+    assert!(document.meta.cached.is_none());
+    assert_eq!(resolver.method().1.get(), 1);
+
+    // The fragment is not part of the cache key.
+    let document: MetaDocument = read(&resolver, &did.join("#key-1").unwrap(), InputMetadata::new());
+    assert!(document.meta.cached.is_some());
+    assert_eq!(resolver.method().1.get(), 1);
+    assert_eq!(resolver.len(), 1);
+
+    let mut input: InputMetadata = InputMetadata::new();
+    input.no_cache = Some(true);
+
+    let document: MetaDocument = read(&resolver, &did, input);
+    assert!(document.meta.cached.is_none());
+    assert_eq!(resolver.method().1.get(), 2);
+  }
+
+  #[test]
+  fn test_resolve_cached() {
+    let (did, method): (DID, Counter) = counter(1);
+    let resolver: CachedResolver<Counter> = CachedResolver::new(method);
+
+    let resolution: Resolution = block_on(resolve(did.as_str(), InputMetadata::new(), &resolver)).unwrap();
+    assert!(!resolution.metadata.cached);
+    assert!(resolution.document_metadata.unwrap().cached.is_none());
+
+    let resolution: Resolution = block_on(resolve(did.as_str(), InputMetadata::new(), &resolver)).unwrap();
+    assert!(resolution.metadata.cached);
+    assert!(resolution.document_metadata.unwrap().cached.is_some());
+    assert_eq!(resolver.method().1.get(), 1);
+  }
+
+  #[test]
+  fn test_invalidate() {
+    let (did, method): (DID, Counter) = counter(1);
+    let (other, _): (DID, Counter) = counter(2);
+    let store: MemoryStore = MemoryStore::default();
+    let resolver: CachedResolver<Counter> = CachedResolver::builder().store(store.clone()).build(method);
+
+    read(&resolver, &did, InputMetadata::new());
+    read(
+      &resolver,
+      &format!("{}/path", did).parse().unwrap(),
+      InputMetadata::new(),
+    );
+    read(&resolver, &other, InputMetadata::new());
+    assert_eq!(store.keys().unwrap().len(), 3);
+
+    // Entries only held by the persistent store are invalidated as well.
+    let resolver: CachedResolver<Counter> = CachedResolver::builder().store(store.clone()).build(counter(1).1);
+
+    resolver.invalidate(&did).unwrap();
+    assert_eq!(store.keys().unwrap(), vec![other.to_string()]);
+
+    let document: MetaDocument = read(&resolver, &did, InputMetadata::new());
+    assert!(document.meta.cached.is_none());
+    assert_eq!(resolver.method().1.get(), 1);
+
+    let document: MetaDocument = read(&resolver, &other, InputMetadata::new());
+    assert!(document.meta.cached.is_some());
+    assert_eq!(resolver.method().1.get(), 1);
+  }
+
+  #[test]
+  fn test_ttl() {
+    let (did, method): (DID, Counter) = counter(1);
+    let resolver: CachedResolver<Counter> = CachedResolver::builder()
+      .ttl(Duration::from_secs(60))
+      .method_ttl(PeerDID::METHOD, Duration::from_secs(0))
+      .build(method);
+
+    assert_eq!(resolver.ttl("example"), Duration::from_secs(60));
+    assert_eq!(resolver.ttl(PeerDID::METHOD), Duration::from_secs(0));
+
+    read(&resolver, &did, InputMetadata::new());
+    read(&resolver, &did, InputMetadata::new());
+    assert_eq!(resolver.method().1.get(), 2);
+    assert!(resolver.is_empty());
+  }
+
+  #[test]
+  fn test_capacity() {
+    let (_, method): (DID, Counter) = counter(1);
+    let resolver: CachedResolver<Counter> = CachedResolver::builder().capacity(2).build(method);
+
+    for seed in 1..=3 {
+      read(&resolver, &counter(seed).0, InputMetadata::new());
+    }
+
+    assert_eq!(resolver.len(), 2);
+  }
+}
@@ -0,0 +1,269 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::crypto::SigName;
+use identity_core::crypto::SigVerify;
+use identity_core::crypto::Signature;
+use identity_core::crypto::TrySignature;
+use serde::Serialize;
+
+use crate::did::DID;
+use crate::document::Document;
+use crate::error::Error;
+use crate::error::Result;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::ResolverMethod;
+use crate::verifiable::LdSuite;
+use crate::verifiable::ResolveMethod;
+use crate::verification::MethodQuery;
+use crate::verification::MethodScope;
+use crate::verification::MethodWrap;
+
+/// Verifies the `proof` of `message` with the `authentication` methods of the
+/// controllers of `document`.
+///
+/// The controller referenced by the `verificationMethod` of the proof is
+/// resolved with the given DID method; a proof created by any controller is
+/// accepted.
+///
+/// Returns the DID of the controller that created the proof.
+///
+/// # Errors
+///
+/// Fails if the proof was not created by a controller of `document`, the
+/// controller cannot be resolved, or the proof is invalid.
+pub async fn verify_controller<T, S, R, A, B, C>(
+  message: &T,
+  document: &Document<A, B, C>,
+  suite: LdSuite<S>,
+  method: R,
+) -> Result<DID>
+where
+  T: Serialize + TrySignature,
+  S: SigVerify + SigName,
+  R: ResolverMethod,
+{
+  let controller: DID = find_controller(message, document)?;
+
+  let resolved: MetaDocument = method
+    .read(&controller, InputMetadata::new())
+    .await?
+    .ok_or(Error::MissingResolutionDocument)?;
+
+  suite.verify_data(message, Authentication(&resolved))?;
+
+  Ok(controller)
+}
+
+// Returns the controller of `document` referenced by the `verificationMethod`
+// of the `message` proof.
+fn find_controller<T, A, B, C>(message: &T, document: &Document<A, B, C>) -> Result<DID>
+where
+  T: TrySignature,
+{
+  let signature: &Signature = message.try_signature()?;
+  let method: DID = signature.verification_method.parse()?;
+
+  document
+    .controllers()
+    .find(|controller| controller.method() == method.method() && controller.method_id() == method.method_id())
+    .cloned()
+    .ok_or(Error::UnknownController)
+}
+
+// Resolves verification methods from the `authentication` relationship of a
+// controller document, regardless of the proof purpose.
+struct Authentication<'a>(&'a MetaDocument);
+
+impl ResolveMethod<Object> for Authentication<'_> {
+  fn resolve_method(&self, query: MethodQuery<'_>) -> Option<MethodWrap<'_>> {
+    self
+      .0
+      .resolve_method(MethodQuery::with_scope(query.ident, MethodScope::Authentication))
+  }
+
+  fn is_deactivated(&self) -> bool {
+    self.0.is_deactivated()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use async_trait::async_trait;
+  use futures::executor::block_on;
+
+  use super::*;
+  use crate::document::DocumentBuilder;
+  use crate::resolution::DocumentMetadata;
+  use crate::verifiable::Properties;
+  use crate::verification::Method;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+  use crate::verification::MethodType;
+  use identity_core::crypto::JcsEd25519Signature2020;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::SignatureOptions;
+
+  // Resolves the given controller documents by their DID.
+  struct Controllers(Vec<MetaDocument>);
+
+  #[async_trait(?Send)]
+  impl ResolverMethod for Controllers {
+    fn is_supported(&self, did: &DID) -> bool {
+      did.method() == "example"
+    }
+
+    async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+      Ok(self.0.iter().find(|document| document.data.id() == did).cloned())
+    }
+  }
+
+  fn organization(controllers: &[&DID]) -> Document {
+    let mut builder: DocumentBuilder<Object> =
+      DocumentBuilder::new(Object::new()).id("did:example:organization".parse().unwrap());
+
+    for controller in controllers {
+      builder = builder.controller((*controller).clone());
+    }
+
+    builder.build().unwrap()
+  }
+
+  fn message(signer: &DID, keypair: &KeyPair) -> Document<Properties> {
+    let mut message: Document<Properties> = DocumentBuilder::new(Properties::new(Object::new()))
+      .id("did:example:message".parse().unwrap())
+      .build()
+      .unwrap();
+
+    let options: SignatureOptions = SignatureOptions::new(signer.join("#key-1").unwrap().to_string());
+
+    LdSuite::new(JcsEd25519Signature2020)
+      .sign(&mut message, options, keypair.secret())
+      .unwrap();
+
+    message
+  }
+
+  fn controller(did: &DID, keypair: &KeyPair) -> MetaDocument {
+    let method: Method = MethodBuilder::default()
+      .id(did.join("#key-1").unwrap())
+      .controller(did.clone())
+      .key_type(MethodType::Ed25519VerificationKey2018)
+      .key_data(MethodData::new_b58(keypair.public()))
+      .build()
+      .unwrap();
+
+    MetaDocument {
+      data: DocumentBuilder::new(Object::new())
+        .id(did.clone())
+        .authentication(method)
+        .build()
+        .unwrap(),
+      meta: DocumentMetadata::new(),
+    }
+  }
+
+  #[test]
+  fn test_controller_authentication() {
+    let officer_1: DID = "did:example:officer-1".parse().unwrap();
+    let officer_2: DID = "did:example:officer-2".parse().unwrap();
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let organization: Document = DocumentBuilder::new(Object::new())
+      .id("did:example:organization".parse().unwrap())
+      .controller(officer_1.clone())
+      .controller(officer_2.clone())
+      .build()
+      .unwrap();
+
+    let mut message: Document<Properties> = DocumentBuilder::new(Properties::new(Object::new()))
+      .id("did:example:message".parse().unwrap())
+      .build()
+      .unwrap();
+
+    let options: SignatureOptions = SignatureOptions::new(officer_2.join("#key-1").unwrap().to_string());
+    let suite: LdSuite<_> = LdSuite::new(JcsEd25519Signature2020);
+
+    suite.sign(&mut message, options, keypair.secret()).unwrap();
+
+    assert_eq!(find_controller(&message, &organization).unwrap(), officer_2);
+    assert!(suite
+      .verify_data(&message, Authentication(&controller(&officer_2, &keypair)))
+      .is_ok());
+
+    let other: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    assert!(suite
+      .verify_data(&message, Authentication(&controller(&officer_2, &other)))
+      .is_err());
+
+    message.proof_mut().unwrap().verification_method = "did:example:officer-3#key-1".into();
+
+    assert!(matches!(
+      find_controller(&message, &organization),
+      Err(Error::UnknownController)
+    ));
+  }
+
+  #[test]
+  fn test_verify_controller() {
+    let officer_1: DID = "did:example:officer-1".parse().unwrap();
+    let officer_2: DID = "did:example:officer-2".parse().unwrap();
+    let keypair_1: KeyPair = KeyPair::new_ed25519().unwrap();
+    let keypair_2: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let organization: Document = organization(&[&officer_1, &officer_2]);
+    let resolver: Controllers = Controllers(vec![
+      controller(&officer_1, &keypair_1),
+      controller(&officer_2, &keypair_2),
+    ]);
+
+    for (officer, keypair) in [(&officer_1, &keypair_1), (&officer_2, &keypair_2)].iter() {
+      let message: Document<Properties> = message(officer, keypair);
+      let suite: LdSuite<_> = LdSuite::new(JcsEd25519Signature2020);
+
+      assert_eq!(
+        block_on(verify_controller(&message, &organization, suite, &resolver)).unwrap(),
+        **officer
+      );
+    }
+
+    // A proof by a controller with a key it does not hold
+    let message: Document<Properties> = message(&officer_2, &keypair_1);
+    let suite: LdSuite<_> = LdSuite::new(JcsEd25519Signature2020);
+
+    assert!(block_on(verify_controller(&message, &organization, suite, &resolver)).is_err());
+  }
+
+  #[test]
+  fn test_verify_non_controller() {
+    let officer: DID = "did:example:officer".parse().unwrap();
+    let outsider: DID = "did:example:outsider".parse().unwrap();
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let organization: Document = organization(&[&officer]);
+    let resolver: Controllers = Controllers(vec![controller(&officer, &keypair), controller(&outsider, &keypair)]);
+
+    // The outsider can be resolved and signed with a valid key, but is not a
+    // controller of the organization
+    let message: Document<Properties> = message(&outsider, &keypair);
+    let suite: LdSuite<_> = LdSuite::new(JcsEd25519Signature2020);
+
+    assert!(matches!(
+      block_on(verify_controller(&message, &organization, suite, &resolver)),
+      Err(Error::UnknownController)
+    ));
+
+    // A controller that cannot be resolved
+    let unresolved: Document = self::organization(&[&officer, &outsider]);
+    let resolver: Controllers = Controllers(vec![controller(&officer, &keypair)]);
+    let suite: LdSuite<_> = LdSuite::new(JcsEd25519Signature2020);
+
+    assert!(matches!(
+      block_on(verify_controller(&message, &unresolved, suite, &resolver)),
+      Err(Error::MissingResolutionDocument)
+    ));
+  }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::resolution::DocumentMetadata;
+use crate::resolution::ErrorKind;
 use crate::resolution::ResolutionMetadata;
 use crate::resolution::Resource;
 
@@ -30,4 +31,24 @@ impl Dereference {
       content_metadata: None,
     }
   }
+
+  /// Returns the HTTP status code of the dereferencing result, as used by DID
+  /// resolver servers.
+  ///
+  /// [More Info](https://w3c-ccg.github.io/did-resolution/#bindings-https)
+  pub fn http_status(&self) -> u16 {
+    match self.metadata.error {
+      Some(error) => error.http_status(),
+      None if self.is_deactivated() => ErrorKind::Deactivated.http_status(),
+      None => 200,
+    }
+  }
+
+  fn is_deactivated(&self) -> bool {
+    self
+      .content_metadata
+      .as_ref()
+      .and_then(|metadata| metadata.deactivated)
+      .unwrap_or(false)
+  }
 }
@@ -17,6 +17,26 @@ pub struct DocumentMetadata {
   /// [More Info](https://www.w3.org/TR/did-spec-registries/#updated)
   #[serde(skip_serializing_if = "Option::is_none")]
   pub updated: Option<Timestamp>,
+  /// The version of the resolved document.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-core/#dfn-versionid)
+  #[serde(rename = "versionId", skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// The version of the next update of the resolved document, if any.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-core/#dfn-nextversionid)
+  #[serde(rename = "nextVersionId", skip_serializing_if = "Option::is_none")]
+  pub next_version_id: Option<String>,
+  /// Indicates whether the DID has been deactivated.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-spec-registries/#deactivated)
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub deactivated: Option<bool>,
+  /// The timestamp at which the document was stored in a resolver cache.
+  ///
+  /// Note: This is only set when the document was served from a cache.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cached: Option<Timestamp>,
   /// Additional document metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -28,6 +48,10 @@ impl DocumentMetadata {
     Self {
       created: None,
       updated: None,
+      version_id: None,
+      next_version_id: None,
+      deactivated: None,
+      cached: None,
       properties: Object::new(),
     }
   }
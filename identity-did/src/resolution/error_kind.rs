@@ -1,20 +1,142 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::error::Error;
+
 /// Types of errors that be returned from a [DID resolution][SPEC] process.
 ///
-/// [SPEC]: https://www.w3.org/TR/did-core/#dfn-did-resolution
+/// Errors are serialized with the names of the DID Resolution spec; the
+/// kebab-case names of earlier versions are still accepted when deserializing.
+///
+/// [SPEC]: https://w3c-ccg.github.io/did-resolution/#errors
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 pub enum ErrorKind {
   /// The DID supplied to the DID resolution function does not conform to
   /// valid syntax.
-  #[serde(rename = "invalid-did")]
+  #[serde(rename = "invalidDid", alias = "invalid-did")]
   InvalidDID,
-  /// The DID resolver does not support the specified method.
-  #[serde(rename = "not-supported")]
-  NotSupported,
+  /// The DID URL supplied to the DID URL dereferencing function does not
+  /// conform to valid syntax.
+  #[serde(rename = "invalidDidUrl")]
+  InvalidDIDUrl,
   /// The DID resolver was unable to return the DID document resulting from
-  /// this resolution request.
-  #[serde(rename = "not-found")]
+  /// this resolution request, or the resource identified by a DID URL.
+  #[serde(rename = "notFound", alias = "not-found")]
   NotFound,
+  /// The representation requested via the `accept` input metadata property is
+  /// not supported by the DID resolver.
+  #[serde(rename = "representationNotSupported")]
+  RepresentationNotSupported,
+  /// The DID has been deactivated.
+  #[serde(rename = "deactivated")]
+  Deactivated,
+  /// The DID resolver does not support the specified method.
+  #[serde(rename = "methodNotSupported", alias = "not-supported")]
+  MethodNotSupported,
+  /// The `proof` of the resolved DID document is missing or invalid and
+  /// verification was required.
+  #[serde(rename = "invalidSignature")]
+  InvalidSignature,
+  /// An unexpected error occurred during the resolution process.
+  #[serde(rename = "internalError")]
+  InternalError,
+}
+
+impl ErrorKind {
+  /// Returns the name of the error as defined by the DID Resolution spec.
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::InvalidDID => "invalidDid",
+      Self::InvalidDIDUrl => "invalidDidUrl",
+      Self::NotFound => "notFound",
+      Self::RepresentationNotSupported => "representationNotSupported",
+      Self::Deactivated => "deactivated",
+      Self::MethodNotSupported => "methodNotSupported",
+      Self::InvalidSignature => "invalidSignature",
+      Self::InternalError => "internalError",
+    }
+  }
+
+  /// Returns the HTTP status code of the error, as used by DID resolver
+  /// servers.
+  ///
+  /// [More Info](https://w3c-ccg.github.io/did-resolution/#bindings-https)
+  pub const fn http_status(self) -> u16 {
+    match self {
+      Self::InvalidDID => 400,
+      Self::InvalidDIDUrl => 400,
+      Self::NotFound => 404,
+      Self::RepresentationNotSupported => 406,
+      Self::Deactivated => 410,
+      Self::MethodNotSupported => 501,
+      Self::InvalidSignature => 422,
+      Self::InternalError => 500,
+    }
+  }
+}
+
+impl<'a> From<&'a Error> for ErrorKind {
+  fn from(other: &'a Error) -> Self {
+    match other {
+      Error::DIDError(_) | Error::MissingResolutionDID => Self::InvalidDID,
+      Error::InvalidDIDQuery
+      | Error::InvalidDIDFragment
+      | Error::InvalidServiceProtocol
+      | Error::InvalidServiceEndpoint => Self::InvalidDIDUrl,
+      Error::MissingResolutionDocument | Error::UnsupportedDereference => Self::NotFound,
+      Error::DeactivatedDocument => Self::Deactivated,
+      _ => Self::InternalError,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  #[test]
+  fn test_serde() {
+    for kind in [
+      ErrorKind::InvalidDID,
+      ErrorKind::InvalidDIDUrl,
+      ErrorKind::NotFound,
+      ErrorKind::RepresentationNotSupported,
+      ErrorKind::Deactivated,
+      ErrorKind::MethodNotSupported,
+      ErrorKind::InvalidSignature,
+      ErrorKind::InternalError,
+    ]
+    .iter()
+    {
+      let json: String = kind.to_json().unwrap();
+
+      assert_eq!(json, format!("{:?}", kind.as_str()));
+      assert_eq!(ErrorKind::from_json(&json).unwrap(), *kind);
+    }
+  }
+
+  #[test]
+  fn test_serde_legacy_names() {
+    assert_eq!(ErrorKind::from_json(r#""invalid-did""#).unwrap(), ErrorKind::InvalidDID);
+    assert_eq!(ErrorKind::from_json(r#""not-found""#).unwrap(), ErrorKind::NotFound);
+    assert_eq!(
+      ErrorKind::from_json(r#""not-supported""#).unwrap(),
+      ErrorKind::MethodNotSupported
+    );
+    assert_eq!(ErrorKind::NotFound.to_json().unwrap(), r#""notFound""#);
+  }
+
+  #[test]
+  fn test_from_error() {
+    assert_eq!(ErrorKind::from(&Error::MissingResolutionDocument), ErrorKind::NotFound);
+    assert_eq!(ErrorKind::from(&Error::UnsupportedDereference), ErrorKind::NotFound);
+    assert_eq!(ErrorKind::from(&Error::InvalidDIDQuery), ErrorKind::InvalidDIDUrl);
+    assert_eq!(ErrorKind::from(&Error::QueryMethodNotFound), ErrorKind::InternalError);
+    assert_eq!(
+      ErrorKind::from(&Error::ResolverMethodError("Node Unavailable".into())),
+      ErrorKind::InternalError
+    );
+  }
 }
@@ -1,7 +1,12 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::convert::TryInto;
 use identity_core::common::Url;
+use identity_core::convert::SerdeInto;
+use identity_core::crypto::EddsaJcs2022;
+use identity_core::crypto::JcsEd25519Signature2020;
+use identity_core::crypto::Signature;
 use std::time::Instant;
 
 use crate::did::DID;
@@ -14,12 +19,19 @@ use crate::resolution::ErrorKind;
 use crate::resolution::InputMetadata;
 use crate::resolution::MetaDocument;
 use crate::resolution::PrimaryResource;
+use crate::resolution::Representation;
 use crate::resolution::Resolution;
 use crate::resolution::ResolverMethod;
 use crate::resolution::Resource;
 use crate::resolution::SecondaryResource;
+use crate::resolution::VerificationMode;
+use crate::service::ServiceEndpoint;
+use crate::url::DIDParameters;
 use crate::utils::DIDKey;
 use crate::utils::OrderedSet;
+use crate::verifiable::LdSuite;
+use crate::verifiable::Properties as VerifiableProperties;
+use crate::verification::MethodQuery;
 
 /// Resolves a DID into a DID Document by using the "Read" operation of the DID method.
 ///
@@ -41,14 +53,34 @@ where
   // 2. Determine if the input DID method is supported by the DID resolver
   //    that implements this algorithm.
   if !method.is_supported(&did) {
-    return Ok(context.finish_error(ErrorKind::NotSupported));
+    return Ok(context.finish_error(ErrorKind::MethodNotSupported));
   }
 
+  // Validate that the representation requested via the `accept` input
+  // metadata property is supported.
+  let representation: Option<Representation> = match input.accept.as_deref() {
+    Some(accept) => match Representation::from_content_type(accept) {
+      Some(representation) => Some(representation),
+      None => return Ok(context.finish_error(ErrorKind::RepresentationNotSupported)),
+    },
+    None => None,
+  };
+
+  // Apply the `versionId` and `versionTime` DID parameters to the input
+  // metadata - Explicit input metadata takes precedence.
+  let input: InputMetadata = match version_input(&did, input) {
+    Ok(input) => input,
+    Err(_) => return Ok(context.finish_error(ErrorKind::InvalidDIDUrl)),
+  };
+
+  let verify: Option<VerificationMode> = input.verify;
+
   // 3. Obtain the DID document for the input DID by executing the Read
   //    operation against the input DID's verifiable data registry.
-  let doc: MetaDocument = match method.read(&did, input).await? {
-    Some(doc) => doc,
-    None => return Ok(context.finish_error(ErrorKind::NotFound)),
+  let doc: MetaDocument = match method.read(&did, input).await {
+    Ok(Some(doc)) => doc,
+    Ok(None) => return Ok(context.finish_error(ErrorKind::NotFound)),
+    Err(error) => return Ok(context.finish_error((&error).into())),
   };
 
   // 4. Validate that the output DID document conforms to a conformant
@@ -57,16 +89,79 @@ where
   //   return Ok(context.finish_error(ErrorKind::InvalidDID));
   // }
 
-  // TODO: Handle deactivated DIDs
-  // TODO: Handle signature verification
+  // 5. If the input DID has been deactivated, the document metadata MUST
+  //    contain a `deactivated` property with the value `true`.
+  let mut meta: DocumentMetadata = doc.meta.clone();
+
+  if meta.deactivated != Some(true) {
+    meta.deactivated = None;
+  }
+
+  // 6. Verify the `proof` of the resolved DID document, if requested.
+  if let Some(verify) = verify {
+    match verify_document(&doc) {
+      Ok(Some(method)) => context.set_verified(true, Some(method)),
+      Ok(None) if verify == VerificationMode::Optional => {}
+      Err(_) if verify == VerificationMode::Optional => context.set_verified(false, None),
+      Ok(None) | Err(_) => return Ok(context.finish_error(ErrorKind::InvalidSignature)),
+    }
+  }
+
+  // 7. Produce the requested representation of the resolved DID document.
+  if let Some(representation) = representation {
+    match representation.encode(&doc.data) {
+      Ok(stream) => context.set_stream(representation, stream),
+      Err(_) => return Ok(context.finish_error(ErrorKind::InternalError)),
+    }
+  }
 
+  context.set_cached(meta.cached.is_some());
   context.set_document(doc.data);
-  context.set_metadata(doc.meta);
+  context.set_metadata(meta);
   context.set_resolved(did);
 
   Ok(context.finish())
 }
 
+fn version_input(did: &DID, mut input: InputMetadata) -> Result<InputMetadata> {
+  let parameters: DIDParameters = DIDParameters::from_did(did)?;
+
+  if input.version_id.is_none() {
+    input.version_id = parameters.version_id;
+  }
+
+  if input.version_time.is_none() {
+    input.version_time = parameters.version_time;
+  }
+
+  Ok(input)
+}
+
+// Verifies the `proof` of a DID document and returns the id of the verifying
+// method - Returns `None` if the document has no `proof`.
+//
+// The signature suite is selected by the proof `type` and `cryptosuite`, and
+// proofs of deactivated documents are rejected.
+fn verify_document(document: &MetaDocument) -> Result<Option<DID>> {
+  let data: Document<VerifiableProperties> = document.data.serde_into()?;
+
+  let signature: &Signature = match data.proof() {
+    Some(signature) => signature,
+    None => return Ok(None),
+  };
+
+  let query: MethodQuery<'_> = signature.try_into()?;
+  let method: DID = data.try_resolve(query)?.id().clone();
+
+  match (signature.type_(), signature.cryptosuite()) {
+    (JcsEd25519Signature2020::NAME, None) => LdSuite::new(JcsEd25519Signature2020).verify_data(&data, document)?,
+    (EddsaJcs2022::NAME, Some(EddsaJcs2022::CRYPTOSUITE)) => LdSuite::new(EddsaJcs2022).verify_data(&data, document)?,
+    (_, _) => return Err(Error::UnknownSignatureType),
+  }
+
+  Ok(Some(method))
+}
+
 /// Dereferences a DID URL into a primary or secondary resource.
 ///
 /// See [DID Url Dereferencing][SPEC] for more information.
@@ -81,31 +176,56 @@ where
 
   // 1. Obtain the DID document for the input DID by executing the DID
   //    resolution algorithm.
-  let resolution: Resolution = resolve(did, input, method).await?;
+  let resolution: Resolution = resolve(did, input, &method).await?;
 
   // If the resolution result contains an error, bail early.
   if let Some(error) = resolution.metadata.error {
     return Ok(context.finish_error(error));
   }
 
-  // Extract the document and metadata - Both properties MUST exist as we
-  // checked for resolution errors above.
-  let (document, metadata): (Document, DocumentMetadata) = match (resolution.document, resolution.document_metadata) {
-    (Some(document), Some(metadata)) => (document, metadata),
-    (Some(_), None) => return Err(Error::MissingResolutionMetadata),
-    (None, Some(_)) => return Err(Error::MissingResolutionDocument),
-    (None, None) => return Err(Error::MissingResolutionData),
+  // Extract the document, metadata, and parsed DID - All properties MUST
+  // exist as we checked for resolution errors above.
+  let (document, metadata, did): (Document, DocumentMetadata, DID) = match (
+    resolution.document,
+    resolution.document_metadata,
+    resolution.metadata.resolved,
+  ) {
+    (Some(document), Some(metadata), Some(did)) => (document, metadata, did),
+    _ => return Ok(context.finish_error(ErrorKind::InternalError)),
+  };
+
+  let method_specific: bool = match is_method_specific(&did) {
+    Ok(method_specific) => method_specific,
+    Err(error) => return Ok(context.finish_error((&error).into())),
   };
 
-  // Extract the parsed DID from the resolution output - It MUST exist as we
-  // checked for resolution errors above.
-  let did: DID = resolution.metadata.resolved.ok_or(Error::MissingResolutionDID)?;
+  // 2. Execute the algorithm for Dereferencing the Primary Resource.
+  let primary: Option<PrimaryResource> = if method_specific {
+    // If the input DID URL contains a DID path and/or DID query, execute the
+    // method-specific dereferencing algorithm.
+    let mut base: DID = did.clone();
+    base.set_fragment(None);
+
+    let document: MetaDocument = MetaDocument {
+      data: document,
+      meta: metadata.clone(),
+    };
+
+    match method.dereference_url(&base, &document).await {
+      Ok(primary) => primary,
+      Err(error) => return Ok(context.finish_error((&error).into())),
+    }
+  } else {
+    match dereference_primary(document, did.clone()) {
+      Ok(primary) => primary,
+      Err(error) => return Ok(context.finish_error((&error).into())),
+    }
+  };
 
   // Add the resolution document metadata to the response.
   context.set_metadata(metadata);
 
-  // 2. Execute the algorithm for Dereferencing the Primary Resource.
-  let primary: PrimaryResource = match dereference_primary(document, did.clone())? {
+  let primary: PrimaryResource = match primary {
     Some(primary) => primary,
     None => return Ok(context.finish_error(ErrorKind::NotFound)),
   };
@@ -121,9 +241,11 @@ where
       PrimaryResource::Document(inner) => {
         // 1.1 From the resolved DID document, select the JSON object whose id
         //     property matches the input DID URL.
-        if let Some(resource) = dereference_document(inner, fragment)? {
+        match dereference_document(inner, fragment) {
           // 1.2. Return the output resource.
-          context.set_content(resource);
+          Ok(Some(resource)) => context.set_content(resource),
+          Ok(None) => {}
+          Err(error) => return Ok(context.finish_error((&error).into())),
         }
       }
       // 2. Otherwise, if the result is an output service endpoint URL.
@@ -134,6 +256,10 @@ where
         // 2.2. Return the output service endpoint URL.
         context.set_content(PrimaryResource::Service(inner));
       }
+      // Fragments of method-specific resources are not dereferenced.
+      PrimaryResource::Object(inner) => {
+        context.set_content(PrimaryResource::Object(inner));
+      }
     }
   } else {
     context.set_content(primary);
@@ -162,6 +288,20 @@ impl ResolveContext {
     self.0.metadata.resolved = Some(value);
   }
 
+  fn set_cached(&mut self, value: bool) {
+    self.0.metadata.cached = value;
+  }
+
+  fn set_stream(&mut self, representation: Representation, value: Vec<u8>) {
+    self.0.metadata.content_type = Some(representation.content_type().into());
+    self.0.document_stream = Some(value);
+  }
+
+  fn set_verified(&mut self, value: bool, method: Option<DID>) {
+    self.0.metadata.verified = Some(value);
+    self.0.metadata.verification_method = method;
+  }
+
   fn set_error(&mut self, value: ErrorKind) {
     self.0.metadata.error = Some(value);
   }
@@ -213,7 +353,7 @@ fn dereference_primary(document: Document, mut did: DID) -> Result<Option<Primar
   did.set_fragment(None);
 
   // 1. If the input DID URL contains the DID parameter service...
-  if let Some((_, target)) = did.query_pairs().find(|(key, _)| key == "service") {
+  if let Some(target) = DIDParameters::from_did(&did)?.service {
     // 1.1. From the resolved DID document, select the service endpoint whose
     //      id property contains a fragment which matches the value of the
     //      service DID parameter of the input DID URL.
@@ -223,21 +363,32 @@ fn dereference_primary(document: Document, mut did: DID) -> Result<Option<Primar
       .find(|service| matches!(service.id().fragment(), Some(fragment) if fragment == target))
       .map(|service| service.service_endpoint())
       // 1.2. Execute the Service Endpoint Construction algorithm.
-      .map(|url| service_endpoint_ctor(did, url))
-      .transpose()?
       // 1.3. Return the output service endpoint URL.
-      .map(Into::into)
-      .map(Ok)
+      .map(|endpoint| service_endpoint_resource(did, endpoint))
       .transpose()
   // 3. Otherwise, if the input DID URL contains no DID path and no DID query.
-  } else if did.path().is_empty() && did.query().is_none() {
+  //
+  //    Note: The `versionId` and `versionTime` DID parameters are applied
+  //    when resolving the DID document; any other DID path or DID query is
+  //    dereferenced by the DID method.
+  } else {
     // 3.1. Return the resolved DID document.
     Ok(Some(document.into()))
-  } else {
-    todo!("Handle Method-Specific Dereference")
   }
 }
 
+// Returns `true` if the DID URL has a DID path or a DID query that is not
+// handled by the DID Core dereferencing algorithm.
+fn is_method_specific(did: &DID) -> Result<bool> {
+  let parameters: DIDParameters = DIDParameters::from_did(did)?;
+
+  if parameters.service.is_some() {
+    return Ok(false);
+  }
+
+  Ok(!did.path().is_empty() || parameters.has_other())
+}
+
 fn dereference_document(document: Document, fragment: &str) -> Result<Option<SecondaryResource>> {
   #[inline]
   fn dereference<T>(base: &DID, query: &str, resources: &OrderedSet<DIDKey<T>>) -> Result<Option<SecondaryResource>>
@@ -288,6 +439,16 @@ fn dereference_document(document: Document, fragment: &str) -> Result<Option<Sec
   Ok(None)
 }
 
+// Returns the output service endpoint URL of the first URL in the service
+// endpoint; a map endpoint without URLs is returned as-is.
+fn service_endpoint_resource(did: DID, endpoint: &ServiceEndpoint) -> Result<PrimaryResource> {
+  match (endpoint.urls().next(), endpoint) {
+    (Some(url), _) => service_endpoint_ctor(did, url).map(Into::into),
+    (None, ServiceEndpoint::Map(map)) => Ok(map.clone().into()),
+    (None, _) => Err(Error::InvalidServiceEndpoint),
+  }
+}
+
 // Service Endpoint Construction
 //
 // [Ref](https://w3c-ccg.github.io/did-resolution/#service-endpoint-construction)
@@ -319,8 +480,8 @@ fn service_endpoint_ctor(did: DID, url: &Url) -> Result<Url> {
   // 3. If the output service endpoint URL has a fragment component, remove it.
   output.set_fragment(None);
 
-  // Decode and join the `relative-ref` query param, if it exists.
-  if let Some((_, relative)) = did.query_pairs().find(|(key, _)| key == "relative-ref") {
+  // Decode and join the `relativeRef` query param, if it exists.
+  if let Some(relative) = DIDParameters::from_did(&did)?.relative_ref {
     output = output.join(&relative)?;
   }
 
@@ -360,3 +521,241 @@ fn service_endpoint_ctor(did: DID, url: &Url) -> Result<Url> {
   // 9. Return the output service endpoint URL.
   Ok(output)
 }
+
+#[cfg(test)]
+mod tests {
+  use async_trait::async_trait;
+  use futures::executor::block_on;
+  use identity_core::common::Object;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::SigName;
+  use identity_core::crypto::SigSign;
+  use identity_core::crypto::SignatureOptions;
+
+  use super::*;
+  use crate::document::DocumentBuilder;
+  use crate::verification::Method;
+  use crate::verification::MethodBuilder;
+  use crate::verification::MethodData;
+  use crate::verification::MethodType;
+
+  const ID: &str = "did:example:1234";
+
+  struct Fixed(MetaDocument);
+
+  #[async_trait(?Send)]
+  impl ResolverMethod for Fixed {
+    fn is_supported(&self, did: &DID) -> bool {
+      did.method() == "example"
+    }
+
+    async fn read(&self, _did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+      Ok(Some(self.0.clone()))
+    }
+  }
+
+  // Dereferences `/diff/<id>` DID URLs for the given diff ids.
+  struct Diffs(MetaDocument, Vec<&'static str>);
+
+  #[async_trait(?Send)]
+  impl ResolverMethod for Diffs {
+    fn is_supported(&self, did: &DID) -> bool {
+      did.method() == "example"
+    }
+
+    async fn read(&self, _did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+      Ok(Some(self.0.clone()))
+    }
+
+    async fn dereference_url(&self, did: &DID, _document: &MetaDocument) -> Result<Option<PrimaryResource>> {
+      let id: &str = did.path().strip_prefix("/diff/").ok_or(Error::UnsupportedDereference)?;
+
+      Ok(
+        self
+          .1
+          .iter()
+          .find(|diff| **diff == id)
+          .map(|diff| diff_object(diff).into()),
+      )
+    }
+  }
+
+  fn diff_object(id: &str) -> Object {
+    let mut object: Object = Object::new();
+    object.insert("id".into(), id.into());
+    object
+  }
+
+  fn document(keypair: &KeyPair) -> Document<VerifiableProperties> {
+    let did: DID = ID.parse().unwrap();
+
+    let method: Method = MethodBuilder::default()
+      .id(did.join("#key-1").unwrap())
+      .controller(did.clone())
+      .key_type(MethodType::Ed25519VerificationKey2018)
+      .key_data(MethodData::new_b58(keypair.public()))
+      .build()
+      .unwrap();
+
+    DocumentBuilder::new(Object::new())
+      .id(did)
+      .verification_method(method)
+      .build()
+      .unwrap()
+      .into_verifiable()
+  }
+
+  fn signed<S>(suite: S, keypair: &KeyPair) -> Document<VerifiableProperties>
+  where
+    S: SigSign + SigName,
+  {
+    let mut document: Document<VerifiableProperties> = document(keypair);
+
+    LdSuite::new(suite)
+      .sign(&mut document, SignatureOptions::new("#key-1"), keypair.secret())
+      .unwrap();
+
+    document
+  }
+
+  fn resolve_with(document: &Document<VerifiableProperties>, deactivated: bool, mode: VerificationMode) -> Resolution {
+    let document: MetaDocument = MetaDocument {
+      data: document.serde_into().unwrap(),
+      meta: DocumentMetadata {
+        deactivated: Some(deactivated),
+        ..DocumentMetadata::new()
+      },
+    };
+
+    let mut input: InputMetadata = InputMetadata::new();
+    input.verify = Some(mode);
+
+    block_on(resolve(ID, input, Fixed(document))).unwrap()
+  }
+
+  fn dereference_with<R>(did: &str, method: R) -> Dereference
+  where
+    R: ResolverMethod,
+  {
+    block_on(dereference(did, InputMetadata::new(), method)).unwrap()
+  }
+
+  #[test]
+  fn test_dereference_method_specific() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let document: MetaDocument = MetaDocument {
+      data: document(&keypair).serde_into().unwrap(),
+      meta: DocumentMetadata::new(),
+    };
+
+    let method: Diffs = Diffs(document.clone(), vec!["1"]);
+    let diff: Option<Resource> = Some(PrimaryResource::Object(diff_object("1")).into());
+
+    let dereference: Dereference = dereference_with("did:example:1234/diff/1", &method);
+
+    assert_eq!(dereference.metadata.error, None);
+    assert_eq!(dereference.content, diff);
+
+    // Fragments of method-specific resources are not dereferenced
+    let dereference: Dereference = dereference_with("did:example:1234/diff/1#key-1", &method);
+
+    assert_eq!(dereference.metadata.error, None);
+    assert_eq!(dereference.content, diff);
+
+    let dereference: Dereference = dereference_with("did:example:1234/diff/2", &method);
+
+    assert_eq!(dereference.metadata.error, Some(ErrorKind::NotFound));
+    assert_eq!(dereference.content, None);
+
+    let dereference: Dereference = dereference_with("did:example:1234/other", &method);
+
+    assert_eq!(dereference.metadata.error, Some(ErrorKind::NotFound));
+    assert_eq!(dereference.content, None);
+
+    // The DID document is dereferenced without calling the DID method
+    let dereference: Dereference = dereference_with(ID, &method);
+
+    assert_eq!(dereference.metadata.error, None);
+    assert_eq!(
+      dereference.content,
+      Some(PrimaryResource::Document(document.data.clone()).into())
+    );
+
+    // DID methods do not dereference DID URLs by default
+    let dereference: Dereference = dereference_with("did:example:1234/diff/1", Fixed(document));
+
+    assert_eq!(dereference.metadata.error, Some(ErrorKind::NotFound));
+    assert_eq!(dereference.content, None);
+  }
+
+  #[test]
+  fn test_verify_signed() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    for document in [
+      signed(JcsEd25519Signature2020, &keypair),
+      signed(EddsaJcs2022, &keypair),
+    ]
+    .iter()
+    {
+      for mode in [VerificationMode::Optional, VerificationMode::Required].iter() {
+        let resolution: Resolution = resolve_with(document, false, *mode);
+
+        assert_eq!(resolution.metadata.error, None);
+        assert_eq!(resolution.metadata.verified, Some(true));
+        assert_eq!(
+          resolution.metadata.verification_method.as_ref().map(DID::as_str),
+          Some("did:example:1234#key-1")
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn test_verify_unsigned() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+    let document: Document<VerifiableProperties> = document(&keypair);
+
+    let resolution: Resolution = resolve_with(&document, false, VerificationMode::Optional);
+
+    assert_eq!(resolution.metadata.error, None);
+    assert_eq!(resolution.metadata.verified, None);
+
+    let resolution: Resolution = resolve_with(&document, false, VerificationMode::Required);
+
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::InvalidSignature));
+    assert!(resolution.document.is_none());
+  }
+
+  #[test]
+  fn test_verify_invalid() {
+    let keypair: KeyPair = KeyPair::new_ed25519().unwrap();
+
+    let mut tampered: Document<VerifiableProperties> = signed(JcsEd25519Signature2020, &keypair);
+    tampered
+      .also_known_as_mut()
+      .push(Url::parse("https://example.com/").unwrap());
+
+    let mut unknown: Document<VerifiableProperties> = signed(JcsEd25519Signature2020, &keypair);
+    unknown
+      .proof_mut()
+      .unwrap()
+      .set_cryptosuite(Some("unknown-2022".to_string()));
+
+    let deactivated: Document<VerifiableProperties> = signed(JcsEd25519Signature2020, &keypair);
+
+    for (document, deactivated) in [(&tampered, false), (&unknown, false), (&deactivated, true)].iter() {
+      let resolution: Resolution = resolve_with(document, *deactivated, VerificationMode::Optional);
+
+      assert_eq!(resolution.metadata.error, None);
+      assert_eq!(resolution.metadata.verified, Some(false));
+      assert!(resolution.document.is_some());
+
+      let resolution: Resolution = resolve_with(document, *deactivated, VerificationMode::Required);
+
+      assert_eq!(resolution.metadata.error, Some(ErrorKind::InvalidSignature));
+      assert!(resolution.document.is_none());
+    }
+  }
+}
@@ -2,6 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
+use identity_core::common::Timestamp;
+
+use crate::resolution::VerificationMode;
 
 /// The content type of a JSON DID Document.
 pub const MIME_DID: &str = "application/did+json";
@@ -9,8 +12,8 @@ pub const MIME_DID: &str = "application/did+json";
 /// The content type of a JSON-LD DID Document.
 pub const MIME_DID_LD: &str = "application/did+ld+json";
 
-// TODO: Support versioning via `version-id`/`version-time`
-// TODO: Support caching via `no-cache`
+/// The content type of a CBOR DID Document.
+pub const MIME_DID_CBOR: &str = "application/did+cbor";
 
 /// Input options used to configure a [DID resolution][SPEC] process.
 ///
@@ -24,6 +27,24 @@ pub struct InputMetadata {
   /// [More Info](https://www.w3.org/TR/did-spec-registries/#accept)
   #[serde(skip_serializing_if = "Option::is_none")]
   pub accept: Option<String>,
+  /// Bypasses any cached results and forces a fresh resolution, if `true`.
+  ///
+  /// [More Info](https://w3c-ccg.github.io/did-resolution/#caching)
+  #[serde(rename = "no-cache", skip_serializing_if = "Option::is_none")]
+  pub no_cache: Option<bool>,
+  /// The version of the DID document to resolve.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-core/#did-parameters)
+  #[serde(rename = "versionId", skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// The latest version of the DID document to resolve, as of the given time.
+  ///
+  /// [More Info](https://www.w3.org/TR/did-core/#did-parameters)
+  #[serde(rename = "versionTime", skip_serializing_if = "Option::is_none")]
+  pub version_time: Option<Timestamp>,
+  /// Verifies the `proof` of the resolved DID document.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub verify: Option<VerificationMode>,
   /// Additional input metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -34,6 +55,10 @@ impl InputMetadata {
   pub fn new() -> Self {
     Self {
       accept: None,
+      no_cache: None,
+      version_id: None,
+      version_time: None,
+      verify: None,
       properties: Object::new(),
     }
   }
@@ -5,16 +5,27 @@
 
 #![allow(clippy::module_inception)]
 
+mod cache;
+mod cached_resolver;
+mod controller;
 mod dereference;
 mod document_metadata;
 mod error_kind;
 mod impls;
 mod input_metadata;
+mod representation;
 mod resolution;
 mod resolution_metadata;
+mod resolver;
 mod resource;
 mod traits;
+mod verification_mode;
 
+pub use self::cache::CacheEntry;
+pub use self::cache::CacheStore;
+pub use self::cached_resolver::CachedResolver;
+pub use self::cached_resolver::CachedResolverBuilder;
+pub use self::controller::verify_controller;
 pub use self::dereference::Dereference;
 pub use self::document_metadata::DocumentMetadata;
 pub use self::error_kind::ErrorKind;
@@ -22,11 +33,15 @@ pub use self::impls::dereference;
 pub use self::impls::resolve;
 pub use self::input_metadata::InputMetadata;
 pub use self::input_metadata::MIME_DID;
+pub use self::input_metadata::MIME_DID_CBOR;
 pub use self::input_metadata::MIME_DID_LD;
+pub use self::representation::Representation;
 pub use self::resolution::Resolution;
 pub use self::resolution_metadata::ResolutionMetadata;
+pub use self::resolver::Resolver;
 pub use self::resource::PrimaryResource;
 pub use self::resource::Resource;
 pub use self::resource::SecondaryResource;
 pub use self::traits::MetaDocument;
 pub use self::traits::ResolverMethod;
+pub use self::verification_mode::VerificationMode;
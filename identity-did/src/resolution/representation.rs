@@ -0,0 +1,180 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::SerdeInto;
+use identity_core::convert::ToJson;
+
+use crate::document::Document;
+use crate::error::Error;
+use crate::error::Result;
+use crate::resolution::MIME_DID;
+use crate::resolution::MIME_DID_CBOR;
+use crate::resolution::MIME_DID_LD;
+
+const CONTEXT: &str = "@context";
+
+/// A concrete representation of a DID Document.
+///
+/// [More Info](https://www.w3.org/TR/did-core/#representations)
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Representation {
+  /// The JSON representation (`application/did+json`).
+  Json,
+  /// The JSON-LD representation (`application/did+ld+json`).
+  JsonLd,
+  /// The CBOR representation (`application/did+cbor`).
+  Cbor,
+}
+
+impl Representation {
+  /// The base JSON-LD context of a DID Document.
+  pub const CONTEXT_V1: &'static str = "https://www.w3.org/ns/did/v1";
+
+  /// Returns the `Representation` of the given media type, if supported.
+  ///
+  /// Note: Media type parameters are ignored.
+  pub fn from_content_type(content_type: &str) -> Option<Self> {
+    match content_type.split(';').next().map(str::trim) {
+      Some(MIME_DID) => Some(Self::Json),
+      Some(MIME_DID_LD) => Some(Self::JsonLd),
+      Some(MIME_DID_CBOR) => Some(Self::Cbor),
+      _ => None,
+    }
+  }
+
+  /// Returns the media type of the `Representation`.
+  pub const fn content_type(self) -> &'static str {
+    match self {
+      Self::Json => MIME_DID,
+      Self::JsonLd => MIME_DID_LD,
+      Self::Cbor => MIME_DID_CBOR,
+    }
+  }
+
+  /// Produces the `Representation` of a DID Document.
+  ///
+  /// The `@context` property is removed from the JSON and CBOR
+  /// representations; the JSON-LD representation has the DID v1 context as
+  /// the first `@context` entry.
+  pub fn encode(self, document: &Document) -> Result<Vec<u8>> {
+    let mut object: Object = document.serde_into()?;
+
+    match self {
+      Self::Json => {
+        object.remove(CONTEXT);
+        object.to_json_vec().map_err(Into::into)
+      }
+      Self::JsonLd => {
+        let context: Value = Self::context(object.remove(CONTEXT));
+        object.insert(CONTEXT.into(), context);
+        object.to_json_vec().map_err(Into::into)
+      }
+      Self::Cbor => {
+        object.remove(CONTEXT);
+        serde_cbor::to_vec(&object).map_err(Into::into)
+      }
+    }
+  }
+
+  /// Consumes the `Representation` of a DID Document.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the data is not a valid `Representation`, i.e. the JSON-LD
+  /// representation is missing the DID v1 context.
+  pub fn decode(self, data: &[u8]) -> Result<Document> {
+    match self {
+      Self::Json => Document::from_json_slice(data).map_err(Into::into),
+      Self::JsonLd => {
+        let mut object: Object = Object::from_json_slice(data)?;
+
+        match object.remove(CONTEXT) {
+          Some(Value::String(context)) if context == Self::CONTEXT_V1 => {}
+          Some(Value::Array(context)) if context.first() == Some(&Value::from(Self::CONTEXT_V1)) => {
+            // Retain any additional contexts.
+            if context.len() > 1 {
+              object.insert(CONTEXT.into(), Value::Array(context));
+            }
+          }
+          _ => return Err(Error::InvalidRepresentation),
+        }
+
+        object.serde_into().map_err(Into::into)
+      }
+      Self::Cbor => serde_cbor::from_slice(data).map_err(|_| Error::InvalidRepresentation),
+    }
+  }
+
+  // Returns a `@context` value with the DID v1 context as the first entry.
+  fn context(value: Option<Value>) -> Value {
+    let base: Value = Self::CONTEXT_V1.into();
+
+    let mut context: Vec<Value> = match value {
+      Some(Value::Array(context)) => context,
+      Some(context) => vec![context],
+      None => Vec::new(),
+    };
+
+    context.retain(|value| *value != base);
+
+    if context.is_empty() {
+      base
+    } else {
+      context.insert(0, base);
+      Value::Array(context)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::did::DID;
+  use crate::document::DocumentBuilder;
+
+  fn document() -> Document {
+    let did: DID = "did:example:123".parse().unwrap();
+
+    DocumentBuilder::new(Object::new()).id(did).build().unwrap()
+  }
+
+  #[test]
+  fn test_content_type() {
+    assert_eq!(
+      Representation::from_content_type("application/did+ld+json; charset=utf-8"),
+      Some(Representation::JsonLd)
+    );
+    assert_eq!(Representation::from_content_type("application/json"), None);
+
+    for representation in [Representation::Json, Representation::JsonLd, Representation::Cbor].iter() {
+      assert_eq!(
+        Representation::from_content_type(representation.content_type()),
+        Some(*representation)
+      );
+    }
+  }
+
+  #[test]
+  fn test_roundtrip() {
+    let document: Document = document();
+
+    for representation in [Representation::Json, Representation::JsonLd, Representation::Cbor].iter() {
+      let data: Vec<u8> = representation.encode(&document).unwrap();
+      assert_eq!(representation.decode(&data).unwrap(), document);
+    }
+  }
+
+  #[test]
+  fn test_json_ld_context() {
+    let data: Vec<u8> = Representation::JsonLd.encode(&document()).unwrap();
+    let object: Object = Object::from_json_slice(&data).unwrap();
+
+    assert_eq!(object[CONTEXT], Representation::CONTEXT_V1);
+
+    let data: Vec<u8> = Representation::Json.encode(&document()).unwrap();
+    assert!(Representation::JsonLd.decode(&data).is_err());
+  }
+}
@@ -3,6 +3,7 @@
 
 use crate::document::Document;
 use crate::resolution::DocumentMetadata;
+use crate::resolution::ErrorKind;
 use crate::resolution::ResolutionMetadata;
 
 /// The output returned from [DID resolution][SPEC].
@@ -19,6 +20,12 @@ pub struct Resolution {
   /// Document-specific metadata.
   #[serde(rename = "did-document-metadata", skip_serializing_if = "Option::is_none")]
   pub document_metadata: Option<DocumentMetadata>,
+  /// The DID Document in the representation requested via the `accept` input
+  /// metadata property.
+  ///
+  /// Note: This is only set when using stream-based resolution.
+  #[serde(skip)]
+  pub document_stream: Option<Vec<u8>>,
 }
 
 impl Resolution {
@@ -28,6 +35,27 @@ impl Resolution {
       metadata: ResolutionMetadata::new(),
       document: None,
       document_metadata: None,
+      document_stream: None,
     }
   }
+
+  /// Returns the HTTP status code of the resolution result, as used by DID
+  /// resolver servers.
+  ///
+  /// [More Info](https://w3c-ccg.github.io/did-resolution/#bindings-https)
+  pub fn http_status(&self) -> u16 {
+    match self.metadata.error {
+      Some(error) => error.http_status(),
+      None if self.is_deactivated() => ErrorKind::Deactivated.http_status(),
+      None => 200,
+    }
+  }
+
+  fn is_deactivated(&self) -> bool {
+    self
+      .document_metadata
+      .as_ref()
+      .and_then(|metadata| metadata.deactivated)
+      .unwrap_or(false)
+  }
 }
@@ -29,6 +29,18 @@ pub struct ResolutionMetadata {
   /// The parsed DID that was used for resolution.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub resolved: Option<DID>,
+  /// Indicates whether the document was served from a resolver cache.
+  #[serde(default)]
+  pub cached: bool,
+  /// The result of verifying the `proof` of the resolved DID document.
+  ///
+  /// Note: This is only set when verification was requested and the document
+  /// has a `proof`, or verification failed.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub verified: Option<bool>,
+  /// The id of the verification method used to verify the resolved document.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub verification_method: Option<DID>,
   /// Additional resolution metadata properties.
   #[serde(flatten)]
   pub properties: Object,
@@ -42,6 +54,9 @@ impl ResolutionMetadata {
       content_type: None,
       duration: Duration::from_secs(0),
       resolved: None,
+      cached: false,
+      verified: None,
+      verification_method: None,
       properties: Object::new(),
     }
   }
@@ -0,0 +1,241 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use std::collections::BTreeMap;
+
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::resolution::dereference;
+use crate::resolution::resolve;
+use crate::resolution::Dereference;
+use crate::resolution::InputMetadata;
+use crate::resolution::MetaDocument;
+use crate::resolution::PrimaryResource;
+use crate::resolution::Resolution;
+use crate::resolution::ResolverMethod;
+
+type Method = Box<dyn ResolverMethod>;
+
+/// A DID resolver that dispatches to a registered [`ResolverMethod`] based on
+/// the method name of the input DID.
+///
+/// DIDs with an unregistered method are reported as
+/// [`MethodNotSupported`][crate::resolution::ErrorKind::MethodNotSupported].
+#[derive(Default)]
+pub struct Resolver {
+  methods: BTreeMap<String, Method>,
+}
+
+impl Resolver {
+  /// Creates a new `Resolver` with no registered methods.
+  pub fn new() -> Self {
+    Self {
+      methods: BTreeMap::new(),
+    }
+  }
+
+  /// Registers a [`ResolverMethod`] for the DID method with the given `name`.
+  ///
+  /// Replaces any existing [`ResolverMethod`] registered for `name`.
+  pub fn register<R>(&mut self, name: impl Into<String>, method: R)
+  where
+    R: ResolverMethod + 'static,
+  {
+    self.methods.insert(name.into(), Box::new(method));
+  }
+
+  /// Removes the [`ResolverMethod`] registered for the DID method with the
+  /// given `name`; returns `true` if a method was registered.
+  pub fn unregister(&mut self, name: &str) -> bool {
+    self.methods.remove(name).is_some()
+  }
+
+  /// Returns `true` if a [`ResolverMethod`] is registered for the DID method
+  /// with the given `name`.
+  pub fn is_registered(&self, name: &str) -> bool {
+    self.methods.contains_key(name)
+  }
+
+  /// Returns an iterator over the names of all registered DID methods.
+  pub fn methods(&self) -> impl Iterator<Item = &str> + '_ {
+    self.methods.keys().map(String::as_str)
+  }
+
+  /// Resolves a DID into a DID Document using the registered method.
+  ///
+  /// See [`resolve`] for more information.
+  pub async fn resolve(&self, did: &str, input: InputMetadata) -> Result<Resolution> {
+    resolve(did, input, self).await
+  }
+
+  /// Dereferences a DID URL into a primary or secondary resource using the
+  /// registered method.
+  ///
+  /// See [`dereference`] for more information.
+  pub async fn dereference(&self, did: &str, input: InputMetadata) -> Result<Dereference> {
+    dereference(did, input, self).await
+  }
+
+  fn method(&self, did: &DID) -> Option<&dyn ResolverMethod> {
+    self.methods.get(did.method()).map(|method| &**method)
+  }
+}
+
+impl Debug for Resolver {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_struct("Resolver")
+      .field("methods", &self.methods.keys().collect::<Vec<_>>())
+      .finish()
+  }
+}
+
+#[async_trait(?Send)]
+impl ResolverMethod for Resolver {
+  fn is_supported(&self, did: &DID) -> bool {
+    self.method(did).map_or(false, |method| method.is_supported(did))
+  }
+
+  async fn read(&self, did: &DID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+    match self.method(did) {
+      Some(method) => method.read(did, input).await,
+      None => Ok(None),
+    }
+  }
+
+  async fn dereference_url(&self, did: &DID, document: &MetaDocument) -> Result<Option<PrimaryResource>> {
+    match self.method(did) {
+      Some(method) => method.dereference_url(did, document).await,
+      None => Err(Error::UnsupportedDereference),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use futures::executor::block_on;
+  use identity_core::common::Object;
+
+  use super::*;
+  use crate::document::Document;
+  use crate::document::DocumentBuilder;
+  use crate::peer::PeerDID;
+  use crate::peer::PeerResolver;
+  use crate::resolution::DocumentMetadata;
+  use crate::resolution::ErrorKind;
+  use crate::resolution::Resource;
+  use crate::verification::MethodType;
+
+  // Resolves every `example` DID and dereferences the `/resource` path.
+  struct Example;
+
+  #[async_trait(?Send)]
+  impl ResolverMethod for Example {
+    fn is_supported(&self, did: &DID) -> bool {
+      did.method() == "example"
+    }
+
+    async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
+      let id: DID = format!("{}:{}:{}", DID::SCHEME, did.method(), did.method_id()).parse()?;
+
+      Ok(Some(MetaDocument {
+        data: DocumentBuilder::new(Object::new()).id(id).build()?,
+        meta: DocumentMetadata::new(),
+      }))
+    }
+
+    async fn dereference_url(&self, did: &DID, _document: &MetaDocument) -> Result<Option<PrimaryResource>> {
+      match did.path() {
+        "/resource" => Ok(Some(resource().into())),
+        _ => Ok(None),
+      }
+    }
+  }
+
+  fn resource() -> Object {
+    let mut object: Object = Object::new();
+    object.insert("id".into(), "resource".into());
+    object
+  }
+
+  fn resolver() -> Resolver {
+    let mut resolver: Resolver = Resolver::new();
+    resolver.register(PeerDID::METHOD, PeerResolver::new());
+    resolver.register("example", Example);
+    resolver
+  }
+
+  #[test]
+  fn test_dispatch_by_method() {
+    let mut resolver: Resolver = Resolver::new();
+    resolver.register(PeerDID::METHOD, PeerResolver::new());
+
+    let peer: PeerDID = PeerDID::from_inception_key(MethodType::Ed25519VerificationKey2018, &[1; 32]).unwrap();
+    let other: DID = "did:example:123".parse().unwrap();
+
+    assert!(resolver.is_registered(PeerDID::METHOD));
+    assert!(resolver.is_supported(&peer));
+    assert!(!resolver.is_supported(&other));
+
+    assert!(resolver.unregister(PeerDID::METHOD));
+    assert!(!resolver.is_supported(&peer));
+  }
+
+  #[test]
+  fn test_resolve() {
+    let resolver: Resolver = resolver();
+    let peer: PeerDID = PeerDID::from_inception_key(MethodType::Ed25519VerificationKey2018, &[1; 32]).unwrap();
+
+    let resolution: Resolution = block_on(resolver.resolve(peer.as_str(), InputMetadata::new())).unwrap();
+    let document: Document = resolution.document.unwrap();
+
+    assert_eq!(resolution.metadata.error, None);
+    assert_eq!(document, peer.to_document().unwrap());
+
+    let resolution: Resolution = block_on(resolver.resolve("did:example:123", InputMetadata::new())).unwrap();
+
+    assert_eq!(resolution.metadata.error, None);
+    assert_eq!(resolution.document.unwrap().id().as_str(), "did:example:123");
+
+    let resolution: Resolution = block_on(resolver.resolve("did:unknown:123", InputMetadata::new())).unwrap();
+
+    assert_eq!(resolution.metadata.error, Some(ErrorKind::MethodNotSupported));
+    assert_eq!(resolution.document, None);
+  }
+
+  #[test]
+  fn test_dereference() {
+    let resolver: Resolver = resolver();
+    let peer: PeerDID = PeerDID::from_inception_key(MethodType::Ed25519VerificationKey2018, &[1; 32]).unwrap();
+
+    let dereference: Dereference =
+      block_on(resolver.dereference("did:example:123/resource", InputMetadata::new())).unwrap();
+
+    assert_eq!(dereference.metadata.error, None);
+    assert_eq!(
+      dereference.content,
+      Some(Resource::from(PrimaryResource::from(resource())))
+    );
+
+    let dereference: Dereference =
+      block_on(resolver.dereference("did:example:123/missing", InputMetadata::new())).unwrap();
+
+    assert_eq!(dereference.metadata.error, Some(ErrorKind::NotFound));
+
+    // The peer method does not dereference DID URLs
+    let dereference: Dereference =
+      block_on(resolver.dereference(&format!("{}/resource", peer), InputMetadata::new())).unwrap();
+
+    assert_eq!(dereference.metadata.error, Some(ErrorKind::NotFound));
+
+    let dereference: Dereference =
+      block_on(resolver.dereference("did:unknown:123/resource", InputMetadata::new())).unwrap();
+
+    assert_eq!(dereference.metadata.error, Some(ErrorKind::MethodNotSupported));
+    assert_eq!(dereference.content, None);
+  }
+}
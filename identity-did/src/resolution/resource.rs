@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Object;
 use identity_core::common::Url;
 
 use crate::did::DID;
@@ -43,6 +44,8 @@ pub enum PrimaryResource {
   Document(Document),
   /// A dereferenced DID Document service endpoint.
   Service(Url),
+  /// A method-specific resource.
+  Object(Object),
 }
 
 impl From<Document> for PrimaryResource {
@@ -57,6 +60,12 @@ impl From<Url> for PrimaryResource {
   }
 }
 
+impl From<Object> for PrimaryResource {
+  fn from(other: Object) -> Self {
+    Self::Object(other)
+  }
+}
+
 /// A secondary resource returned from a [DID URL dereferencing][SPEC] process.
 ///
 /// [SPEC]: https://www.w3.org/TR/did-core/#dfn-did-url-dereferencing
@@ -2,12 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use async_trait::async_trait;
+use identity_core::common::Object;
 
 use crate::did::DID;
 use crate::document::Document;
+use crate::error::Error;
 use crate::error::Result;
 use crate::resolution::DocumentMetadata;
 use crate::resolution::InputMetadata;
+use crate::resolution::PrimaryResource;
+use crate::verifiable::ResolveMethod;
+use crate::verification::MethodQuery;
+use crate::verification::MethodWrap;
 
 /// A resolved [`Document`] and associated [`DocumentMetadata`].
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -18,6 +24,16 @@ pub struct MetaDocument {
   pub meta: DocumentMetadata,
 }
 
+impl ResolveMethod<Object> for MetaDocument {
+  fn resolve_method(&self, query: MethodQuery<'_>) -> Option<MethodWrap<'_>> {
+    self.data.resolve(query)
+  }
+
+  fn is_deactivated(&self) -> bool {
+    self.meta.deactivated.unwrap_or(false)
+  }
+}
+
 /// A trait for generic DID Resolvers.
 #[async_trait(?Send)]
 pub trait ResolverMethod {
@@ -26,12 +42,26 @@ pub trait ResolverMethod {
 
   /// Performs the "Read" operation of the DID method.
   async fn read(&self, did: &DID, input: InputMetadata) -> Result<Option<MetaDocument>>;
+
+  /// Dereferences a DID URL with a DID path or query that is not defined by
+  /// DID Core; `document` is the resolved DID Document of `did`.
+  ///
+  /// Returns `Ok(None)` if the resource does not exist.
+  ///
+  /// # Errors
+  ///
+  /// Fails with [`Error::UnsupportedDereference`][crate::Error::UnsupportedDereference]
+  /// if the DID method does not support dereferencing the DID URL - this is
+  /// the default.
+  async fn dereference_url(&self, _did: &DID, _document: &MetaDocument) -> Result<Option<PrimaryResource>> {
+    Err(Error::UnsupportedDereference)
+  }
 }
 
 #[async_trait(?Send)]
 impl<T> ResolverMethod for &'_ T
 where
-  T: ResolverMethod + Send + Sync,
+  T: ResolverMethod + ?Sized,
 {
   fn is_supported(&self, did: &DID) -> bool {
     (**self).is_supported(did)
@@ -40,4 +70,8 @@ where
   async fn read(&self, did: &DID, input: InputMetadata) -> Result<Option<MetaDocument>> {
     (**self).read(did, input).await
   }
+
+  async fn dereference_url(&self, did: &DID, document: &MetaDocument) -> Result<Option<PrimaryResource>> {
+    (**self).dereference_url(did, document).await
+  }
 }
@@ -0,0 +1,13 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Configures verification of the `proof` of a resolved DID Document.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum VerificationMode {
+  /// Verify the document `proof`, if present, and report the result.
+  #[serde(rename = "optional")]
+  Optional,
+  /// Require a valid document `proof`; resolution fails otherwise.
+  #[serde(rename = "required")]
+  Required,
+}
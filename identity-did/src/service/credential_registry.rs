@@ -0,0 +1,110 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use identity_core::common::Object;
+use identity_core::common::Url;
+
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::service::Service;
+use crate::service::ServiceEndpoint;
+
+/// A typed Credential Registry [`Service`], used to discover the verifiable
+/// credentials of a DID subject.
+///
+/// [More Info](https://www.w3.org/TR/did-spec-registries/#credentialregistry)
+#[derive(Clone, Debug, PartialEq)]
+pub struct CredentialRegistry {
+  url: Url,
+}
+
+impl CredentialRegistry {
+  /// The `type` of a Credential Registry service.
+  pub const TYPE: &'static str = "CredentialRegistry";
+
+  /// Creates a new `CredentialRegistry` service definition.
+  pub fn new(url: Url) -> Self {
+    Self { url }
+  }
+
+  /// Returns the URL of the credential registry.
+  pub fn url(&self) -> &Url {
+    &self.url
+  }
+
+  /// Returns the `serviceEndpoint` value of the service.
+  pub fn endpoint(&self) -> ServiceEndpoint {
+    self.url.clone().into()
+  }
+
+  /// Creates a [`Service`] with the given `id` from the service definition.
+  pub fn into_service(self, id: DID) -> Result<Service> {
+    Service::builder(Object::new())
+      .id(id)
+      .type_(Self::TYPE)
+      .service_endpoint(self.endpoint())
+      .build()
+  }
+}
+
+impl<'a, T> TryFrom<&'a Service<T>> for CredentialRegistry {
+  type Error = Error;
+
+  fn try_from(other: &'a Service<T>) -> Result<Self, Self::Error> {
+    if other.type_() != Self::TYPE {
+      return Err(Error::InvalidServiceType);
+    }
+
+    other
+      .service_endpoint()
+      .urls()
+      .next()
+      .cloned()
+      .map(Self::new)
+      .ok_or(Error::InvalidServiceEndpoint)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use super::*;
+
+  #[test]
+  fn test_roundtrip() {
+    let registry: CredentialRegistry = CredentialRegistry::new(Url::parse("https://example.com/credentials").unwrap());
+
+    let service: Service = registry
+      .clone()
+      .into_service("did:example:123#registry".parse().unwrap())
+      .unwrap();
+
+    assert_eq!(service.type_(), CredentialRegistry::TYPE);
+    assert_eq!(service.service_endpoint().as_url(), Some(registry.url()));
+    assert_eq!(CredentialRegistry::try_from(&service).unwrap(), registry);
+
+    let decoded: Service = Service::from_json(&service.to_json().unwrap()).unwrap();
+
+    assert_eq!(decoded, service);
+    assert_eq!(CredentialRegistry::try_from(&decoded).unwrap(), registry);
+  }
+
+  #[test]
+  fn test_invalid_type() {
+    let service: Service = Service::builder(Object::new())
+      .id("did:example:123#registry".parse().unwrap())
+      .type_("LinkedDomains")
+      .service_endpoint(Url::parse("https://example.com/credentials").unwrap())
+      .build()
+      .unwrap();
+
+    assert!(matches!(
+      CredentialRegistry::try_from(&service),
+      Err(Error::InvalidServiceType)
+    ));
+  }
+}
@@ -0,0 +1,144 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::service::Service;
+use crate::service::ServiceEndpoint;
+
+const URI: &str = "uri";
+const ACCEPT: &str = "accept";
+const ROUTING_KEYS: &str = "routingKeys";
+
+/// A typed DIDComm v2 messaging [`Service`].
+///
+/// [More Info](https://identity.foundation/didcomm-messaging/spec/#did-document-service-endpoint)
+#[derive(Clone, Debug, PartialEq)]
+pub struct DIDCommMessaging {
+  uri: Url,
+  accept: Vec<String>,
+  routing_keys: Vec<String>,
+}
+
+impl DIDCommMessaging {
+  /// The `type` of a DIDComm v2 messaging service.
+  pub const TYPE: &'static str = "DIDCommMessaging";
+
+  /// Creates a new `DIDCommMessaging` service definition.
+  pub fn new(uri: Url, accept: Vec<String>, routing_keys: Vec<String>) -> Self {
+    Self {
+      uri,
+      accept,
+      routing_keys,
+    }
+  }
+
+  /// Returns the URI of the transport endpoint.
+  pub fn uri(&self) -> &Url {
+    &self.uri
+  }
+
+  /// Returns the accepted DIDComm profiles, in order of preference.
+  pub fn accept(&self) -> &[String] {
+    &self.accept
+  }
+
+  /// Returns the DID URLs of the mediator keys, in routing order.
+  pub fn routing_keys(&self) -> &[String] {
+    &self.routing_keys
+  }
+
+  /// Returns the map `serviceEndpoint` value of the service.
+  pub fn endpoint(&self) -> ServiceEndpoint {
+    let mut map: Object = Object::new();
+
+    map.insert(URI.into(), self.uri.to_string().into());
+
+    if !self.accept.is_empty() {
+      map.insert(ACCEPT.into(), self.accept.clone().into());
+    }
+
+    if !self.routing_keys.is_empty() {
+      map.insert(ROUTING_KEYS.into(), self.routing_keys.clone().into());
+    }
+
+    map.into()
+  }
+
+  /// Creates a [`Service`] with the given `id` from the service definition.
+  pub fn into_service(self, id: DID) -> Result<Service> {
+    Service::builder(Object::new())
+      .id(id)
+      .type_(Self::TYPE)
+      .service_endpoint(self.endpoint())
+      .build()
+  }
+}
+
+impl<'a, T> TryFrom<&'a Service<T>> for DIDCommMessaging {
+  type Error = Error;
+
+  fn try_from(other: &'a Service<T>) -> Result<Self, Self::Error> {
+    if other.type_() != Self::TYPE {
+      return Err(Error::InvalidServiceType);
+    }
+
+    match other.service_endpoint() {
+      ServiceEndpoint::One(uri) => Ok(Self::new(uri.clone(), Vec::new(), Vec::new())),
+      ServiceEndpoint::Map(map) => {
+        let uri: Url = match map.get(URI) {
+          Some(Value::String(uri)) => Url::parse(uri).map_err(|_| Error::InvalidServiceEndpoint)?,
+          _ => return Err(Error::InvalidServiceEndpoint),
+        };
+
+        Ok(Self::new(uri, strings(map, ACCEPT)?, strings(map, ROUTING_KEYS)?))
+      }
+      ServiceEndpoint::Set(_) => Err(Error::InvalidServiceEndpoint),
+    }
+  }
+}
+
+// Returns the optional array of strings stored under `key`.
+fn strings(map: &Object, key: &str) -> Result<Vec<String>> {
+  match map.get(key) {
+    Some(Value::Array(values)) => values
+      .iter()
+      .map(|value| value.as_str().map(ToOwned::to_owned))
+      .collect::<Option<_>>()
+      .ok_or(Error::InvalidServiceEndpoint),
+    Some(_) => Err(Error::InvalidServiceEndpoint),
+    None => Ok(Vec::new()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_roundtrip() {
+    let messaging: DIDCommMessaging = DIDCommMessaging::new(
+      Url::parse("https://example.com/didcomm").unwrap(),
+      vec!["didcomm/v2".into()],
+      vec!["did:example:mediator#key-1".into()],
+    );
+
+    let service: Service = messaging
+      .clone()
+      .into_service("did:example:123#didcomm".parse().unwrap())
+      .unwrap();
+
+    assert_eq!(service.type_(), DIDCommMessaging::TYPE);
+    assert_eq!(
+      service.service_endpoint().as_map().unwrap()[ROUTING_KEYS][0],
+      "did:example:mediator#key-1"
+    );
+    assert_eq!(DIDCommMessaging::try_from(&service).unwrap(), messaging);
+  }
+}
@@ -0,0 +1,114 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::convert::TryFrom;
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::service::Service;
+use crate::service::ServiceEndpoint;
+
+const ORIGINS: &str = "origins";
+
+/// A typed Linked Domains [`Service`], used to link a DID to web origins.
+///
+/// [More Info](https://identity.foundation/.well-known/resources/did-configuration/#linked-domain-service-endpoint)
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkedDomains {
+  origins: Vec<Url>,
+}
+
+impl LinkedDomains {
+  /// The `type` of a Linked Domains service.
+  pub const TYPE: &'static str = "LinkedDomains";
+
+  /// Creates a new `LinkedDomains` service definition.
+  pub fn new(origins: Vec<Url>) -> Self {
+    Self { origins }
+  }
+
+  /// Returns the linked origins.
+  pub fn origins(&self) -> &[Url] {
+    &self.origins
+  }
+
+  /// Returns the `serviceEndpoint` value of the service - a single origin is
+  /// encoded as a URL, multiple origins as an `origins` map.
+  pub fn endpoint(&self) -> ServiceEndpoint {
+    match self.origins.as_slice() {
+      [origin] => origin.clone().into(),
+      origins => {
+        let mut map: Object = Object::new();
+        let origins: Vec<String> = origins.iter().map(ToString::to_string).collect();
+
+        map.insert(ORIGINS.into(), origins.into());
+        map.into()
+      }
+    }
+  }
+
+  /// Creates a [`Service`] with the given `id` from the service definition.
+  pub fn into_service(self, id: DID) -> Result<Service> {
+    Service::builder(Object::new())
+      .id(id)
+      .type_(Self::TYPE)
+      .service_endpoint(self.endpoint())
+      .build()
+  }
+}
+
+impl<'a, T> TryFrom<&'a Service<T>> for LinkedDomains {
+  type Error = Error;
+
+  fn try_from(other: &'a Service<T>) -> Result<Self, Self::Error> {
+    if other.type_() != Self::TYPE {
+      return Err(Error::InvalidServiceType);
+    }
+
+    match other.service_endpoint() {
+      ServiceEndpoint::One(origin) => Ok(Self::new(vec![origin.clone()])),
+      ServiceEndpoint::Map(map) => match map.get(ORIGINS) {
+        Some(Value::Array(origins)) => origins
+          .iter()
+          .map(|origin| origin.as_str().and_then(|origin| Url::parse(origin).ok()))
+          .collect::<Option<_>>()
+          .map(Self::new)
+          .ok_or(Error::InvalidServiceEndpoint),
+        _ => Err(Error::InvalidServiceEndpoint),
+      },
+      ServiceEndpoint::Set(_) => Err(Error::InvalidServiceEndpoint),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_roundtrip() {
+    let one: LinkedDomains = LinkedDomains::new(vec![Url::parse("https://foo.example.com/").unwrap()]);
+    let many: LinkedDomains = LinkedDomains::new(vec![
+      Url::parse("https://foo.example.com/").unwrap(),
+      Url::parse("https://bar.example.com/").unwrap(),
+    ]);
+
+    let service: Service = one
+      .clone()
+      .into_service("did:example:123#domains".parse().unwrap())
+      .unwrap();
+    assert!(service.service_endpoint().as_url().is_some());
+    assert_eq!(LinkedDomains::try_from(&service).unwrap(), one);
+
+    let service: Service = many
+      .clone()
+      .into_service("did:example:123#domains".parse().unwrap())
+      .unwrap();
+    assert!(service.service_endpoint().as_map().is_some());
+    assert_eq!(LinkedDomains::try_from(&service).unwrap(), many);
+  }
+}
@@ -4,7 +4,15 @@
 #![allow(clippy::module_inception)]
 
 mod builder;
+mod credential_registry;
+mod did_comm_messaging;
+mod linked_domains;
 mod service;
+mod service_endpoint;
 
 pub use self::builder::ServiceBuilder;
+pub use self::credential_registry::CredentialRegistry;
+pub use self::did_comm_messaging::DIDCommMessaging;
+pub use self::linked_domains::LinkedDomains;
 pub use self::service::Service;
+pub use self::service_endpoint::ServiceEndpoint;
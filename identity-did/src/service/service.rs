@@ -6,7 +6,6 @@ use core::fmt::Error as FmtError;
 use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
 use identity_core::common::Object;
-use identity_core::common::Url;
 use identity_core::convert::ToJson;
 use serde::Serialize;
 
@@ -14,6 +13,7 @@ use crate::did::DID;
 use crate::error::Error;
 use crate::error::Result;
 use crate::service::ServiceBuilder;
+use crate::service::ServiceEndpoint;
 
 /// A DID Document Service
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -22,7 +22,7 @@ pub struct Service<T = Object> {
   #[serde(rename = "type")]
   pub(crate) type_: String,
   #[serde(rename = "serviceEndpoint")]
-  pub(crate) service_endpoint: Url,
+  pub(crate) service_endpoint: ServiceEndpoint,
   #[serde(flatten)]
   pub(crate) properties: T,
 }
@@ -40,7 +40,10 @@ impl<T> Service<T> {
     Ok(Self {
       id: builder.id.ok_or(Error::InvalidServiceId)?,
       type_: builder.type_.ok_or(Error::InvalidServiceType)?,
-      service_endpoint: builder.service_endpoint.ok_or(Error::InvalidServiceEndpoint)?,
+      service_endpoint: builder
+        .service_endpoint
+        .filter(ServiceEndpoint::is_valid)
+        .ok_or(Error::InvalidServiceEndpoint)?,
       properties: builder.properties,
     })
   }
@@ -66,12 +69,12 @@ impl<T> Service<T> {
   }
 
   /// Returns a reference to the `Service` endpoint.
-  pub fn service_endpoint(&self) -> &Url {
+  pub fn service_endpoint(&self) -> &ServiceEndpoint {
     &self.service_endpoint
   }
 
   /// Returns a mutable reference to the `Service` endpoint.
-  pub fn service_endpoint_mut(&mut self) -> &mut Url {
+  pub fn service_endpoint_mut(&mut self) -> &mut ServiceEndpoint {
     &mut self.service_endpoint
   }
 
@@ -0,0 +1,134 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Error as FmtError;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::convert::ToJson;
+
+use crate::utils::OrderedSet;
+
+/// The `serviceEndpoint` value of a DID Document [`Service`][crate::service::Service].
+///
+/// [More Info](https://www.w3.org/TR/did-core/#services)
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ServiceEndpoint {
+  /// A single URL.
+  One(Url),
+  /// A map of endpoint properties, e.g. a DIDComm v2 endpoint.
+  Map(Object),
+  /// A set of URLs and/or maps.
+  ///
+  /// Note: Nested sets are not conformant with DID Core.
+  Set(OrderedSet<ServiceEndpoint>),
+}
+
+impl ServiceEndpoint {
+  /// Returns the URL of a single URL endpoint.
+  pub fn as_url(&self) -> Option<&Url> {
+    match self {
+      Self::One(url) => Some(url),
+      _ => None,
+    }
+  }
+
+  /// Returns the properties of a map endpoint.
+  pub fn as_map(&self) -> Option<&Object> {
+    match self {
+      Self::Map(map) => Some(map),
+      _ => None,
+    }
+  }
+
+  /// Returns the entries of a set endpoint.
+  pub fn as_set(&self) -> Option<&OrderedSet<Self>> {
+    match self {
+      Self::Set(set) => Some(set),
+      _ => None,
+    }
+  }
+
+  /// Returns an iterator over the URLs of the endpoint; a set yields the URLs
+  /// of its entries and a map yields no URLs.
+  pub fn urls(&self) -> Box<dyn Iterator<Item = &Url> + '_> {
+    match self {
+      Self::One(url) => Box::new(Some(url).into_iter()),
+      Self::Map(_) => Box::new(None.into_iter()),
+      Self::Set(set) => Box::new(set.iter().flat_map(Self::urls)),
+    }
+  }
+
+  /// Returns `true` if the endpoint conforms with DID Core, i.e. it is not an
+  /// empty or nested set.
+  pub fn is_valid(&self) -> bool {
+    match self {
+      Self::One(_) | Self::Map(_) => true,
+      Self::Set(set) => !set.is_empty() && set.iter().all(|entry| !matches!(entry, Self::Set(_))),
+    }
+  }
+}
+
+impl Display for ServiceEndpoint {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::One(url) => Display::fmt(url, f),
+      Self::Map(_) | Self::Set(_) => f.write_str(&self.to_json().map_err(|_| FmtError)?),
+    }
+  }
+}
+
+impl From<Url> for ServiceEndpoint {
+  fn from(other: Url) -> Self {
+    Self::One(other)
+  }
+}
+
+impl From<Object> for ServiceEndpoint {
+  fn from(other: Object) -> Self {
+    Self::Map(other)
+  }
+}
+
+impl From<OrderedSet<ServiceEndpoint>> for ServiceEndpoint {
+  fn from(other: OrderedSet<ServiceEndpoint>) -> Self {
+    Self::Set(other)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use identity_core::convert::FromJson;
+
+  #[test]
+  fn test_deserialize() {
+    let one: ServiceEndpoint = ServiceEndpoint::from_json(r#""https://example.com/""#).unwrap();
+    let map: ServiceEndpoint = ServiceEndpoint::from_json(r#"{"origins":["https://example.com/"]}"#).unwrap();
+    let set: ServiceEndpoint =
+      ServiceEndpoint::from_json(r#"["https://example.com/",{"uri":"https://example.org/"}]"#).unwrap();
+
+    assert_eq!(one.as_url().unwrap().as_str(), "https://example.com/");
+    assert!(map.as_map().unwrap().contains_key("origins"));
+    assert_eq!(set.as_set().unwrap().len(), 2);
+    assert_eq!(set.urls().count(), 1);
+
+    assert!(one.is_valid() && map.is_valid() && set.is_valid());
+    assert!(!ServiceEndpoint::from_json("[]").unwrap().is_valid());
+    assert!(!ServiceEndpoint::from_json(r#"[["https://example.com/"]]"#)
+      .unwrap()
+      .is_valid());
+  }
+
+  #[test]
+  fn test_roundtrip() {
+    let json: &str = r#"["https://example.com/",{"uri":"https://example.org/"}]"#;
+    let endpoint: ServiceEndpoint = ServiceEndpoint::from_json(json).unwrap();
+
+    assert_eq!(endpoint.to_json().unwrap(), json);
+    assert_eq!(endpoint.to_string(), json);
+  }
+}
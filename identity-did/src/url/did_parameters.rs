@@ -0,0 +1,155 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+
+use crate::did::DID;
+use crate::error::Result;
+use crate::url::normalize::encode_component;
+
+const SERVICE: &str = "service";
+const RELATIVE_REF: &str = "relativeRef";
+const VERSION_ID: &str = "versionId";
+const VERSION_TIME: &str = "versionTime";
+const HASHLINK: &str = "hl";
+
+// The name of the `relativeRef` parameter used by earlier DID Core drafts.
+const RELATIVE_REF_LEGACY: &str = "relative-ref";
+
+/// The parsed DID parameters of a DID URL query.
+///
+/// [More Info](https://www.w3.org/TR/did-core/#did-parameters)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DIDParameters {
+  /// Identifies a service of the DID document by the fragment of its id.
+  pub service: Option<String>,
+  /// A relative URI reference resolved against the selected service endpoint.
+  pub relative_ref: Option<String>,
+  /// Identifies a specific version of the DID document.
+  pub version_id: Option<String>,
+  /// Identifies the version of the DID document that was valid at the given
+  /// time.
+  pub version_time: Option<Timestamp>,
+  /// A hashlink used to verify the integrity of the resource.
+  pub hl: Option<String>,
+  /// Any other (method-specific) parameters, in query order.
+  pub other: Vec<(String, String)>,
+}
+
+impl DIDParameters {
+  /// Creates a new, empty `DIDParameters` instance.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Parses the DID parameters from the query of a DID URL.
+  ///
+  /// Note: The first occurrence of a parameter takes precedence.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `versionTime` parameter is not a valid timestamp.
+  pub fn from_did(did: &DID) -> Result<Self> {
+    let mut this: Self = Self::new();
+
+    for (key, value) in did.query_pairs() {
+      match &*key {
+        SERVICE if this.service.is_none() => {
+          this.service = Some(value.into_owned());
+        }
+        RELATIVE_REF | RELATIVE_REF_LEGACY if this.relative_ref.is_none() => {
+          this.relative_ref = Some(value.into_owned());
+        }
+        VERSION_ID if this.version_id.is_none() => {
+          this.version_id = Some(value.into_owned());
+        }
+        VERSION_TIME if this.version_time.is_none() => {
+          this.version_time = Some(Timestamp::parse(&value)?);
+        }
+        HASHLINK if this.hl.is_none() => {
+          this.hl = Some(value.into_owned());
+        }
+        SERVICE | RELATIVE_REF | RELATIVE_REF_LEGACY | VERSION_ID | VERSION_TIME | HASHLINK => {}
+        _ => {
+          this.other.push((key.into_owned(), value.into_owned()));
+        }
+      }
+    }
+
+    Ok(this)
+  }
+
+  /// Returns `true` if no parameters are set.
+  pub fn is_empty(&self) -> bool {
+    self.service.is_none()
+      && self.relative_ref.is_none()
+      && self.version_id.is_none()
+      && self.version_time.is_none()
+      && self.hl.is_none()
+      && self.other.is_empty()
+  }
+
+  /// Returns `true` if any parameters other than the DID Core parameters are
+  /// set.
+  pub fn has_other(&self) -> bool {
+    !self.other.is_empty()
+  }
+
+  /// Returns the parameters as a DID URL query, or `None` if no parameters
+  /// are set.
+  pub fn to_query(&self) -> Option<String> {
+    let version_time: Option<String> = self.version_time.map(|value| value.to_rfc3339());
+
+    let pairs: Vec<String> = [
+      (SERVICE, self.service.as_deref()),
+      (RELATIVE_REF, self.relative_ref.as_deref()),
+      (VERSION_ID, self.version_id.as_deref()),
+      (VERSION_TIME, version_time.as_deref()),
+      (HASHLINK, self.hl.as_deref()),
+    ]
+    .iter()
+    .filter_map(|(key, value)| value.map(|value| (*key, value)))
+    .chain(self.other.iter().map(|(key, value)| (key.as_str(), value.as_str())))
+    .map(|(key, value)| format!("{}={}", encode_component(key), encode_component(value)))
+    .collect();
+
+    if pairs.is_empty() {
+      None
+    } else {
+      Some(pairs.join("&"))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_from_did() {
+    let did: DID = "did:example:123?service=files&relative-ref=%2Fa%20b&versionTime=2021-01-01T00:00:00Z&hl=zQm&x=1"
+      .parse()
+      .unwrap();
+
+    let parameters: DIDParameters = DIDParameters::from_did(&did).unwrap();
+
+    assert_eq!(parameters.service.as_deref(), Some("files"));
+    assert_eq!(parameters.relative_ref.as_deref(), Some("/a b"));
+    assert_eq!(parameters.version_time.unwrap().to_rfc3339(), "2021-01-01T00:00:00Z");
+    assert_eq!(parameters.hl.as_deref(), Some("zQm"));
+    assert_eq!(parameters.other, vec![("x".to_string(), "1".to_string())]);
+
+    assert_eq!(
+      parameters.to_query().unwrap(),
+      "service=files&relativeRef=/a%20b&versionTime=2021-01-01T00:00:00Z&hl=zQm&x=1"
+    );
+  }
+
+  #[test]
+  fn test_invalid_version_time() {
+    let did: DID = "did:example:123?versionTime=yesterday".parse().unwrap();
+
+    assert!(DIDParameters::from_did(&did).is_err());
+    assert_eq!(DIDParameters::new().to_query(), None);
+  }
+}
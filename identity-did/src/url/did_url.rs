@@ -0,0 +1,207 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::str::FromStr;
+
+use crate::did::DID;
+use crate::error::Error;
+use crate::error::Result;
+use crate::url::normalize::normalize;
+use crate::url::normalize::remove_dot_segments;
+use crate::url::normalize::Components;
+use crate::url::DIDParameters;
+
+/// A normalized DID URL with parsed DID parameters.
+///
+/// [More Info](https://www.w3.org/TR/did-core/#did-url-syntax)
+#[derive(Clone, Debug, PartialEq)]
+pub struct DIDUrl {
+  did: DID,
+  parameters: DIDParameters,
+}
+
+impl DIDUrl {
+  /// Parses and normalizes an absolute DID URL.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the input is not a valid DID URL or contains invalid DID
+  /// parameters.
+  pub fn parse(input: impl AsRef<str>) -> Result<Self> {
+    DID::parse(&normalize(input.as_ref()))
+      .map_err(Into::into)
+      .and_then(Self::from_did)
+  }
+
+  /// Creates a new `DIDUrl` from an already parsed `DID`.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `DID` contains invalid DID parameters.
+  pub fn from_did(did: DID) -> Result<Self> {
+    let parameters: DIDParameters = DIDParameters::from_did(&did)?;
+
+    Ok(Self { did, parameters })
+  }
+
+  /// Resolves a (possibly relative) DID URL reference against the `base` DID.
+  ///
+  /// [More Info](https://tools.ietf.org/html/rfc3986#section-5.2)
+  ///
+  /// # Errors
+  ///
+  /// Fails if the resolved reference is not a valid DID URL.
+  pub fn resolve(base: &DID, reference: &str) -> Result<Self> {
+    if reference.starts_with(DID::SCHEME) && reference[DID::SCHEME.len()..].starts_with(':') {
+      return Self::parse(reference);
+    }
+
+    let base: Components<'_> = Components::split(base.as_str());
+    let this: Components<'_> = Components::split(reference);
+
+    let reference: String = reference_path(&this);
+    let path: String;
+
+    let target: Components<'_> = if reference.is_empty() {
+      Components {
+        did: base.did,
+        path: base.path,
+        query: this.query.or(base.query),
+        fragment: this.fragment,
+      }
+    } else {
+      path = if reference.starts_with('/') {
+        remove_dot_segments(&reference)
+      } else {
+        remove_dot_segments(&merge(base.path, &reference))
+      };
+
+      Components {
+        did: base.did,
+        path: &path,
+        query: this.query,
+        fragment: this.fragment,
+      }
+    };
+
+    Self::parse(target.compose())
+  }
+
+  /// Returns a reference to the underlying `DID`.
+  pub fn did(&self) -> &DID {
+    &self.did
+  }
+
+  /// Returns a reference to the parsed DID parameters.
+  pub fn parameters(&self) -> &DIDParameters {
+    &self.parameters
+  }
+
+  /// Returns the DID path, or an empty string if the DID URL has no path.
+  pub fn path(&self) -> &str {
+    self.did.path()
+  }
+
+  /// Returns the DID fragment, if any.
+  pub fn fragment(&self) -> Option<&str> {
+    self.did.fragment()
+  }
+
+  /// Returns `true` if `self` and `other` reference the same DID, ignoring
+  /// any path, query, or fragment.
+  pub fn is_same_did(&self, other: &DID) -> bool {
+    self.did.method() == other.method() && self.did.method_id() == other.method_id()
+  }
+
+  /// Consumes the `DIDUrl` and returns the underlying `DID`.
+  pub fn into_did(self) -> DID {
+    self.did
+  }
+}
+
+impl Display for DIDUrl {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(self.did.as_str())
+  }
+}
+
+impl FromStr for DIDUrl {
+  type Err = Error;
+
+  fn from_str(string: &str) -> Result<Self, Self::Err> {
+    Self::parse(string)
+  }
+}
+
+impl AsRef<DID> for DIDUrl {
+  fn as_ref(&self) -> &DID {
+    &self.did
+  }
+}
+
+impl From<DIDUrl> for DID {
+  fn from(other: DIDUrl) -> Self {
+    other.into_did()
+  }
+}
+
+// Returns the path of a relative reference; `Components::split` treats the
+// segment before the first `/` as the DID, which is part of the path here.
+fn reference_path(reference: &Components<'_>) -> String {
+  format!("{}{}", reference.did, reference.path)
+}
+
+// Merges a relative-path reference with the path of the base DID URL.
+//
+// [Ref](https://tools.ietf.org/html/rfc3986#section-5.2.3)
+fn merge(base: &str, reference: &str) -> String {
+  match base.rfind('/') {
+    Some(index) => format!("{}{}", &base[..=index], reference),
+    None => format!("/{}", reference),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const BASE: &str = "did:example:123/a/b/c?q=1";
+
+  #[test]
+  fn test_parse_normalized() {
+    let url: DIDUrl = DIDUrl::parse("DID:example:123/a/./b/../c?versionId=1#%6Bey-1").unwrap();
+
+    assert_eq!(url.to_string(), "did:example:123/a/c?versionId=1#key-1");
+    assert_eq!(url.path(), "/a/c");
+    assert_eq!(url.fragment(), Some("key-1"));
+    assert_eq!(url.parameters().version_id.as_deref(), Some("1"));
+  }
+
+  #[test]
+  fn test_resolve_relative() {
+    let base: DID = BASE.parse().unwrap();
+
+    let resolve = |reference: &str| DIDUrl::resolve(&base, reference).unwrap().to_string();
+
+    assert_eq!(resolve("#key-1"), "did:example:123/a/b/c?q=1#key-1");
+    assert_eq!(resolve("?service=agent"), "did:example:123/a/b/c?service=agent");
+    assert_eq!(resolve("/x/y"), "did:example:123/x/y");
+    assert_eq!(resolve("d"), "did:example:123/a/b/d");
+    assert_eq!(resolve("../d#frag"), "did:example:123/a/d#frag");
+    assert_eq!(resolve("./../../g"), "did:example:123/g");
+    assert_eq!(resolve("did:example:456#key-2"), "did:example:456#key-2");
+  }
+
+  #[test]
+  fn test_resolve_without_base_path() {
+    let base: DID = "did:example:123".parse().unwrap();
+    let url: DIDUrl = DIDUrl::resolve(&base, "files/readme?versionTime=2021-01-01T00:00:00Z").unwrap();
+
+    assert_eq!(url.path(), "/files/readme");
+    assert!(url.parameters().version_time.is_some());
+    assert!(url.is_same_did(&base));
+  }
+}
@@ -0,0 +1,11 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types for parsing, resolving, and normalizing DID URLs.
+
+mod did_parameters;
+mod did_url;
+mod normalize;
+
+pub use self::did_parameters::DIDParameters;
+pub use self::did_url::DIDUrl;
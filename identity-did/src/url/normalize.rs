@@ -0,0 +1,202 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// The components of a DID URL string.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Components<'a> {
+  pub(crate) did: &'a str,
+  pub(crate) path: &'a str,
+  pub(crate) query: Option<&'a str>,
+  pub(crate) fragment: Option<&'a str>,
+}
+
+impl<'a> Components<'a> {
+  /// Splits a DID URL string into DID, path, query, and fragment components.
+  pub(crate) fn split(input: &'a str) -> Self {
+    let (rest, fragment): (&str, Option<&str>) = match input.find('#') {
+      Some(index) => (&input[..index], Some(&input[index + 1..])),
+      None => (input, None),
+    };
+
+    let (rest, query): (&str, Option<&str>) = match rest.find('?') {
+      Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+      None => (rest, None),
+    };
+
+    let (did, path): (&str, &str) = match rest.find('/') {
+      Some(index) => (&rest[..index], &rest[index..]),
+      None => (rest, ""),
+    };
+
+    Self {
+      did,
+      path,
+      query,
+      fragment,
+    }
+  }
+
+  /// Recomposes the components as a DID URL string.
+  pub(crate) fn compose(&self) -> String {
+    let mut output: String = format!("{}{}", self.did, self.path);
+
+    if let Some(query) = self.query {
+      output.push('?');
+      output.push_str(query);
+    }
+
+    if let Some(fragment) = self.fragment {
+      output.push('#');
+      output.push_str(fragment);
+    }
+
+    output
+  }
+}
+
+/// Applies RFC 3986 syntax-based normalization to a DID URL string: the scheme
+/// is lowercased, percent-encodings are normalized, and dot-segments are
+/// removed from the path.
+///
+/// [More Info](https://tools.ietf.org/html/rfc3986#section-6.2.2)
+pub(crate) fn normalize(input: &str) -> String {
+  let input: String = normalize_percent_encoding(input);
+  let components: Components<'_> = Components::split(&input);
+
+  let did: String = match components.did.find(':') {
+    Some(index) => format!(
+      "{}{}",
+      components.did[..index].to_ascii_lowercase(),
+      &components.did[index..]
+    ),
+    None => components.did.to_string(),
+  };
+
+  let path: String = remove_dot_segments(components.path);
+
+  Components {
+    did: &did,
+    path: &path,
+    query: components.query,
+    fragment: components.fragment,
+  }
+  .compose()
+}
+
+/// Removes the `.` and `..` segments of a path.
+///
+/// [More Info](https://tools.ietf.org/html/rfc3986#section-5.2.4)
+pub(crate) fn remove_dot_segments(path: &str) -> String {
+  let mut output: Vec<&str> = Vec::new();
+  let mut input: &str = path;
+
+  while !input.is_empty() {
+    if let Some(rest) = input.strip_prefix("../").or_else(|| input.strip_prefix("./")) {
+      input = rest;
+    } else if input.starts_with("/./") {
+      input = &input[2..];
+    } else if input == "/." {
+      input = "/";
+    } else if input.starts_with("/../") {
+      input = &input[3..];
+      output.pop();
+    } else if input == "/.." {
+      input = "/";
+      output.pop();
+    } else if input == "." || input == ".." {
+      input = "";
+    } else {
+      let start: usize = if input.starts_with('/') { 1 } else { 0 };
+      let end: usize = input[start..].find('/').map_or(input.len(), |index| index + start);
+
+      output.push(&input[..end]);
+      input = &input[end..];
+    }
+  }
+
+  output.concat()
+}
+
+/// Uppercases the hex digits of percent-encodings and decodes percent-encoded
+/// unreserved characters.
+fn normalize_percent_encoding(input: &str) -> String {
+  let bytes: &[u8] = input.as_bytes();
+  let mut output: String = String::with_capacity(input.len());
+  let mut index: usize = 0;
+
+  while index < bytes.len() {
+    let decoded: Option<u8> = match bytes.get(index..index + 3) {
+      Some([b'%', high, low]) if high.is_ascii_hexdigit() && low.is_ascii_hexdigit() => {
+        u8::from_str_radix(&input[index + 1..index + 3], 16).ok()
+      }
+      _ => None,
+    };
+
+    match decoded {
+      Some(byte) if is_unreserved(byte) => {
+        output.push(byte as char);
+        index += 3;
+      }
+      Some(_) => {
+        output.push_str(&input[index..index + 3].to_ascii_uppercase());
+        index += 3;
+      }
+      None => {
+        // Push the full (possibly multi-byte) character.
+        let next: char = input[index..].chars().next().expect("infallible");
+        output.push(next);
+        index += next.len_utf8();
+      }
+    }
+  }
+
+  output
+}
+
+/// Percent-encodes the characters of a DID parameter name or value that are
+/// not allowed in a DID URL query.
+pub(crate) fn encode_component(input: &str) -> String {
+  let mut output: String = String::with_capacity(input.len());
+
+  for byte in input.bytes() {
+    if is_unreserved(byte) || b"!$'()*,;:@/".contains(&byte) {
+      output.push(byte as char);
+    } else {
+      output.push_str(&format!("%{:02X}", byte));
+    }
+  }
+
+  output
+}
+
+fn is_unreserved(byte: u8) -> bool {
+  byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~')
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_remove_dot_segments() {
+    assert_eq!(remove_dot_segments("/a/b/c/./../../g"), "/a/g");
+    assert_eq!(remove_dot_segments("mid/content=5/../6"), "mid/6");
+    assert_eq!(remove_dot_segments("/a/b/.."), "/a/");
+    assert_eq!(remove_dot_segments(""), "");
+  }
+
+  #[test]
+  fn test_normalize() {
+    assert_eq!(
+      normalize("DID:example:123/a/./b/../c?x=%7e%2f#%6Bey-1"),
+      "did:example:123/a/c?x=~%2F#key-1"
+    );
+    assert_eq!(normalize("did:example:123"), "did:example:123");
+  }
+
+  #[test]
+  fn test_encode_component() {
+    assert_eq!(encode_component("/a b&c=d"), "/a%20b%26c%3Dd");
+    assert_eq!(encode_component("2021-01-01T00:00:00Z"), "2021-01-01T00:00:00Z");
+  }
+}
@@ -132,6 +132,19 @@ impl<T> OrderedSet<T> {
     }
   }
 
+  /// Removes a value from the `OrderedSet`, preserving the order of the
+  /// remaining values; returns `true` if the value was removed.
+  pub fn remove<U>(&mut self, item: &U) -> bool
+  where
+    T: Borrow<U>,
+    U: PartialEq + ?Sized,
+  {
+    let len: usize = self.0.len();
+
+    self.0.retain(|other| other.borrow() != item);
+    self.0.len() != len
+  }
+
   /// Replaces a `current` value with the given `update` value; returns `true`
   /// if the value was successfully replaced.
   #[inline]
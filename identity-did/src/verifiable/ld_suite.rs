@@ -23,6 +23,7 @@ use crate::verification::MethodWrap;
 pub struct LdSuite<S> {
   suite: S,
   methods: &'static [MethodType],
+  historical: bool,
 }
 
 impl<S> LdSuite<S> {
@@ -31,7 +32,19 @@ impl<S> LdSuite<S> {
   }
 
   pub fn with_methods(suite: S, methods: &'static [MethodType]) -> Self {
-    Self { suite, methods }
+    Self {
+      suite,
+      methods,
+      historical: false,
+    }
+  }
+
+  /// Allows verifying signatures created by a deactivated DID, i.e. when
+  /// checking a signature that was created before deactivation.
+  #[must_use]
+  pub fn historical(mut self, value: bool) -> Self {
+    self.historical = value;
+    self
   }
 }
 
@@ -44,7 +57,10 @@ where
     T: Serialize + SetSignature,
     K: AsRef<[u8]> + ?Sized,
   {
-    message.set_signature(Signature::new(self.suite.name(), options));
+    let mut signature: Signature = Signature::new(self.suite.name(), options);
+
+    signature.set_cryptosuite(self.suite.cryptosuite());
+    message.set_signature(signature);
 
     let value: SignatureData = self.suite.sign(message, secret.as_ref())?;
 
@@ -74,7 +90,11 @@ where
   {
     let signature: &Signature = message.try_signature()?;
 
-    if signature.type_() != self.suite.name() {
+    if !self.historical && resolver.is_deactivated() {
+      return Err(Error::DeactivatedDocument);
+    }
+
+    if signature.type_() != self.suite.name() || signature.cryptosuite() != self.suite.cryptosuite().as_deref() {
       return Err(Error::UnknownSignatureType);
     }
 
@@ -15,6 +15,12 @@ pub trait ResolveMethod<M> {
   fn try_resolve_method(&self, query: MethodQuery<'_>) -> Result<MethodWrap<'_, M>> {
     self.resolve_method(query).ok_or(Error::QueryMethodNotFound)
   }
+
+  /// Returns `true` if the DID of the resolved verification methods has been
+  /// deactivated.
+  fn is_deactivated(&self) -> bool {
+    false
+  }
 }
 
 impl<'a, T, M> ResolveMethod<M> for &'a T
@@ -24,4 +30,8 @@ where
   fn resolve_method(&self, query: MethodQuery<'_>) -> Option<MethodWrap<'_, M>> {
     (**self).resolve_method(query)
   }
+
+  fn is_deactivated(&self) -> bool {
+    (**self).is_deactivated()
+  }
 }
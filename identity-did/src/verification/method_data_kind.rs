@@ -0,0 +1,32 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::verification::MethodData;
+
+/// The format of verification method data, without the data itself.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MethodDataKind {
+  PublicKeyBase58,
+  PublicKeyHex,
+  PublicKeyJwk,
+}
+
+impl MethodDataKind {
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::PublicKeyBase58 => "publicKeyBase58",
+      Self::PublicKeyHex => "publicKeyHex",
+      Self::PublicKeyJwk => "publicKeyJwk",
+    }
+  }
+}
+
+impl<'a> From<&'a MethodData> for MethodDataKind {
+  fn from(other: &'a MethodData) -> Self {
+    match other {
+      MethodData::PublicKeyBase58(_) => Self::PublicKeyBase58,
+      MethodData::PublicKeyHex(_) => Self::PublicKeyHex,
+      MethodData::PublicKeyJwk(_) => Self::PublicKeyJwk,
+    }
+  }
+}
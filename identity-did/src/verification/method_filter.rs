@@ -0,0 +1,80 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::did::DID;
+use crate::verification::Method;
+use crate::verification::MethodDataKind;
+use crate::verification::MethodScope;
+use crate::verification::MethodType;
+
+/// Specifies the conditions of a DID document multi-method query.
+///
+/// Unset conditions match every method.
+///
+/// See `Document::methods`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MethodFilter<'a> {
+  pub(crate) scope: Option<MethodScope>,
+  pub(crate) key_type: Option<MethodType>,
+  pub(crate) key_data: Option<MethodDataKind>,
+  pub(crate) controller: Option<&'a DID>,
+}
+
+impl<'a> MethodFilter<'a> {
+  /// Creates a new `MethodFilter` that matches every method.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Only matches methods of the given verification relationship.
+  #[must_use]
+  pub fn scope(mut self, value: MethodScope) -> Self {
+    self.scope = Some(value);
+    self
+  }
+
+  /// Only matches methods of the given `MethodType`.
+  #[must_use]
+  pub fn key_type(mut self, value: MethodType) -> Self {
+    self.key_type = Some(value);
+    self
+  }
+
+  /// Only matches methods with key data of the given format.
+  #[must_use]
+  pub fn key_data(mut self, value: MethodDataKind) -> Self {
+    self.key_data = Some(value);
+    self
+  }
+
+  /// Only matches methods with the given controller.
+  #[must_use]
+  pub fn controller(mut self, value: &'a DID) -> Self {
+    self.controller = Some(value);
+    self
+  }
+
+  /// Returns `true` if the method matches the `key_type`, `key_data`, and
+  /// `controller` conditions of the filter.
+  pub fn matches<T>(&self, method: &Method<T>) -> bool {
+    self.key_type.map_or(true, |key_type| method.key_type() == key_type)
+      && self
+        .key_data
+        .map_or(true, |key_data| MethodDataKind::from(method.key_data()) == key_data)
+      && self
+        .controller
+        .map_or(true, |controller| method.controller() == controller)
+  }
+}
+
+impl From<MethodScope> for MethodFilter<'_> {
+  fn from(other: MethodScope) -> Self {
+    Self::new().scope(other)
+  }
+}
+
+impl From<MethodType> for MethodFilter<'_> {
+  fn from(other: MethodType) -> Self {
+    Self::new().key_type(other)
+  }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::did::DID;
+use crate::url::DIDUrl;
 
 /// Index or identifier used to identify the target verification method of a
 /// `MethodQuery`.
@@ -16,9 +17,9 @@ impl<'a> MethodIdent<'a> {
   pub fn matches(&self, did: &DID) -> bool {
     match self {
       Self::Index(_) => false,
-      Self::Ident(ident) if ident.starts_with(DID::SCHEME) && !ident.ends_with('#') => ident
-        .rfind('#')
-        .map_or(false, |index| Self::matches_fragment(did, &ident[index + 1..])),
+      Self::Ident(ident) if ident.starts_with(DID::SCHEME) => DIDUrl::parse(ident).map_or(false, |url| {
+        url.is_same_did(did) && matches!((url.fragment(), did.fragment()), (Some(lhs), Some(rhs)) if lhs == rhs)
+      }),
       Self::Ident(ident) if ident.starts_with('#') => Self::matches_fragment(did, &ident[1..]),
       Self::Ident(ident) => Self::matches_fragment(did, *ident),
     }
@@ -52,3 +53,30 @@ impl PartialEq<&'_ str> for MethodIdent<'_> {
     matches!(self, Self::Ident(ident) if ident == other)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_matches() {
+    let did: DID = "did:example:123#key-1".parse().unwrap();
+
+    assert!(MethodIdent::Ident("#key-1").matches(&did));
+    assert!(MethodIdent::Ident("key-1").matches(&did));
+    assert!(MethodIdent::Ident("did:example:123#key-1").matches(&did));
+
+    assert!(!MethodIdent::Ident("#key-2").matches(&did));
+    assert!(!MethodIdent::Ident("did:example:123").matches(&did));
+    assert!(!MethodIdent::Index(0).matches(&did));
+  }
+
+  #[test]
+  fn test_matches_foreign_did() {
+    let did: DID = "did:example:123#key-1".parse().unwrap();
+
+    // Absolute ids with the same fragment only match methods of the same DID
+    assert!(!MethodIdent::Ident("did:example:456#key-1").matches(&did));
+    assert!(!MethodIdent::Ident("did:other:123#key-1").matches(&did));
+  }
+}
@@ -14,6 +14,7 @@ pub enum MethodType {
   JwsVerificationKey2020,
   Ed25519VerificationKey2018,
   MerkleKeyCollection2021,
+  X25519KeyAgreementKey2019,
 }
 
 impl MethodType {
@@ -23,6 +24,7 @@ impl MethodType {
       Self::JwsVerificationKey2020 => "JwsVerificationKey2020",
       Self::Ed25519VerificationKey2018 => "Ed25519VerificationKey2018",
       Self::MerkleKeyCollection2021 => "MerkleKeyCollection2021",
+      Self::X25519KeyAgreementKey2019 => "X25519KeyAgreementKey2019",
     }
   }
 }
@@ -36,6 +38,7 @@ impl FromStr for MethodType {
       "JwsVerificationKey2020" => Ok(Self::JwsVerificationKey2020),
       "Ed25519VerificationKey2018" => Ok(Self::Ed25519VerificationKey2018),
       "MerkleKeyCollection2021" => Ok(Self::MerkleKeyCollection2021),
+      "X25519KeyAgreementKey2019" => Ok(Self::X25519KeyAgreementKey2019),
       _ => Err(Error::UnknownMethodType),
     }
   }
@@ -4,6 +4,8 @@
 mod builder;
 mod method;
 mod method_data;
+mod method_data_kind;
+mod method_filter;
 mod method_ident;
 mod method_query;
 mod method_ref;
@@ -14,6 +16,8 @@ mod method_wrap;
 pub use self::builder::MethodBuilder;
 pub use self::method::Method;
 pub use self::method_data::MethodData;
+pub use self::method_data_kind::MethodDataKind;
+pub use self::method_filter::MethodFilter;
 pub use self::method_ident::MethodIdent;
 pub use self::method_query::MethodQuery;
 pub use self::method_ref::MethodRef;
@@ -9,6 +9,8 @@ use identity_core::crypto::SignatureData;
 use identity_core::crypto::SignatureOptions;
 use identity_core::utils::decode_b58;
 use identity_did::document;
+use identity_did::resolution::DocumentMetadata;
+use identity_did::resolution::MetaDocument;
 use identity_did::verifiable::LdSuite;
 use identity_did::verifiable::Properties;
 
@@ -83,3 +85,28 @@ fn test_jcs_ed25519_fails_when_signature_is_mutated() {
     assert!(suite.verify(&document).is_err());
   }
 }
+
+#[test]
+fn test_jcs_ed25519_fails_when_signer_is_deactivated() {
+  for tv in TVS {
+    let secret = decode_b58(tv.secret_b58).unwrap();
+    let mut document: Document = Document::from_json(tv.document_unsigned).unwrap();
+    let method = document.try_resolve("#key-1").unwrap();
+    let options: SignatureOptions = SignatureOptions::new(method.try_into_fragment().unwrap());
+    let suite: LdSuite<_> = LdSuite::new(JcsEd25519Signature2020);
+
+    suite.sign(&mut document, options, &secret).unwrap();
+
+    let mut signer: MetaDocument = MetaDocument {
+      data: document::Document::from_json(tv.document_unsigned).unwrap(),
+      meta: DocumentMetadata::new(),
+    };
+
+    assert!(suite.verify_data(&document, &signer).is_ok());
+
+    signer.meta.deactivated = Some(true);
+
+    assert!(suite.verify_data(&document, &signer).is_err());
+    assert!(suite.historical(true).verify_data(&document, &signer).is_ok());
+  }
+}
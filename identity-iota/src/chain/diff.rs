@@ -7,6 +7,7 @@ use crate::chain::AuthChain;
 use crate::chain::DocumentChain;
 use crate::did::DocumentDiff;
 use crate::did::IotaDID;
+use crate::did::IotaDocument;
 use crate::error::Error;
 use crate::error::Result;
 use crate::tangle::Message;
@@ -17,6 +18,9 @@ use crate::tangle::TangleRef;
 #[derive(Debug)]
 pub struct DiffChain {
   inner: Vec<DocumentDiff>,
+  // The auth document with all diffs of the chain merged, if the chain is not
+  // empty - cached to avoid folding the chain for every new diff.
+  pub(crate) document: Option<IotaDocument>,
 }
 
 impl DiffChain {
@@ -34,22 +38,36 @@ impl DiffChain {
       .collect();
 
     let mut this: Self = Self::new();
+    let mut document: IotaDocument = auth.current().clone();
+
+    // Diffs published after the document was deactivated are ignored
+    while !document.deactivated() {
+      let mut list: Vec<DocumentDiff> = match index.remove(DocumentChain::__diff_message_id(auth, &this)) {
+        Some(list) => list,
+        None => break,
+      };
 
-    while let Some(mut list) = index.remove(DocumentChain::__diff_message_id(auth, &this)) {
       'inner: while let Some(next) = list.pop() {
-        if auth.current().verify_data(&next).is_ok() {
+        if auth.current().verify_data(&next).is_ok() && document.merge(&next).is_ok() {
           this.inner.push(next);
           break 'inner;
         }
       }
     }
 
+    if !this.is_empty() {
+      this.document = Some(document);
+    }
+
     Ok(this)
   }
 
   /// Creates a new `DiffChain`.
   pub fn new() -> Self {
-    Self { inner: Vec::new() }
+    Self {
+      inner: Vec::new(),
+      document: None,
+    }
   }
 
   /// Returns the total number of diffs in the chain.
@@ -65,6 +83,7 @@ impl DiffChain {
   /// Empties the diff chain, removing all diffs.
   pub fn clear(&mut self) {
     self.inner.clear();
+    self.document = None;
   }
 
   /// Returns an iterator yielding references to `DocumentDiff`s.
@@ -81,26 +100,36 @@ impl DiffChain {
   ///
   /// # Errors
   ///
-  /// Fails if the diff signature is invalid or the Tangle message
-  /// references within the diff are invalid.
+  /// Fails if the diff signature is invalid, the Tangle message
+  /// references within the diff are invalid, the document was
+  /// deactivated by a previous diff, or the diff cannot be merged.
   pub fn try_push(&mut self, auth: &AuthChain, diff: DocumentDiff) -> Result<()> {
     self.check_validity(auth, &diff)?;
 
-    // SAFETY: we performed the necessary validation in `check_validity`.
-    unsafe {
-      self.push_unchecked(diff);
-    }
+    let mut document: IotaDocument = match self.document.as_ref() {
+      Some(document) => document.clone(),
+      None => DocumentChain::__fold(auth, self)?,
+    };
+
+    document.merge(&diff)?;
+
+    self.document = Some(document);
+    self.inner.push(diff);
 
     Ok(())
   }
 
   /// Adds a new diff to the diff chain with performing validation checks.
   ///
+  /// Note: This discards the cached latest document, so the next validity
+  /// check folds the whole chain.
+  ///
   /// # Safety
   ///
   /// This function is unsafe because it does not check the validity of
   /// the signature or Tangle references of the `DocumentDiff`.
   pub unsafe fn push_unchecked(&mut self, diff: DocumentDiff) {
+    self.document = None;
     self.inner.push(diff);
   }
 
@@ -113,7 +142,8 @@ impl DiffChain {
   ///
   /// # Errors
   ///
-  /// Fails if the `DocumentDiff` is not a valid addition.
+  /// Fails if the `DocumentDiff` is not a valid addition, e.g. if the
+  /// document was deactivated by a previous diff.
   pub fn check_validity(&self, auth: &AuthChain, diff: &DocumentDiff) -> Result<()> {
     if auth.current().verify_data(diff).is_err() {
       return Err(Error::ChainError {
@@ -139,6 +169,17 @@ impl DiffChain {
       });
     }
 
+    let deactivated: bool = match self.document.as_ref() {
+      Some(document) => document.deactivated(),
+      None => !self.is_empty() && DocumentChain::__fold(auth, self)?.deactivated(),
+    };
+
+    if deactivated {
+      return Err(Error::ChainError {
+        error: "Deactivated Document",
+      });
+    }
+
     Ok(())
   }
 }
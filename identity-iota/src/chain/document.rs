@@ -1,6 +1,8 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::mem;
+
 use crate::chain::AuthChain;
 use crate::chain::DiffChain;
 use crate::did::DocumentDiff;
@@ -8,12 +10,13 @@ use crate::did::IotaDID;
 use crate::did::IotaDocument;
 use crate::error::Result;
 use crate::tangle::MessageId;
+use crate::tangle::TangleRef;
 
 #[derive(Debug)]
 pub struct DocumentChain {
   auth_chain: AuthChain,
   diff_chain: DiffChain,
-  document: Option<IotaDocument>,
+  diff_history: Vec<(MessageId, DiffChain)>,
 }
 
 impl DocumentChain {
@@ -36,22 +39,20 @@ impl DocumentChain {
     Self {
       auth_chain,
       diff_chain: DiffChain::new(),
-      document: None,
+      diff_history: Vec::new(),
     }
   }
 
   /// Creates a new `DocumentChain` from given the `AuthChain` and `DiffChain`.
-  pub fn with_diff_chain(auth_chain: AuthChain, diff_chain: DiffChain) -> Result<Self> {
-    let document: Option<IotaDocument> = if diff_chain.is_empty() {
-      None
-    } else {
-      Some(Self::__fold(&auth_chain, &diff_chain)?)
-    };
+  pub fn with_diff_chain(auth_chain: AuthChain, mut diff_chain: DiffChain) -> Result<Self> {
+    if diff_chain.document.is_none() && !diff_chain.is_empty() {
+      diff_chain.document = Some(Self::__fold(&auth_chain, &diff_chain)?);
+    }
 
     Ok(Self {
       auth_chain,
       diff_chain,
-      document,
+      diff_history: Vec::new(),
     })
   }
 
@@ -88,14 +89,69 @@ impl DocumentChain {
     Ok(self.auth_chain.current)
   }
 
+  /// Returns every version of the document in the chain, oldest first, paired
+  /// with the Tangle message id of the auth document or diff that produced it.
+  ///
+  /// Diffs of earlier auth documents are only included if they were part of
+  /// the chain when the next auth document was added with
+  /// [`try_push_auth`](Self::try_push_auth).
+  pub fn history(&self) -> Result<Vec<(MessageId, IotaDocument)>> {
+    let mut history: Vec<(MessageId, IotaDocument)> = Vec::new();
+
+    for document in self.auth_chain.history.iter().flatten() {
+      let diff_chain: Option<&DiffChain> = self
+        .diff_history
+        .iter()
+        .find(|(message_id, _)| message_id == document.message_id())
+        .map(|(_, diff_chain)| diff_chain);
+
+      Self::__push_history(&mut history, document, diff_chain.into_iter().flat_map(DiffChain::iter))?;
+    }
+
+    Self::__push_history(&mut history, &self.auth_chain.current, self.diff_chain.iter())?;
+
+    Ok(history)
+  }
+
+  fn __push_history<'a>(
+    history: &mut Vec<(MessageId, IotaDocument)>,
+    document: &IotaDocument,
+    diffs: impl Iterator<Item = &'a DocumentDiff>,
+  ) -> Result<()> {
+    let mut current: IotaDocument = document.clone();
+
+    history.push((current.message_id().clone(), current.clone()));
+
+    for diff in diffs {
+      current.merge(diff)?;
+      history.push((diff.message_id().clone(), current.clone()));
+    }
+
+    Ok(())
+  }
+
+  /// Returns an iterator yielding every `DocumentDiff` in the chain, oldest
+  /// first, including the diffs of earlier auth documents.
+  pub fn diffs(&self) -> impl Iterator<Item = &DocumentDiff> {
+    self
+      .diff_history
+      .iter()
+      .flat_map(|(_, diff_chain)| diff_chain.iter())
+      .chain(self.diff_chain.iter())
+  }
+
   /// Returns a reference to the latest document in the chain.
   pub fn current(&self) -> &IotaDocument {
-    self.document.as_ref().unwrap_or_else(|| self.auth_chain.current())
+    self
+      .diff_chain
+      .document
+      .as_ref()
+      .unwrap_or_else(|| self.auth_chain.current())
   }
 
   /// Returns a mutable reference to the latest document in the chain.
   pub fn current_mut(&mut self) -> &mut IotaDocument {
-    if let Some(document) = self.document.as_mut() {
+    if let Some(document) = self.diff_chain.document.as_mut() {
       document
     } else {
       self.auth_chain.current_mut()
@@ -114,14 +170,21 @@ impl DocumentChain {
 
   /// Adds a new auth document to the chain.
   ///
+  /// The diffs of the previous auth document are kept in the chain history.
+  ///
   /// # Errors
   ///
   /// Fails if the document is not a valid auth document.
   pub fn try_push_auth(&mut self, document: IotaDocument) -> Result<()> {
+    let message_id: MessageId = self.auth_chain.current_message_id().clone();
+
     self.auth_chain.try_push(document)?;
-    self.diff_chain.clear();
 
-    self.document = None;
+    let diff_chain: DiffChain = mem::replace(&mut self.diff_chain, DiffChain::new());
+
+    if !diff_chain.is_empty() {
+      self.diff_history.push((message_id, diff_chain));
+    }
 
     Ok(())
   }
@@ -130,24 +193,111 @@ impl DocumentChain {
   ///
   /// # Errors
   ///
-  /// Fails if the document diff is invalid.
+  /// Fails if the document diff is invalid or the document was deactivated
+  /// by a previous diff.
   pub fn try_push_diff(&mut self, diff: DocumentDiff) -> Result<()> {
-    self.diff_chain.check_validity(&self.auth_chain, &diff)?;
+    self.diff_chain.try_push(&self.auth_chain, diff)
+  }
+}
 
-    let mut document: IotaDocument = self
-      .document
-      .take()
-      .unwrap_or_else(|| self.auth_chain.current().clone());
+#[cfg(test)]
+mod tests {
+  use identity_core::crypto::KeyPair;
 
-    document.merge(&diff)?;
+  use super::*;
 
-    self.document = Some(document);
+  fn message_id(tryte: char) -> MessageId {
+    MessageId::new(tryte.to_string().repeat(81))
+  }
 
-    // SAFETY: we performed the necessary validation in `DiffChain::check_validity`.
-    unsafe {
-      self.diff_chain.push_unchecked(diff);
-    }
+  // Creates a diff between `current` and `updated`, signed with the
+  // authentication method of the auth document `signer`.
+  fn diff(
+    signer: &IotaDocument,
+    keypair: &KeyPair,
+    current: &IotaDocument,
+    updated: &IotaDocument,
+    previous: &MessageId,
+    tryte: char,
+  ) -> DocumentDiff {
+    let mut diff: DocumentDiff = DocumentDiff::new(current, updated, previous.clone()).unwrap();
 
-    Ok(())
+    signer.sign_data(&mut diff, keypair.secret()).unwrap();
+    diff.set_message_id(message_id(tryte));
+    diff
+  }
+
+  #[test]
+  fn test_reject_diff_after_deactivation() {
+    let (mut root, keypair): (IotaDocument, KeyPair) = IotaDocument::builder().build().unwrap();
+
+    root.sign(keypair.secret()).unwrap();
+    root.set_message_id(message_id('A'));
+
+    let mut chain: DocumentChain = DocumentChain::new(AuthChain::new(root.clone()).unwrap());
+
+    let mut deactivated: IotaDocument = root.clone();
+    deactivated.set_deactivated(true);
+
+    chain
+      .try_push_diff(diff(&root, &keypair, &root, &deactivated, &message_id('A'), 'B'))
+      .unwrap();
+
+    assert!(chain.current().deactivated());
+
+    let mut updated: IotaDocument = deactivated.clone();
+    updated.set_deactivated(false);
+
+    let next: DocumentDiff = diff(&root, &keypair, &deactivated, &updated, &message_id('B'), 'C');
+
+    assert!(!chain.diff().is_valid(chain.auth(), &next));
+    assert!(chain.try_push_diff(next).is_err());
+    assert!(chain.current().deactivated());
+    assert_eq!(chain.diff().len(), 1);
+    assert_eq!(chain.history().unwrap().len(), 2);
+    assert!(chain.fold().unwrap().deactivated());
+  }
+
+  #[test]
+  fn test_history_across_auth_documents() {
+    let (mut root, keypair): (IotaDocument, KeyPair) = IotaDocument::builder().build().unwrap();
+
+    root.sign(keypair.secret()).unwrap();
+    root.set_message_id(message_id('A'));
+
+    let mut chain: DocumentChain = DocumentChain::new(AuthChain::new(root.clone()).unwrap());
+
+    let mut updated: IotaDocument = root.clone();
+    updated.properties_mut().insert("foo".into(), 1.into());
+
+    chain
+      .try_push_diff(diff(&root, &keypair, &root, &updated, &message_id('A'), 'B'))
+      .unwrap();
+
+    let mut auth: IotaDocument = chain.current().clone();
+    auth.set_previous_message_id(message_id('A'));
+    auth.sign(keypair.secret()).unwrap();
+    auth.set_message_id(message_id('C'));
+
+    chain.try_push_auth(auth.clone()).unwrap();
+
+    let mut updated: IotaDocument = auth.clone();
+    updated.properties_mut().insert("foo".into(), 2.into());
+
+    chain
+      .try_push_diff(diff(&auth, &keypair, &auth, &updated, &message_id('C'), 'D'))
+      .unwrap();
+
+    let history: Vec<(MessageId, IotaDocument)> = chain.history().unwrap();
+    let message_ids: Vec<MessageId> = history.iter().map(|(message_id, _)| message_id.clone()).collect();
+
+    assert_eq!(
+      message_ids,
+      [message_id('A'), message_id('B'), message_id('C'), message_id('D')]
+    );
+    assert_eq!(history[1].1.properties()["foo"], 1);
+    assert_eq!(history[3].1.properties()["foo"], 2);
+    assert_eq!(chain.diff().len(), 1);
+    assert_eq!(chain.diffs().count(), 2);
   }
 }
@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::iter::once;
 use core::slice::from_ref;
 use identity_core::common::Url;
 use identity_core::convert::ToJson;
@@ -146,7 +147,59 @@ impl Client {
     self.read_document_chain(did).await.and_then(DocumentChain::fold)
   }
 
+  /// Returns every version of the DID document, oldest first, paired with the
+  /// Tangle message id that produced it.
+  ///
+  /// The diffs of every auth document in the chain are included.
+  ///
+  /// See [`DocumentChain::history`] for more information.
+  pub async fn read_document_history(&self, did: &IotaDID) -> Result<Vec<(MessageId, IotaDocument)>> {
+    self
+      .read_document_chain_history(did)
+      .await
+      .and_then(|chain| chain.history())
+  }
+
   pub async fn read_document_chain(&self, did: &IotaDID) -> Result<DocumentChain> {
+    let auth: AuthChain = self.read_auth_chain(did).await?;
+    let diff: DiffChain = self.read_diff_chain(&auth).await?;
+
+    DocumentChain::with_diff_chain(auth, diff)
+  }
+
+  /// Reads the `DocumentChain` of `did` including the diff chains of earlier
+  /// auth documents, which requires reading a diff chain per auth document.
+  pub async fn read_document_chain_history(&self, did: &IotaDID) -> Result<DocumentChain> {
+    let auth: AuthChain = self.read_auth_chain(did).await?;
+
+    // Replay the auth chain to read the diff chain of every auth document.
+    let mut documents = auth.history.into_iter().flatten().chain(once(auth.current));
+
+    let root: IotaDocument = documents.next().expect("infallible");
+    let mut chain: DocumentChain = DocumentChain::new(AuthChain::new(root)?);
+
+    for document in documents {
+      self.extend_diff_chain(&mut chain).await?;
+
+      chain.try_push_auth(document)?;
+    }
+
+    self.extend_diff_chain(&mut chain).await?;
+
+    Ok(chain)
+  }
+
+  async fn extend_diff_chain(&self, chain: &mut DocumentChain) -> Result<()> {
+    let diff: DiffChain = self.read_diff_chain(chain.auth()).await?;
+
+    for diff in diff.iter() {
+      chain.try_push_diff(diff.clone())?;
+    }
+
+    Ok(())
+  }
+
+  async fn read_auth_chain(&self, did: &IotaDID) -> Result<AuthChain> {
     trace!("Read Document Chain: {}", did);
     trace!("Auth Chain Address: {}", did.address());
 
@@ -154,21 +207,21 @@ impl Client {
     let address: String = did.address();
     let messages: Vec<Message> = self.read_messages(&address).await?;
 
-    let auth: AuthChain = AuthChain::try_from_messages(did, &messages)?;
+    AuthChain::try_from_messages(did, &messages)
+  }
 
-    let diff: DiffChain = if auth.current().immutable() {
-      DiffChain::new()
-    } else {
-      // Fetch all messages for the diff chain.
-      let address: String = IotaDocument::diff_address(auth.current_message_id())?;
-      let messages: Vec<Message> = self.read_messages(&address).await?;
+  async fn read_diff_chain(&self, auth: &AuthChain) -> Result<DiffChain> {
+    if auth.current().immutable() {
+      return Ok(DiffChain::new());
+    }
 
-      trace!("Tangle Messages: {:?}", messages);
+    // Fetch all messages for the diff chain.
+    let address: String = IotaDocument::diff_address(auth.current_message_id())?;
+    let messages: Vec<Message> = self.read_messages(&address).await?;
 
-      DiffChain::try_from_messages(&auth, &messages)?
-    };
+    trace!("Tangle Messages: {:?}", messages);
 
-    DocumentChain::with_diff_chain(auth, diff)
+    DiffChain::try_from_messages(auth, &messages)
   }
 
   pub async fn read_messages(&self, address: &str) -> Result<Vec<Message>> {
@@ -9,11 +9,18 @@ use identity_did::error::Result;
 use identity_did::resolution::DocumentMetadata;
 use identity_did::resolution::InputMetadata;
 use identity_did::resolution::MetaDocument;
+use identity_did::resolution::PrimaryResource;
 use identity_did::resolution::ResolverMethod;
 
+use crate::chain::DocumentChain;
 use crate::client::Client;
 use crate::did::IotaDID;
 use crate::did::IotaDocument;
+use crate::error::Error as IotaError;
+use crate::tangle::MessageId;
+use crate::tangle::TangleRef;
+
+const DIFF_PATH: &str = "/diff/";
 
 #[async_trait(?Send)]
 impl ResolverMethod for Client {
@@ -23,20 +30,118 @@ impl ResolverMethod for Client {
       .unwrap_or(false)
   }
 
-  async fn read(&self, did: &DID, _input: InputMetadata) -> Result<Option<MetaDocument>> {
-    let document: IotaDocument = IotaDID::try_from_borrowed(did)
-      .map_err(|_| Error::MissingResolutionDID)
-      .map(|did| self.read_document(&did))?
-      .await
-      .map_err(|_| Error::MissingResolutionDocument)?;
+  async fn read(&self, did: &DID, input: InputMetadata) -> Result<Option<MetaDocument>> {
+    let did: &IotaDID = IotaDID::try_from_borrowed(did).map_err(|_| Error::MissingResolutionDID)?;
+
+    let version: Option<(MessageId, IotaDocument, Option<String>)> = match (&input.version_id, input.version_time) {
+      // The latest version does not require the history of the document
+      (None, None) => {
+        let chain: DocumentChain = self.read_document_chain(did).await.map_err(resolution_error)?;
+        let message_id: MessageId = chain.diff_message_id().clone();
+
+        Some((message_id, chain.fold().map_err(resolution_error)?, None))
+      }
+      _ => {
+        let history: Vec<(MessageId, IotaDocument)> =
+          self.read_document_history(did).await.map_err(resolution_error)?;
+
+        select_version(history, &input)
+      }
+    };
+
+    let (message_id, document, next_version_id): (MessageId, IotaDocument, Option<String>) = match version {
+      Some(version) => version,
+      None => return Ok(None),
+    };
 
     let mut meta: DocumentMetadata = DocumentMetadata::new();
     meta.created = Some(document.created());
     meta.updated = Some(document.updated());
+    meta.version_id = Some(message_id.as_str().into());
+    meta.next_version_id = next_version_id;
+
+    if document.deactivated() {
+      meta.deactivated = Some(true);
+    }
 
     Ok(Some(MetaDocument {
       data: document.serde_into()?,
       meta,
     }))
   }
+
+  async fn dereference_url(&self, did: &DID, _document: &MetaDocument) -> Result<Option<PrimaryResource>> {
+    // Diff messages are dereferenced with a `/diff/<message-id>` DID path;
+    // historical versions are resolved with the `versionId` DID parameter.
+    let message_id: &str = match did.path().strip_prefix(DIFF_PATH) {
+      Some(message_id) if !message_id.is_empty() && did.query().is_none() => message_id,
+      _ => return Err(Error::UnsupportedDereference),
+    };
+
+    let did: &IotaDID = IotaDID::try_from_borrowed(did).map_err(|_| Error::MissingResolutionDID)?;
+
+    // Diffs of earlier auth documents remain dereferenceable
+    let chain: DocumentChain = self.read_document_chain_history(did).await.map_err(resolution_error)?;
+
+    chain
+      .diffs()
+      .find(|diff| *diff.message_id() == message_id)
+      .map(|diff| diff.serde_into().map(PrimaryResource::Object))
+      .transpose()
+      .map_err(Into::into)
+  }
+}
+
+// Selects the version of the document requested by `input` - Versions are
+// identified by the Tangle message id of the auth document or diff that
+// produced them.
+fn select_version(
+  history: Vec<(MessageId, IotaDocument)>,
+  input: &InputMetadata,
+) -> Option<(MessageId, IotaDocument, Option<String>)> {
+  let index: usize = if let Some(version_id) = input.version_id.as_deref() {
+    history.iter().position(|(message_id, _)| *message_id == version_id)?
+  } else if let Some(version_time) = input.version_time {
+    history
+      .iter()
+      .rposition(|(_, document)| document.updated() <= version_time)?
+  } else {
+    history.len().checked_sub(1)?
+  };
+
+  let next_version_id: Option<String> = history.get(index + 1).map(|(message_id, _)| message_id.as_str().into());
+
+  history
+    .into_iter()
+    .nth(index)
+    .map(|(message_id, document)| (message_id, document, next_version_id))
+}
+
+// Only invalid or missing document chains are reported as missing documents;
+// client and network failures are internal errors of the DID method.
+fn resolution_error(error: IotaError) -> Error {
+  match error {
+    IotaError::ChainError { .. } => Error::MissingResolutionDocument,
+    error => Error::ResolverMethodError(error.to_string()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_did::resolution::ErrorKind;
+
+  use super::*;
+
+  #[test]
+  fn test_resolution_error() {
+    let error: Error = resolution_error(IotaError::ChainError {
+      error: "Invalid Root Document",
+    });
+
+    assert_eq!(ErrorKind::from(&error), ErrorKind::NotFound);
+
+    let error: Error = resolution_error(IotaError::InvalidTransactionTrytes);
+
+    assert_eq!(ErrorKind::from(&error), ErrorKind::InternalError);
+  }
 }
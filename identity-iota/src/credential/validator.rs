@@ -3,6 +3,7 @@
 
 use identity_core::common::Object;
 use identity_core::convert::FromJson;
+use identity_core::crypto::TrySignature;
 use identity_credential::credential::VerifiableCredential;
 use identity_credential::presentation::VerifiablePresentation;
 use serde::de::DeserializeOwned;
@@ -37,17 +38,32 @@ pub struct DocumentValidation {
   pub document: IotaDocument,
   pub metadata: Object,
   pub verified: bool,
+  pub deactivated: bool,
 }
 
 #[derive(Clone, Copy, Debug)]
 pub struct CredentialValidator<'a> {
   client: &'a Client,
+  historical: bool,
 }
 
 impl<'a> CredentialValidator<'a> {
   /// Creates a new `CredentialValidator`.
   pub const fn new(client: &'a Client) -> Self {
-    Self { client }
+    Self {
+      client,
+      historical: false,
+    }
+  }
+
+  /// Accepts proofs created by deactivated DIDs, i.e. when checking a
+  /// credential that was issued before deactivation.
+  ///
+  /// Note: Proofs created by deactivated DIDs are rejected by default.
+  #[must_use]
+  pub fn historical(mut self, value: bool) -> Self {
+    self.historical = value;
+    self
   }
 
   /// Deserializes the given JSON-encoded `VerifiableCredential` and validates
@@ -81,7 +97,7 @@ impl<'a> CredentialValidator<'a> {
     T: Serialize,
   {
     let issuer: DocumentValidation = self.validate_document(credential.issuer.url().as_str()).await?;
-    let verified: bool = issuer.document.verify_data(&credential).is_ok();
+    let verified: bool = self.verify_data(&issuer.document, &credential);
 
     let mut subjects: BTreeMap<String, DocumentValidation> = BTreeMap::new();
 
@@ -118,7 +134,7 @@ impl<'a> CredentialValidator<'a> {
       .ok_or(Error::InvalidPresentationHolder)?;
 
     let holder: DocumentValidation = self.validate_document(holder).await?;
-    let verified: bool = holder.document.verify_data(&presentation).is_ok();
+    let verified: bool = self.verify_data(&holder.document, &presentation);
 
     let mut credentials: Vec<CredentialValidation<U>> = Vec::new();
 
@@ -138,12 +154,25 @@ impl<'a> CredentialValidator<'a> {
     let did: IotaDID = did.parse()?;
     let document: IotaDocument = self.client.read_document(&did).await?;
     let verified: bool = document.verify().is_ok();
+    let deactivated: bool = document.deactivated();
 
     Ok(DocumentValidation {
       did,
       document,
       metadata: Object::new(),
       verified,
+      deactivated,
     })
   }
+
+  fn verify_data<T>(&self, document: &IotaDocument, data: &T) -> bool
+  where
+    T: Serialize + TrySignature,
+  {
+    if self.historical {
+      document.verify_historical_data(data).is_ok()
+    } else {
+      document.verify_data(data).is_ok()
+    }
+  }
 }
@@ -186,6 +186,19 @@ impl IotaDocument {
     self.document.properties_mut().immutable = value;
   }
 
+  /// Returns true if the `IotaDocument` is flagged as deactivated.
+  ///
+  /// Signatures created by a deactivated DID are rejected by
+  /// [`verify_data`][Self::verify_data]; this includes any further updates.
+  pub fn deactivated(&self) -> bool {
+    self.document.properties().deactivated
+  }
+
+  /// Sets the value of the `deactivated` flag.
+  pub fn set_deactivated(&mut self, value: bool) {
+    self.document.properties_mut().deactivated = value;
+  }
+
   /// Returns a reference to the custom `IotaDocument` properties.
   pub fn properties(&self) -> &Object {
     &self.document.properties().properties
@@ -290,15 +303,41 @@ impl IotaDocument {
   ///
   /// # Errors
   ///
+  /// Fails if the DID document is deactivated, an unsupported verification
+  /// method is used, document serialization fails, or the verification
+  /// operation fails.
+  pub fn verify_data<T>(&self, data: &T) -> Result<()>
+  where
+    T: Serialize + TrySignature,
+  {
+    self.verify_data_with(data, false)
+  }
+
+  /// Verfies the signature of the provided data, regardless of whether the
+  /// DID document is deactivated.
+  ///
+  /// This is used to check signatures that were created before deactivation.
+  ///
+  /// # Errors
+  ///
   /// Fails if an unsupported verification method is used, document
   /// serialization fails, or the verification operation fails.
-  pub fn verify_data<T>(&self, data: &T) -> Result<()>
+  pub fn verify_historical_data<T>(&self, data: &T) -> Result<()>
+  where
+    T: Serialize + TrySignature,
+  {
+    self.verify_data_with(data, true)
+  }
+
+  fn verify_data_with<T>(&self, data: &T, historical: bool) -> Result<()>
   where
     T: Serialize + TrySignature,
   {
     match self.authentication_type() {
       MethodType::Ed25519VerificationKey2018 => {
-        LdSuite::new(JcsEd25519Signature2020).verify_data(data, &self.document)?;
+        LdSuite::new(JcsEd25519Signature2020)
+          .historical(historical)
+          .verify_data(data, self)?;
       }
       _ => {
         return Err(Error::InvalidDocument { error: ERR_AMNS });
@@ -471,4 +510,8 @@ impl ResolveMethod<Object> for IotaDocument {
   fn resolve_method(&self, query: MethodQuery<'_>) -> Option<MethodWrap<'_>> {
     self.document.resolve(query)
   }
+
+  fn is_deactivated(&self) -> bool {
+    self.deactivated()
+  }
 }
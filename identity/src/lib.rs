@@ -46,7 +46,15 @@ pub mod credential {
 
   pub use identity_credential::credential::*;
   pub use identity_credential::error::*;
+  pub use identity_credential::exchange::*;
+  pub use identity_credential::integrity::*;
+  pub use identity_credential::jwt::*;
+  pub use identity_credential::merkle::*;
   pub use identity_credential::presentation::*;
+  pub use identity_credential::schema::*;
+  pub use identity_credential::sd_jwt::*;
+  pub use identity_credential::verifier::*;
+  pub use identity_credential::wallet::*;
 }
 
 #[cfg(feature = "identifier")]
@@ -59,6 +67,7 @@ pub mod did {
   pub use identity_did::document::*;
   pub use identity_did::error::*;
   pub use identity_did::service::*;
+  pub use identity_did::url::*;
   pub use identity_did::utils::*;
   pub use identity_did::verification::*;
 
@@ -66,6 +75,7 @@ pub mod did {
   pub use identity_did::did::Error as DIDError;
   pub use identity_did::did::DID;
 
+  pub use identity_did::peer;
   pub use identity_did::resolution;
   pub use identity_did::verifiable;
 }